@@ -464,6 +464,8 @@ export declare class NativeWatcher {
 export declare class NativeWatchResult {
   changedFiles: Array<string>
   removedFiles: Array<string>
+  /** Whether the number of changed files is above the `massiveChangeThreshold`. */
+  massiveChange: boolean
 }
 
 
@@ -572,6 +574,7 @@ export declare enum BuiltinPluginName {
   FlagDependencyExportsPlugin = 'FlagDependencyExportsPlugin',
   FlagDependencyUsagePlugin = 'FlagDependencyUsagePlugin',
   InlineExportsPlugin = 'InlineExportsPlugin',
+  RemoveUnusedClassMembersPlugin = 'RemoveUnusedClassMembersPlugin',
   MangleExportsPlugin = 'MangleExportsPlugin',
   ModuleConcatenationPlugin = 'ModuleConcatenationPlugin',
   CssModulesPlugin = 'CssModulesPlugin',
@@ -1113,6 +1116,7 @@ export interface JsRsdoctorModuleGraph {
   modules: Array<JsRsdoctorModule>
   dependencies: Array<JsRsdoctorDependency>
   chunkModules: Array<JsRsdoctorChunkModules>
+  sideEffects: Array<JsRsdoctorSideEffect>
 }
 
 export interface JsRsdoctorModuleGraphModule {
@@ -1285,6 +1289,18 @@ export interface JsStatsChildGroupChildAssets {
   prefetch?: Array<string>
 }
 
+export interface JsStatsCacheInvalidation {
+  reset?: JsStatsCacheReset
+  paths: Array<JsStatsInvalidatedPath>
+  modules: Array<string>
+}
+
+export interface JsStatsCacheReset {
+  type: "versionNotFound" | "buildDependencies"
+  version?: string
+  paths: Array<JsStatsInvalidatedPath>
+}
+
 export interface JsStatsChunk {
   type: string
   files: Array<string>
@@ -1333,6 +1349,7 @@ export interface JsStatsChunkGroupChildren {
 export interface JsStatsCompilation {
   assets?: Array<JsStatsAsset>
   assetsByChunkName?: Array<JsStatsAssetsByChunkName>
+  cacheInvalidation?: JsStatsCacheInvalidation
   chunks?: Array<JsStatsChunk>
   entrypoints?: Array<JsStatsChunkGroup>
   errors: Array<JsStatsError>
@@ -1363,6 +1380,12 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsInvalidatedPath {
+  path: string
+  type: "modified" | "deleted"
+  message: string
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -1405,6 +1428,7 @@ export interface JsStatsModuleCommonAttributes {
   reasons?: Array<JsStatsModuleReason>
   providedExports?: Array<string>
   optimizationBailout?: Array<string>
+  sideEffectsBailout?: JsStatsSideEffectsBailout
   depth?: number
   source?: string | Buffer
 }
@@ -1449,6 +1473,7 @@ export interface JsStatsOptimizationBailout {
 
 export interface JsStatsOptions {
   assets: boolean
+  cacheInvalidation: boolean
   cachedModules: boolean
   chunks: boolean
   chunkGroupAuxiliary: boolean
@@ -1467,11 +1492,18 @@ export interface JsStatsOptions {
   optimizationBailout: boolean
   providedExports: boolean
   reasons: boolean
+  sideEffectsBailout: boolean
   source: boolean
   usedExports: boolean
   warnings: boolean
 }
 
+export interface JsStatsSideEffectsBailout {
+  type: string
+  loc: string
+  statement: string
+}
+
 export interface JsStatsSize {
   sourceType: string
   size: number
@@ -1706,6 +1738,12 @@ export interface NativeWatcherOptions {
   followSymlinks?: boolean
   pollInterval?: number
   aggregateTimeout?: number
+  /** The max timeout to aggregate events while events keep arriving. */
+  maxAggregateTimeout?: number
+  /** The number of files above which the aggregated events are handled as a massive change. */
+  massiveChangeThreshold?: number
+  /** Whether to ignore the changes of files whose content is not changed. */
+  contentHash?: boolean
   /**
    * The ignored paths for the watcher.
    * It can be a single path, an array of paths, or a regular expression.
@@ -2273,12 +2311,17 @@ export interface RawIgnorePluginOptions {
 
 export interface RawIncremental {
   silent: boolean
+  verify: boolean
   make: boolean
   inferAsyncModules: boolean
   providedExports: boolean
   dependenciesDiagnostics: boolean
   sideEffects: boolean
   buildChunkGraph: boolean
+  moduleConcatenation: boolean
+  splitChunks: boolean
+  inlineExports: boolean
+  mangleExports: boolean
   moduleIds: boolean
   chunkIds: boolean
   modulesHashes: boolean
@@ -2595,7 +2638,9 @@ export interface RawOptimizationOptions {
   realContentHash: boolean
   mangleExports: boolean | string
   inlineExports: boolean
+  removeUnusedClassMembers: boolean
   concatenateModules: boolean
+  concatenateCommonjsModules: boolean
   avoidEntryIife: boolean
 }
 
@@ -2615,6 +2660,8 @@ export interface RawOptions {
   profile: boolean
   amd?: string
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   __references: Record<string, any>
   __virtual_files?: Array<JsVirtualFile>
 }
@@ -2880,8 +2927,15 @@ export interface RawStatsOptions {
 }
 
 export interface RawStorageOptions {
-  type: "filesystem"
+  type: "filesystem" | "remote"
   directory: string
+  /** The directory to share the cache with other machines, required by remote storage. */
+  remoteDirectory?: string
+  /** Whether to push the cache to `remoteDirectory` after saved. */
+  push?: boolean
+  compression?: "none" | "lz4" | "zstd"
+  /** The max total size of all versions in the cache directory in bytes. */
+  maxSize?: number
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
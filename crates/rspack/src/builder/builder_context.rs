@@ -61,6 +61,7 @@ pub(super) enum BuiltinPluginOptions {
   FlagDependencyUsagePlugin(bool),
   ModuleConcatenationPlugin,
   MangleExportsPlugin(bool),
+  RemoveUnusedClassMembersPlugin,
 
   // Experiments
   // TODO: support lazy compilation
@@ -269,6 +270,9 @@ impl BuilderContext {
       BuiltinPluginOptions::MangleExportsPlugin(value) => {
         plugins.push(rspack_plugin_javascript::MangleExportsPlugin::new(value).boxed())
       }
+      BuiltinPluginOptions::RemoveUnusedClassMembersPlugin => {
+        plugins.push(rspack_plugin_javascript::RemoveUnusedClassMembersPlugin::default().boxed())
+      }
 
       // Experiments
       // TODO: support lazy compilation
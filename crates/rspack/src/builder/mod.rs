@@ -236,6 +236,32 @@ impl CompilerBuilder {
     self
   }
 
+  /// Set the path to read and write records.
+  ///
+  /// See [`CompilerOptionsBuilder::records_path`] for more details.
+  pub fn records_path(&mut self, records_path: impl Into<Utf8PathBuf>) -> &mut Self {
+    self.options_builder.records_path(records_path);
+    self
+  }
+
+  /// Set the path to read records from.
+  ///
+  /// See [`CompilerOptionsBuilder::records_input_path`] for more details.
+  pub fn records_input_path(&mut self, records_input_path: impl Into<Utf8PathBuf>) -> &mut Self {
+    self.options_builder.records_input_path(records_input_path);
+    self
+  }
+
+  /// Set the path to write records to.
+  ///
+  /// See [`CompilerOptionsBuilder::records_output_path`] for more details.
+  pub fn records_output_path(&mut self, records_output_path: impl Into<Utf8PathBuf>) -> &mut Self {
+    self
+      .options_builder
+      .records_output_path(records_output_path);
+    self
+  }
+
   /// Set whether to enable profiling.
   ///
   /// See [`CompilerOptionsBuilder::profile`] for more details.
@@ -588,6 +614,12 @@ pub struct CompilerOptionsBuilder {
   profile: Option<bool>,
   /// Whether to fail on the first error.
   bail: Option<bool>,
+  /// The path to read and write records.
+  records_path: Option<Utf8PathBuf>,
+  /// The path to read records from.
+  records_input_path: Option<Utf8PathBuf>,
+  /// The path to write records to.
+  records_output_path: Option<Utf8PathBuf>,
   /// Performance optimization options.
   experiments: Option<ExperimentsBuilder>,
   /// Options for module configuration.
@@ -621,6 +653,9 @@ impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
       devtool: value.devtool.take(),
       profile: value.profile.take(),
       bail: value.bail.take(),
+      records_path: value.records_path.take(),
+      records_input_path: value.records_input_path.take(),
+      records_output_path: value.records_output_path.take(),
       experiments: value.experiments.take(),
       module: value.module.take(),
       output: value.output.take(),
@@ -723,6 +758,28 @@ impl CompilerOptionsBuilder {
     self
   }
 
+  /// Set the path to read and write records, which keep the module and chunk ids and the hashes
+  /// of the last compilation across separate builds.
+  ///
+  /// It's used as the default of [`CompilerOptionsBuilder::records_input_path`] and
+  /// [`CompilerOptionsBuilder::records_output_path`].
+  pub fn records_path(&mut self, records_path: impl Into<Utf8PathBuf>) -> &mut Self {
+    self.records_path = Some(records_path.into());
+    self
+  }
+
+  /// Set the path to read records from, relative paths are resolved from the context.
+  pub fn records_input_path(&mut self, records_input_path: impl Into<Utf8PathBuf>) -> &mut Self {
+    self.records_input_path = Some(records_input_path.into());
+    self
+  }
+
+  /// Set the path to write records to, relative paths are resolved from the context.
+  pub fn records_output_path(&mut self, records_output_path: impl Into<Utf8PathBuf>) -> &mut Self {
+    self.records_output_path = Some(records_output_path.into());
+    self
+  }
+
   /// Set whether to enable profiling.
   pub fn profile(&mut self, profile: bool) -> &mut Self {
     self.profile = Some(profile);
@@ -940,6 +997,24 @@ impl CompilerOptionsBuilder {
 
     let profile = d!(self.profile.take(), false);
     let bail = d!(self.bail.take(), false);
+    let records_path = self.records_path.take();
+    let resolve_records_path = |path: Utf8PathBuf| {
+      if path.is_absolute() {
+        path
+      } else {
+        context.as_path().join(path)
+      }
+    };
+    let records_input_path = self
+      .records_input_path
+      .take()
+      .or_else(|| records_path.clone())
+      .map(resolve_records_path);
+    let records_output_path = self
+      .records_output_path
+      .take()
+      .or(records_path)
+      .map(resolve_records_path);
     let cache = d!(self.cache.take(), {
       if development {
         CacheOptions::Memory {
@@ -1274,6 +1349,8 @@ impl CompilerOptionsBuilder {
       profile,
       amd,
       bail,
+      records_input_path,
+      records_output_path,
       __references: Default::default(),
     })
   }
@@ -3214,8 +3291,12 @@ pub struct OptimizationOptionsBuilder {
   mangle_exports: Option<MangleExportsOption>,
   /// Whether to enable inline exports.
   inline_exports: Option<bool>,
+  /// Whether to remove unused class members.
+  remove_unused_class_members: Option<bool>,
   /// Whether to enable concatenate modules.
   concatenate_modules: Option<bool>,
+  /// Whether to concatenate the CommonJS modules with static exports.
+  concatenate_commonjs_modules: Option<bool>,
   /// Whether to enable real content hash.
   real_content_hash: Option<bool>,
   /// Whether to enable avoid entry iife.
@@ -3239,7 +3320,9 @@ impl From<Optimization> for OptimizationOptionsBuilder {
       inner_graph: Some(value.inner_graph),
       mangle_exports: Some(value.mangle_exports),
       inline_exports: Some(value.inline_exports),
+      remove_unused_class_members: Some(value.remove_unused_class_members),
       concatenate_modules: Some(value.concatenate_modules),
+      concatenate_commonjs_modules: Some(value.concatenate_commonjs_modules),
       avoid_entry_iife: Some(value.avoid_entry_iife),
       remove_empty_chunks: None,
       merge_duplicate_chunks: None,
@@ -3271,7 +3354,9 @@ impl From<&mut OptimizationOptionsBuilder> for OptimizationOptionsBuilder {
       inner_graph: value.inner_graph.take(),
       mangle_exports: value.mangle_exports.take(),
       inline_exports: value.inline_exports.take(),
+      remove_unused_class_members: value.remove_unused_class_members.take(),
       concatenate_modules: value.concatenate_modules.take(),
+      concatenate_commonjs_modules: value.concatenate_commonjs_modules.take(),
       real_content_hash: value.real_content_hash.take(),
       avoid_entry_iife: value.avoid_entry_iife.take(),
       node_env: value.node_env.take(),
@@ -3378,6 +3463,14 @@ impl OptimizationOptionsBuilder {
     self
   }
 
+  /// Set whether to remove the unused members of the classes.
+  ///
+  /// Default set to `false`.
+  pub fn remove_unused_class_members(&mut self, value: bool) -> &mut Self {
+    self.remove_unused_class_members = Some(value);
+    self
+  }
+
   /// Set whether to enable concatenate modules.
   ///
   /// Default set to `true` in production mode.
@@ -3387,6 +3480,14 @@ impl OptimizationOptionsBuilder {
     self
   }
 
+  /// Set whether to concatenate the CommonJS modules with static exports.
+  ///
+  /// Default set to `false`.
+  pub fn concatenate_commonjs_modules(&mut self, value: bool) -> &mut Self {
+    self.concatenate_commonjs_modules = Some(value);
+    self
+  }
+
   /// Set whether to enable real content hash.
   ///
   /// Default set to `true` in production mode.
@@ -3567,6 +3668,12 @@ impl OptimizationOptionsBuilder {
     }
 
     let inline_exports = d!(self.inline_exports, production);
+    let remove_unused_class_members = d!(self.remove_unused_class_members, false);
+    if remove_unused_class_members {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::RemoveUnusedClassMembersPlugin);
+    }
     let mangle_exports = f!(self.mangle_exports.take(), || {
       if production {
         MangleExportsOption::Deterministic
@@ -3620,6 +3727,7 @@ impl OptimizationOptionsBuilder {
         .plugins
         .push(BuiltinPluginOptions::ModuleConcatenationPlugin);
     }
+    let concatenate_commonjs_modules = d!(self.concatenate_commonjs_modules, false);
 
     let avoid_entry_iife = d!(self.avoid_entry_iife, false);
     let minimize = d!(self.minimize, production);
@@ -3671,7 +3779,9 @@ impl OptimizationOptionsBuilder {
       inner_graph,
       mangle_exports,
       inline_exports,
+      remove_unused_class_members,
       concatenate_modules,
+      concatenate_commonjs_modules,
       avoid_entry_iife,
       real_content_hash,
     })
@@ -3786,6 +3896,7 @@ impl ExperimentsBuilder {
       };
       IncrementalOptions {
         silent: true,
+        verify: false,
         passes,
       }
     });
@@ -49,6 +49,7 @@
 //! Currently, there's still alot of features that are not implemented yet. Here's a list of features that are not implemented yet:
 //!
 //! - [x] `CompilerBuilder` API
+//! - [x] Watch mode API
 //! - [ ] `SplitChunksPlugin` API
 //! - [ ] `BundlerInfoPlugin` API
 //! - [ ] `StatsPrinter` API
@@ -61,3 +62,4 @@
 //!
 //! To track the current stats for API, please refer to [this](https://github.com/web-infra-dev/rspack/issues/9378) GitHub issue.
 pub mod builder;
+pub mod watch;
@@ -0,0 +1,447 @@
+//! The watch mode of Rspack compiler.
+//!
+//! [`Watch::watch`] moves the [`Compiler`] into a background task, which builds once and then rebuilds
+//! whenever the file, context or missing dependencies of the last compilation change.
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use rspack::{
+//!   builder::{Builder, CompilerBuilder},
+//!   watch::{Watch, WatchOptions},
+//! };
+//! use rspack_core::Compiler;
+//!
+//! # async fn run() -> rspack_error::Result<()> {
+//! let compiler = Compiler::builder()
+//!   .context("/path/to/project")
+//!   .entry("main", "./src/index.js")
+//!   .build()?;
+//!
+//! let mut watching = compiler.watch(WatchOptions::default());
+//! while let Some(result) = watching.next().await {
+//!   let result = result?;
+//!   println!(
+//!     "rebuilt {:?}, {} errors",
+//!     result.changed_files,
+//!     result.errors.len()
+//!   );
+//! }
+//! let _compiler = watching.close().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+  pin::Pin,
+  task::{Context, Poll},
+  time::SystemTime,
+};
+
+use futures::Stream;
+use rspack_core::{Compilation, Compiler};
+use rspack_error::{Diagnostic, Error, Result, error};
+pub use rspack_watcher::FsWatcherIgnored;
+use rspack_watcher::{EventAggregateHandler, EventHandler, FsWatcher, FsWatcherOptions};
+use rustc_hash::FxHashSet as HashSet;
+use tokio::{
+  sync::mpsc::{self, UnboundedReceiver, UnboundedSender, WeakUnboundedSender},
+  task::JoinHandle,
+};
+
+/// Options for [`Watch::watch`].
+#[derive(Debug, Default)]
+pub struct WatchOptions {
+  /// Whether to follow symbolic links when watching files.
+  pub follow_symlinks: bool,
+
+  /// The interval in milliseconds to poll for changes.
+  pub poll_interval: Option<u32>,
+
+  /// The delay in milliseconds before rebuilding once the first change is detected, the changes
+  /// made in this period are aggregated into one rebuild.
+  pub aggregate_timeout: Option<u32>,
+
+  /// The max delay in milliseconds to aggregate changes while they keep coming, e.g. when switching
+  /// branches. The delay is not extended if it's `None`.
+  pub max_aggregate_timeout: Option<u32>,
+
+  /// The number of changed files above which the dependencies of the last compilation are rescanned
+  /// instead of processing the changed files reported by the watcher one by one.
+  pub massive_change_threshold: Option<u32>,
+
+  /// The paths that should not be watched.
+  pub ignored: FsWatcherIgnored,
+
+  /// Whether to ignore the changes of files whose content is the same as the last build, the file
+  /// hashes of the persistent cache are reused if it's enabled.
+  pub content_hash: bool,
+}
+
+/// The result of a build or rebuild in watch mode.
+#[derive(Debug)]
+pub struct WatchResult {
+  /// Files changed since the last build, empty for the initial build.
+  pub changed_files: HashSet<String>,
+
+  /// Files removed since the last build, empty for the initial build.
+  pub removed_files: HashSet<String>,
+
+  /// The hash of the compilation.
+  pub hash: Option<String>,
+
+  /// The errors of the compilation.
+  pub errors: Vec<Diagnostic>,
+
+  /// The warnings of the compilation.
+  pub warnings: Vec<Diagnostic>,
+}
+
+/// Watch trait
+pub trait Watch {
+  /// Start watch mode
+  ///
+  /// This spawns a background task on the current tokio runtime, so it must be called within a
+  /// tokio runtime.
+  fn watch(self, options: WatchOptions) -> Watching;
+}
+
+impl Watch for Compiler {
+  fn watch(self, options: WatchOptions) -> Watching {
+    let (results_tx, results_rx) = mpsc::unbounded_channel();
+    let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+    let handle = tokio::spawn(run(self, options, results_tx, commands_rx));
+
+    Watching {
+      results: results_rx,
+      commands: commands_tx,
+      handle,
+    }
+  }
+}
+
+#[derive(Debug)]
+enum WatchCommand {
+  Pause,
+  Resume,
+  Invalidate,
+  Close,
+}
+
+/// Handle of a compiler running in watch mode, created by [`Watch::watch`].
+///
+/// It's a [`Stream`] of the results of the initial build and each rebuild. A result is an error if
+/// the compiler failed to build or the file system watcher failed to watch the dependencies.
+#[derive(Debug)]
+pub struct Watching {
+  results: UnboundedReceiver<Result<WatchResult>>,
+  commands: UnboundedSender<WatchCommand>,
+  handle: JoinHandle<Result<Compiler>>,
+}
+
+impl Watching {
+  /// Pause rebuilding, the changes are collected and rebuilt after [`Watching::resume`].
+  pub fn pause(&self) {
+    let _ = self.commands.send(WatchCommand::Pause);
+  }
+
+  /// Resume rebuilding, rebuild immediately if there are changes collected while paused.
+  pub fn resume(&self) {
+    let _ = self.commands.send(WatchCommand::Resume);
+  }
+
+  /// Rebuild even if no file is changed, e.g. when the lazy compilation modules are activated.
+  pub fn invalidate(&self) {
+    let _ = self.commands.send(WatchCommand::Invalidate);
+  }
+
+  /// Returns a handle to invalidate the watching from other tasks or threads.
+  pub fn invalidator(&self) -> Invalidator {
+    Invalidator(self.commands.downgrade())
+  }
+
+  /// Stop watching and return the compiler once the running build is finished.
+  pub async fn close(self) -> Result<Compiler> {
+    let _ = self.commands.send(WatchCommand::Close);
+    self
+      .handle
+      .await
+      .map_err(|e| Error::error(format!("Watching task failed: {e}")))?
+  }
+}
+
+/// Handle to invalidate a [`Watching`], created by [`Watching::invalidator`].
+///
+/// It doesn't keep the watching alive, invalidating does nothing after the [`Watching`] is dropped.
+#[derive(Debug, Clone)]
+pub struct Invalidator(WeakUnboundedSender<WatchCommand>);
+
+impl Invalidator {
+  /// See [`Watching::invalidate`].
+  pub fn invalidate(&self) {
+    if let Some(commands) = self.0.upgrade() {
+      let _ = commands.send(WatchCommand::Invalidate);
+    }
+  }
+}
+
+impl Stream for Watching {
+  type Item = Result<WatchResult>;
+
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    self.results.poll_recv(cx)
+  }
+}
+
+enum WatchEvent {
+  Changed {
+    changed_files: HashSet<String>,
+    removed_files: HashSet<String>,
+  },
+  MassiveChange {
+    changed_files: HashSet<String>,
+    removed_files: HashSet<String>,
+  },
+  Error(Error),
+}
+
+struct AggregateHandler(UnboundedSender<WatchEvent>);
+
+impl EventAggregateHandler for AggregateHandler {
+  fn on_event_handle(&self, changed_files: HashSet<String>, removed_files: HashSet<String>) {
+    let _ = self.0.send(WatchEvent::Changed {
+      changed_files,
+      removed_files,
+    });
+  }
+
+  fn on_massive_change(&self, changed_files: HashSet<String>, removed_files: HashSet<String>) {
+    let _ = self.0.send(WatchEvent::MassiveChange {
+      changed_files,
+      removed_files,
+    });
+  }
+
+  fn on_error(&self, error: Error) {
+    let _ = self.0.send(WatchEvent::Error(error));
+  }
+}
+
+struct UndelayedHandler;
+
+impl EventHandler for UndelayedHandler {}
+
+async fn run(
+  mut compiler: Compiler,
+  options: WatchOptions,
+  results: UnboundedSender<Result<WatchResult>>,
+  mut commands: UnboundedReceiver<WatchCommand>,
+) -> Result<Compiler> {
+  let mut watcher = FsWatcher::new(
+    FsWatcherOptions {
+      follow_symlinks: options.follow_symlinks,
+      poll_interval: options.poll_interval,
+      aggregate_timeout: options.aggregate_timeout,
+      max_aggregate_timeout: options.max_aggregate_timeout,
+      massive_change_threshold: options.massive_change_threshold,
+      content_hash: options.content_hash,
+    },
+    options.ignored,
+  );
+  let (events_tx, mut events_rx) = mpsc::unbounded_channel();
+
+  let mut start_time = SystemTime::now();
+  let result = compiler.build().await;
+  if options.content_hash {
+    watcher.set_content_hashes(compiler.cache.content_hashes(), start_time);
+  }
+  let _ = results.send(result.map(|_| {
+    to_watch_result(
+      &compiler.compilation,
+      Default::default(),
+      Default::default(),
+    )
+  }));
+
+  let mut initial = true;
+  let mut paused = false;
+  loop {
+    watch_dependencies(
+      &mut watcher,
+      &compiler.compilation,
+      initial,
+      start_time,
+      events_tx.clone(),
+    )
+    .await;
+    initial = false;
+
+    let mut changed_files = HashSet::default();
+    let mut removed_files = HashSet::default();
+    let mut invalidated = false;
+    let mut massive_change = false;
+    while paused
+      || (!invalidated && !massive_change && changed_files.is_empty() && removed_files.is_empty())
+    {
+      tokio::select! {
+        Some(event) = events_rx.recv() => match event {
+          WatchEvent::Changed {
+            changed_files: changed,
+            removed_files: removed,
+          } => merge_changes(&mut changed_files, &mut removed_files, changed, removed),
+          WatchEvent::MassiveChange {
+            changed_files: changed,
+            removed_files: removed,
+          } => {
+            merge_changes(&mut changed_files, &mut removed_files, changed, removed);
+            massive_change = true;
+          }
+          WatchEvent::Error(error) => {
+            let _ = results.send(Err(error));
+          }
+        },
+        command = commands.recv() => match command {
+          Some(WatchCommand::Pause) => paused = true,
+          Some(WatchCommand::Resume) => paused = false,
+          Some(WatchCommand::Invalidate) => invalidated = true,
+          // all the handles are dropped if the channel is closed
+          Some(WatchCommand::Close) | None => {
+            watcher.close().await?;
+            compiler.close().await?;
+            return Ok(compiler);
+          }
+        },
+      }
+    }
+
+    // the reported files are kept, the files not reported by the watcher are found by the rescan
+    if massive_change {
+      match rescan_dependencies(&compiler.compilation, start_time).await {
+        Ok((changed, removed)) => {
+          merge_changes(&mut changed_files, &mut removed_files, changed, removed)
+        }
+        Err(error) => {
+          let _ = results.send(Err(error));
+        }
+      }
+    }
+
+    start_time = SystemTime::now();
+    let result = compiler
+      .rebuild(
+        changed_files.iter().cloned().collect(),
+        removed_files.iter().cloned().collect(),
+      )
+      .await;
+    let _ = results
+      .send(result.map(|_| to_watch_result(&compiler.compilation, changed_files, removed_files)));
+  }
+}
+
+/// Merges the changes into the pending ones, the latest change of a file wins.
+fn merge_changes(
+  changed_files: &mut HashSet<String>,
+  removed_files: &mut HashSet<String>,
+  changed: HashSet<String>,
+  removed: HashSet<String>,
+) {
+  for file in changed {
+    removed_files.remove(&file);
+    changed_files.insert(file);
+  }
+  for file in removed {
+    changed_files.remove(&file);
+    removed_files.insert(file);
+  }
+}
+
+/// Watches the dependencies of the compilation, all the dependencies are watched for the initial
+/// build and only the added and removed ones are updated for rebuilds.
+async fn watch_dependencies(
+  watcher: &mut FsWatcher,
+  compilation: &Compilation,
+  initial: bool,
+  start_time: SystemTime,
+  events: UnboundedSender<WatchEvent>,
+) {
+  let (all_files, added_files, removed_files) = compilation.file_dependencies();
+  let (all_contexts, added_contexts, removed_contexts) = compilation.context_dependencies();
+  let (all_missing, added_missing, removed_missing) = compilation.missing_dependencies();
+
+  let files = if initial {
+    all_files.cloned().collect::<Vec<_>>()
+  } else {
+    added_files.cloned().collect::<Vec<_>>()
+  };
+  let contexts = if initial {
+    all_contexts.cloned().collect::<Vec<_>>()
+  } else {
+    added_contexts.cloned().collect::<Vec<_>>()
+  };
+  let missing = if initial {
+    all_missing.cloned().collect::<Vec<_>>()
+  } else {
+    added_missing.cloned().collect::<Vec<_>>()
+  };
+
+  watcher
+    .watch(
+      (files.into_iter(), removed_files.cloned()),
+      (contexts.into_iter(), removed_contexts.cloned()),
+      (missing.into_iter(), removed_missing.cloned()),
+      start_time,
+      Box::new(AggregateHandler(events)),
+      Box::new(UndelayedHandler),
+    )
+    .await;
+}
+
+/// Rescans the dependencies of the compilation for the changes since `start_time`, which is
+/// cheaper than processing the changed files one by one when most of the project is changed.
+async fn rescan_dependencies(
+  compilation: &Compilation,
+  start_time: SystemTime,
+) -> Result<(HashSet<String>, HashSet<String>)> {
+  let (all_files, _, _) = compilation.file_dependencies();
+  let (all_contexts, _, _) = compilation.context_dependencies();
+  let (all_missing, _, _) = compilation.missing_dependencies();
+  let existing = all_files.chain(all_contexts).cloned().collect::<Vec<_>>();
+  let missing = all_missing.cloned().collect::<Vec<_>>();
+
+  tokio::task::spawn_blocking(move || {
+    let mut changed_files = HashSet::default();
+    let mut removed_files = HashSet::default();
+    for path in existing {
+      match std::fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+        Ok(modified) if modified > start_time => {
+          changed_files.insert(path.to_string_lossy().to_string());
+        }
+        Ok(_) => {}
+        Err(_) => {
+          removed_files.insert(path.to_string_lossy().to_string());
+        }
+      }
+    }
+    for path in missing {
+      if path.exists() {
+        changed_files.insert(path.to_string_lossy().to_string());
+      }
+    }
+    (changed_files, removed_files)
+  })
+  .await
+  .map_err(|e| error!("Failed to rescan the dependencies after a massive change: {e}"))
+}
+
+fn to_watch_result(
+  compilation: &Compilation,
+  changed_files: HashSet<String>,
+  removed_files: HashSet<String>,
+) -> WatchResult {
+  WatchResult {
+    changed_files,
+    removed_files,
+    hash: compilation.get_hash().map(ToString::to_string),
+    errors: compilation.get_errors().cloned().collect(),
+    warnings: compilation.get_warnings().cloned().collect(),
+  }
+}
@@ -52,4 +52,223 @@ mod tests {
     })
     .await;
   }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn rebuild_verify() {
+    use rspack_core::{
+      Experiments,
+      incremental::{IncrementalOptions, IncrementalPasses},
+    };
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let mut compiler = Compiler::builder()
+        .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+        .entry("main", "./src/index.js")
+        .experiments(Experiments::builder().incremental(IncrementalOptions {
+          silent: true,
+          verify: true,
+          passes: IncrementalPasses::all(),
+        }))
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+      compiler
+        .rebuild(
+          std::iter::once(
+            Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+              .join("tests/fixtures/basic/src/index.js")
+              .to_string(),
+          )
+          .collect(),
+          Default::default(),
+        )
+        .await
+        .unwrap();
+
+      let errors: Vec<_> = compiler.compilation.get_errors().collect();
+      assert!(errors.is_empty());
+      assert!(
+        compiler
+          .compilation
+          .get_warnings()
+          .all(|warning| warning.code.as_deref() != Some("IncrementalDivergence"))
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn rebuild_verify_divergence() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rspack_core::{
+      Compilation, CompilationAsset, CompilationProcessAssets, Experiments, Plugin,
+      incremental::{IncrementalOptions, IncrementalPasses},
+      rspack_sources::{RawStringSource, SourceExt},
+    };
+    use rspack_error::Result;
+    use rspack_hook::{plugin, plugin_hook};
+    use rspack_tasks::within_compiler_context_for_testing;
+
+    // The hooks also run in the shadow full rebuild,
+    // so the asset content is different from the incremental rebuild.
+    #[plugin]
+    #[derive(Debug)]
+    struct CountingAssetPlugin {
+      count: AtomicUsize,
+    }
+
+    #[plugin_hook(CompilationProcessAssets for CountingAssetPlugin)]
+    async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+      let count = self.count.fetch_add(1, Ordering::Relaxed);
+      compilation.emit_asset(
+        "count.txt".into(),
+        CompilationAsset::new(
+          Some(RawStringSource::from(count.to_string()).boxed()),
+          Default::default(),
+        ),
+      );
+      Ok(())
+    }
+
+    impl Plugin for CountingAssetPlugin {
+      fn name(&self) -> &'static str {
+        "CountingAssetPlugin"
+      }
+
+      fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+        ctx
+          .compilation_hooks
+          .process_assets
+          .tap(process_assets::new(self));
+        Ok(())
+      }
+    }
+
+    within_compiler_context_for_testing(async {
+      let mut compiler = Compiler::builder()
+        .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+        .entry("main", "./src/index.js")
+        .plugin(Box::new(CountingAssetPlugin::new_inner(AtomicUsize::new(
+          0,
+        ))))
+        .experiments(Experiments::builder().incremental(IncrementalOptions {
+          silent: true,
+          verify: true,
+          passes: IncrementalPasses::all(),
+        }))
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+      compiler
+        .rebuild(
+          std::iter::once(
+            Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+              .join("tests/fixtures/basic/src/index.js")
+              .to_string(),
+          )
+          .collect(),
+          Default::default(),
+        )
+        .await
+        .unwrap();
+
+      let divergences: Vec<_> = compiler
+        .compilation
+        .get_warnings()
+        .filter(|warning| warning.code.as_deref() == Some("IncrementalDivergence"))
+        .map(|warning| warning.message.clone())
+        .collect();
+      assert_eq!(divergences.len(), 1);
+      assert!(divergences[0].contains("incremental.emitAssets"));
+      assert!(divergences[0].contains("the content of asset count.txt is different"));
+      // the assets of the incremental rebuild are kept
+      assert_eq!(
+        compiler
+          .compilation
+          .assets()
+          .get("count.txt")
+          .unwrap()
+          .get_source()
+          .unwrap()
+          .source()
+          .into_string_lossy(),
+        "1"
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn rebuild_dead_branch() {
+    use rspack_core::{
+      Experiments, Mode,
+      incremental::{IncrementalOptions, IncrementalPasses},
+    };
+    use rspack_paths::Utf8PathBuf;
+    use rspack_tasks::within_compiler_context_for_testing;
+
+    let context = Utf8PathBuf::from_path_buf(
+      std::env::temp_dir().join(format!("rspack-dead-branch-{}", std::process::id())),
+    )
+    .unwrap();
+    std::fs::create_dir_all(context.join("src")).unwrap();
+    std::fs::write(
+      context.join("src/index.js"),
+      "import { DEBUG } from './flags';\nconsole.log(DEBUG ? require('./debug') : null);",
+    )
+    .unwrap();
+    std::fs::write(context.join("src/flags.js"), "export const DEBUG = false;").unwrap();
+    std::fs::write(context.join("src/debug.js"), "module.exports = 'debug';").unwrap();
+    let has_debug_module = |compiler: &Compiler| {
+      compiler
+        .compilation
+        .get_module_graph()
+        .modules()
+        .keys()
+        .any(|identifier| identifier.ends_with("debug.js"))
+    };
+
+    within_compiler_context_for_testing(async {
+      let mut compiler = Compiler::builder()
+        .context(context.clone())
+        .mode(Mode::Production)
+        .entry("main", "./src/index.js")
+        .experiments(Experiments::builder().incremental(IncrementalOptions {
+          silent: true,
+          verify: true,
+          passes: IncrementalPasses::all(),
+        }))
+        .build()
+        .unwrap();
+
+      // the module required in the dead branch is not built
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      assert!(!has_debug_module(&compiler));
+
+      // the module is built once the branch is live
+      std::fs::write(context.join("src/flags.js"), "export const DEBUG = true;").unwrap();
+      compiler
+        .rebuild(
+          std::iter::once(context.join("src/flags.js").to_string()).collect(),
+          Default::default(),
+        )
+        .await
+        .unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      assert!(has_debug_module(&compiler));
+      assert!(
+        compiler
+          .compilation
+          .get_warnings()
+          .all(|warning| warning.code.as_deref() != Some("IncrementalDivergence"))
+      );
+    })
+    .await;
+
+    std::fs::remove_dir_all(&context).unwrap();
+  }
 }
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+  use rspack::builder::{Builder as _, ExperimentsBuilder};
+  use rspack_core::{
+    CacheOptions, Compiler, ExperimentCacheOptions, LogType, Mode,
+    cache::persistent::{
+      CacheResetReason, PersistentCacheOptions,
+      snapshot::{SnapshotOptions, ValidateResult},
+      storage::{StorageOptions, StoragePackOptions},
+    },
+  };
+  use rspack_paths::Utf8PathBuf;
+
+  fn build_compiler(context: &Utf8PathBuf) -> Compiler {
+    Compiler::builder()
+      .context(context.clone())
+      .mode(Mode::Production)
+      .entry("main", "./src/index.js")
+      .cache(CacheOptions::Memory {
+        max_generations: None,
+      })
+      .experiments(
+        ExperimentsBuilder::default().cache(ExperimentCacheOptions::Persistent(
+          PersistentCacheOptions {
+            build_dependencies: vec![],
+            version: String::new(),
+            snapshot: SnapshotOptions::new(vec![], vec![], vec![]),
+            storage: StorageOptions::FileSystem {
+              directory: context.join("cache").into(),
+            },
+            storage_pack: StoragePackOptions::default(),
+          },
+        )),
+      )
+      .build()
+      .unwrap()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn cache_invalidation_report() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    let context = Utf8PathBuf::from_path_buf(
+      std::env::temp_dir().join(format!("rspack-cache-{}", std::process::id())),
+    )
+    .unwrap();
+    std::fs::create_dir_all(context.join("src")).unwrap();
+    std::fs::write(
+      context.join("src/index.js"),
+      "import a from './a';\nconsole.log(a);",
+    )
+    .unwrap();
+    std::fs::write(context.join("src/a.js"), "export default 1;").unwrap();
+
+    // each build runs in a new compiler context, as it would in a new process
+    within_compiler_context_for_testing(async {
+      let mut compiler = build_compiler(&context);
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      let stats = compiler.compilation.get_stats();
+      let report = stats.get_cache_invalidation_report().unwrap();
+      assert!(matches!(
+        report.reset,
+        Some(CacheResetReason::VersionNotFound { .. })
+      ));
+      assert!(report.paths.is_empty());
+      compiler.close().await.unwrap();
+    })
+    .await;
+
+    // make sure the modified time is changed
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    std::fs::write(context.join("src/a.js"), "export default 2;").unwrap();
+
+    within_compiler_context_for_testing(async {
+      let mut compiler = build_compiler(&context);
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      let stats = compiler.compilation.get_stats();
+      let report = stats.get_cache_invalidation_report().unwrap();
+      assert!(report.reset.is_none());
+      assert_eq!(report.paths.len(), 1);
+      assert_eq!(
+        report.paths[0].path.as_ref(),
+        context.join("src/a.js").as_std_path()
+      );
+      assert!(matches!(report.paths[0].result, ValidateResult::Modified));
+      assert_eq!(report.modules.len(), 1);
+      assert!(report.modules[0].ends_with("src/a.js"));
+      compiler.close().await.unwrap();
+    })
+    .await;
+
+    std::fs::remove_dir_all(&context).unwrap();
+  }
+
+  /// The sources and hashes of the code generation results of each module.
+  fn code_generation_results(compiler: &Compiler) -> Vec<(String, String, Option<String>)> {
+    let (map, results) = compiler.compilation.code_generation_results.inner();
+    let mut res = map
+      .iter()
+      .flat_map(|(module, runtimes)| {
+        runtimes.values().map(move |id| {
+          let result = results.get(id).unwrap();
+          let mut sources = result
+            .inner()
+            .iter()
+            .map(|(source_type, source)| {
+              format!("{source_type}: {}", source.source().into_string_lossy())
+            })
+            .collect::<Vec<_>>();
+          sources.sort();
+          (
+            module.to_string(),
+            sources.join("\n"),
+            result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          )
+        })
+      })
+      .collect::<Vec<_>>();
+    res.sort();
+    res
+  }
+
+  /// The hit and total count of the module code generation cache.
+  fn code_generation_cache_count(compiler: &Compiler) -> (u32, u32) {
+    compiler
+      .compilation
+      .get_logging()
+      .iter()
+      .flat_map(|entry| entry.value().clone())
+      .find_map(|log| match log {
+        LogType::Cache {
+          label: "module code generation cache",
+          hit,
+          total,
+        } => Some((hit, total)),
+        _ => None,
+      })
+      .unwrap()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn code_generation_cache_restore() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    let context = Utf8PathBuf::from_path_buf(
+      std::env::temp_dir().join(format!("rspack-cache-codegen-{}", std::process::id())),
+    )
+    .unwrap();
+    std::fs::create_dir_all(context.join("src")).unwrap();
+    std::fs::write(
+      context.join("src/index.js"),
+      "import('./a').then(console.log);",
+    )
+    .unwrap();
+    std::fs::write(context.join("src/a.js"), "export default 1;").unwrap();
+
+    let cold = within_compiler_context_for_testing(async {
+      let mut compiler = build_compiler(&context);
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      assert_eq!(code_generation_cache_count(&compiler).0, 0);
+      let results = code_generation_results(&compiler);
+      compiler.close().await.unwrap();
+      results
+    })
+    .await;
+
+    // the restored results are used without generating the code again
+    within_compiler_context_for_testing(async {
+      let mut compiler = build_compiler(&context);
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      let (hit, total) = code_generation_cache_count(&compiler);
+      assert!(total > 0);
+      assert_eq!(hit, total);
+      assert_eq!(code_generation_results(&compiler), cold);
+      compiler.close().await.unwrap();
+    })
+    .await;
+
+    std::fs::remove_dir_all(&context).unwrap();
+  }
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod tests {
+  use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+  };
+
+  use futures::StreamExt;
+  use rspack::{
+    builder::Builder as _,
+    watch::{Watch, WatchOptions},
+  };
+  use rspack_collections::IdentifierSet;
+  use rspack_core::{CompilationId, Compiler, CompilerId, Mode, Module, Plugin};
+  use rspack_paths::Utf8PathBuf;
+  use rspack_plugin_lazy_compilation::{
+    Backend, HttpBackend, HttpBackendOptions, LazyCompilationPlugin, LazyCompilationTestCheck,
+  };
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+  };
+
+  #[derive(Debug)]
+  struct LazyAll;
+
+  #[async_trait::async_trait]
+  impl LazyCompilationTestCheck for LazyAll {
+    async fn test(&self, _: CompilerId, _: CompilationId, _: &dyn Module) -> bool {
+      true
+    }
+  }
+
+  /// Drains the modules activated since the last compilation, like the JavaScript backend.
+  #[derive(Debug, Clone, Default)]
+  struct DrainingBackend(Arc<Mutex<IdentifierSet>>);
+
+  #[async_trait::async_trait]
+  impl Backend for DrainingBackend {
+    async fn current_active_modules(&mut self) -> rspack_error::Result<IdentifierSet> {
+      Ok(std::mem::take(&mut self.0.lock().unwrap()))
+    }
+  }
+
+  async fn post(backend: &HttpBackend, body: &str) -> String {
+    let url = backend.url().strip_prefix("http://").unwrap();
+    let (host, path) = url.split_at(url.find('/').unwrap());
+    let mut stream = TcpStream::connect(host).await.unwrap();
+    stream
+      .write_all(
+        format!(
+          "POST {path} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+          body.len()
+        )
+        .as_bytes(),
+      )
+      .await
+      .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn http_backend_rebuild() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let context = Utf8PathBuf::from_path_buf(
+        std::env::temp_dir().join(format!("rspack-lazy-compilation-{}", std::process::id())),
+      )
+      .unwrap();
+      std::fs::create_dir_all(context.join("src")).unwrap();
+      std::fs::write(
+        context.join("src/index.js"),
+        "import('./lazy').then(console.log);",
+      )
+      .unwrap();
+      std::fs::write(context.join("src/lazy.js"), "export default 'lazy';").unwrap();
+      std::fs::write(
+        context.join("src/client.js"),
+        "exports.activate = function () { return function () {}; };",
+      )
+      .unwrap();
+
+      let backend = HttpBackend::listen(HttpBackendOptions::default())
+        .await
+        .unwrap();
+      let plugin = LazyCompilationPlugin::<_, LazyAll>::new(
+        backend.clone(),
+        None,
+        false,
+        true,
+        backend.client_request(context.join("src/client.js").as_str()),
+      );
+      let compiler = Compiler::builder()
+        .context(context.clone())
+        .mode(Mode::Development)
+        .entry("main", "./src/index.js")
+        .plugin(Box::new(plugin) as Box<dyn Plugin>)
+        .build()
+        .unwrap();
+      let mut watching = compiler.watch(WatchOptions::default());
+      let invalidator = watching.invalidator();
+      backend.on_invalidate(move || invalidator.invalidate());
+
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      let lazy_chunk = context.join("dist/src_lazy_js.js");
+      assert!(!lazy_chunk.exists());
+
+      // the module is built once the client requests it
+      let module = format!("lazy-compilation-proxy|{}", context.join("src/lazy.js"));
+      assert!(post(&backend, &module).await.starts_with("HTTP/1.1 200 OK"));
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      assert!(result.changed_files.is_empty());
+      assert!(lazy_chunk.exists());
+
+      let compiler = watching.close().await.unwrap();
+      assert!(
+        compiler
+          .compilation
+          .get_module_graph()
+          .modules()
+          .keys()
+          .any(|identifier| identifier.as_str() == context.join("src/lazy.js").as_str())
+      );
+
+      std::fs::remove_dir_all(&context).unwrap();
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn draining_backend_replaces_active_modules() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let context = Utf8PathBuf::from_path_buf(std::env::temp_dir().join(format!(
+        "rspack-lazy-compilation-draining-{}",
+        std::process::id()
+      )))
+      .unwrap();
+      std::fs::create_dir_all(context.join("src")).unwrap();
+      std::fs::write(
+        context.join("src/index.js"),
+        "import('./lazy').then(console.log);",
+      )
+      .unwrap();
+      std::fs::write(context.join("src/lazy.js"), "export default 'lazy';").unwrap();
+      std::fs::write(
+        context.join("src/client.js"),
+        "exports.activate = function () { return function () {}; };",
+      )
+      .unwrap();
+
+      let backend = DrainingBackend::default();
+      let plugin = LazyCompilationPlugin::<_, LazyAll>::new(
+        backend.clone(),
+        None,
+        false,
+        true,
+        context.join("src/client.js").to_string(),
+      );
+      let mut compiler = Compiler::builder()
+        .context(context.clone())
+        .mode(Mode::Development)
+        .entry("main", "./src/index.js")
+        .plugin(Box::new(plugin) as Box<dyn Plugin>)
+        .build()
+        .unwrap();
+      let lazy_built = |compiler: &Compiler| {
+        compiler
+          .compilation
+          .get_module_graph()
+          .modules()
+          .keys()
+          .any(|identifier| identifier.as_str() == context.join("src/lazy.js").as_str())
+      };
+
+      compiler.build().await.unwrap();
+      assert!(!lazy_built(&compiler));
+
+      backend
+        .0
+        .lock()
+        .unwrap()
+        .insert(format!("lazy-compilation-proxy|{}", context.join("src/lazy.js")).into());
+      compiler
+        .rebuild(Default::default(), Default::default())
+        .await
+        .unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      assert!(lazy_built(&compiler));
+
+      // the backend doesn't expire modules, so the recreated proxy is inactive again
+      for code in [
+        "console.log('lazy');",
+        "import('./lazy').then(console.log);",
+      ] {
+        std::fs::write(context.join("src/index.js"), code).unwrap();
+        compiler
+          .rebuild(
+            std::iter::once(context.join("src/index.js").to_string()).collect(),
+            Default::default(),
+          )
+          .await
+          .unwrap();
+        assert!(compiler.compilation.get_errors().next().is_none());
+        assert!(!lazy_built(&compiler));
+      }
+
+      std::fs::remove_dir_all(&context).unwrap();
+    })
+    .await;
+  }
+}
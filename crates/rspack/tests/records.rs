@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+  use rspack::builder::Builder as _;
+  use rspack_core::{
+    ChunkGraph, Compiler, Experiments, Mode, Optimization,
+    incremental::{IncrementalOptions, IncrementalPasses},
+  };
+  use rspack_paths::Utf8PathBuf;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn records_revive_ids() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let context = Utf8PathBuf::from_path_buf(
+        std::env::temp_dir().join(format!("rspack-records-{}", std::process::id())),
+      )
+      .unwrap();
+      std::fs::create_dir_all(context.join("src")).unwrap();
+      std::fs::write(
+        context.join("src/index.js"),
+        "import('./a').then(console.log);",
+      )
+      .unwrap();
+      std::fs::write(context.join("src/a.js"), "export default 1;").unwrap();
+      std::fs::write(
+        context.join("records.json"),
+        r#"{
+  "modules": { "byIdentifier": { "./src/a.js": "custom-a" } },
+  "chunks": { "byName": { "main": 42 } }
+}"#,
+      )
+      .unwrap();
+
+      let mut compiler = Compiler::builder()
+        .context(context.clone())
+        .mode(Mode::Production)
+        .entry("main", "./src/index.js")
+        .records_path("records.json")
+        .optimization(Optimization::builder().concatenate_modules(false))
+        .experiments(Experiments::builder().incremental(IncrementalOptions {
+          silent: true,
+          verify: false,
+          passes: IncrementalPasses::all(),
+        }))
+        .build()
+        .unwrap();
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+
+      let records: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(context.join("records.json")).unwrap()).unwrap();
+      assert_eq!(
+        records["modules"]["byIdentifier"]["./src/a.js"],
+        serde_json::json!("custom-a")
+      );
+      assert_eq!(records["chunks"]["byName"]["main"], serde_json::json!(42));
+      assert!(
+        records["modules"]["byIdentifier"]
+          .get("./src/index.js")
+          .is_some()
+      );
+
+      // the revived ids are kept when the module ids are reassigned in a rebuild
+      std::fs::write(context.join("src/a.js"), "export default 2;").unwrap();
+      compiler
+        .rebuild(
+          std::iter::once(context.join("src/a.js").to_string()).collect(),
+          Default::default(),
+        )
+        .await
+        .unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+      let module = compiler
+        .compilation
+        .get_module_graph()
+        .modules()
+        .keys()
+        .find(|identifier| identifier.ends_with("a.js"))
+        .copied()
+        .unwrap();
+      assert_eq!(
+        ChunkGraph::get_module_id(&compiler.compilation.module_ids_artifact, module)
+          .map(|id| id.to_string()),
+        Some("custom-a".to_string())
+      );
+      let records: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(context.join("records.json")).unwrap()).unwrap();
+      assert_eq!(
+        records["modules"]["byIdentifier"]["./src/a.js"],
+        serde_json::json!("custom-a")
+      );
+
+      std::fs::remove_dir_all(&context).unwrap();
+    })
+    .await;
+  }
+}
@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use futures::StreamExt;
+  use rspack::{
+    builder::Builder as _,
+    watch::{Watch, WatchOptions},
+  };
+  use rspack_core::Compiler;
+  use rspack_paths::Utf8PathBuf;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn watch_rebuild() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let context = Utf8PathBuf::from_path_buf(
+        std::env::temp_dir().join(format!("rspack-watch-{}", std::process::id())),
+      )
+      .unwrap();
+      let entry = context.join("src/index.js");
+      std::fs::create_dir_all(entry.parent().unwrap()).unwrap();
+      std::fs::write(&entry, "console.log(1);").unwrap();
+
+      let compiler = Compiler::builder()
+        .context(context.clone())
+        .entry("main", "./src/index.js")
+        .build()
+        .unwrap();
+      let mut watching = compiler.watch(WatchOptions::default());
+
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      assert!(result.changed_files.is_empty());
+
+      std::fs::write(&entry, "console.log(2);").unwrap();
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      assert!(result.changed_files.contains(entry.as_str()));
+
+      watching.invalidator().invalidate();
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      assert!(result.changed_files.is_empty());
+
+      let compiler = watching.close().await.unwrap();
+      let asset = compiler.compilation.assets().get("main.js").unwrap();
+      assert_eq!(
+        asset.source.as_ref().unwrap().source().into_string_lossy(),
+        "console.log(2);"
+      );
+
+      std::fs::remove_dir_all(&context).unwrap();
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn watch_massive_change() {
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let context = Utf8PathBuf::from_path_buf(
+        std::env::temp_dir().join(format!("rspack-watch-massive-{}", std::process::id())),
+      )
+      .unwrap();
+      let entry = context.join("src/index.js");
+      std::fs::create_dir_all(entry.parent().unwrap()).unwrap();
+      std::fs::write(&entry, "console.log(1);").unwrap();
+
+      let compiler = Compiler::builder()
+        .context(context.clone())
+        .entry("main", "./src/index.js")
+        .build()
+        .unwrap();
+      let mut watching = compiler.watch(WatchOptions {
+        massive_change_threshold: Some(0),
+        ..Default::default()
+      });
+
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+
+      // the reported files are rebuilt along with the rescanned ones
+      std::fs::write(&entry, "console.log(2);").unwrap();
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      assert!(result.changed_files.contains(entry.as_str()));
+
+      let compiler = watching.close().await.unwrap();
+      let asset = compiler.compilation.assets().get("main.js").unwrap();
+      assert_eq!(
+        asset.source.as_ref().unwrap().source().into_string_lossy(),
+        "console.log(2);"
+      );
+
+      std::fs::remove_dir_all(&context).unwrap();
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn watch_content_hash_with_change_during_build() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use rspack::builder::ExperimentsBuilder;
+    use rspack_core::{
+      AsyncModulesArtifact, CacheOptions, Compilation, CompilationFinishModules,
+      ExperimentCacheOptions, Plugin,
+      cache::persistent::{
+        PersistentCacheOptions,
+        snapshot::SnapshotOptions,
+        storage::{StorageOptions, StoragePackOptions},
+      },
+    };
+    use rspack_error::Result;
+    use rspack_hook::{plugin, plugin_hook};
+    use rspack_tasks::within_compiler_context_for_testing;
+
+    // The entry is modified after it is read by the build, before the snapshot hashes it.
+    #[plugin]
+    #[derive(Debug)]
+    struct ModifyEntryPlugin {
+      entry: Utf8PathBuf,
+      modified: AtomicBool,
+    }
+
+    #[plugin_hook(CompilationFinishModules for ModifyEntryPlugin)]
+    async fn finish_modules(
+      &self,
+      _compilation: &mut Compilation,
+      _async_modules_artifact: &mut AsyncModulesArtifact,
+    ) -> Result<()> {
+      if !self.modified.swap(true, Ordering::Relaxed) {
+        std::thread::sleep(Duration::from_millis(10));
+        std::fs::write(&self.entry, "console.log(2);").unwrap();
+      }
+      Ok(())
+    }
+
+    impl Plugin for ModifyEntryPlugin {
+      fn name(&self) -> &'static str {
+        "ModifyEntryPlugin"
+      }
+
+      fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+        ctx
+          .compilation_hooks
+          .finish_modules
+          .tap(finish_modules::new(self));
+        Ok(())
+      }
+    }
+
+    within_compiler_context_for_testing(async {
+      let context = Utf8PathBuf::from_path_buf(
+        std::env::temp_dir().join(format!("rspack-watch-content-hash-{}", std::process::id())),
+      )
+      .unwrap();
+      let entry = context.join("src/index.js");
+      std::fs::create_dir_all(entry.parent().unwrap()).unwrap();
+      std::fs::write(&entry, "console.log(1);").unwrap();
+
+      let compiler = Compiler::builder()
+        .context(context.clone())
+        .entry("main", "./src/index.js")
+        .plugin(Box::new(ModifyEntryPlugin::new_inner(
+          entry.clone(),
+          AtomicBool::new(false),
+        )))
+        .cache(CacheOptions::Memory {
+          max_generations: None,
+        })
+        .experiments(
+          ExperimentsBuilder::default().cache(ExperimentCacheOptions::Persistent(
+            PersistentCacheOptions {
+              build_dependencies: vec![],
+              version: String::new(),
+              snapshot: SnapshotOptions::new(vec![], vec![], vec![]),
+              storage: StorageOptions::FileSystem {
+                directory: context.join("cache").into(),
+              },
+              storage_pack: StoragePackOptions::default(),
+            },
+          )),
+        )
+        .build()
+        .unwrap();
+      let mut watching = compiler.watch(WatchOptions {
+        content_hash: true,
+        ..Default::default()
+      });
+
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+
+      // the hash of the snapshot is computed after the modification, so it is not reused
+      let result = tokio::time::timeout(Duration::from_secs(30), watching.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+      assert!(result.errors.is_empty());
+      assert!(result.changed_files.contains(entry.as_str()));
+
+      let compiler = watching.close().await.unwrap();
+      let asset = compiler.compilation.assets().get("main.js").unwrap();
+      assert!(
+        asset
+          .source
+          .as_ref()
+          .unwrap()
+          .source()
+          .into_string_lossy()
+          .contains("console.log(2)")
+      );
+
+      std::fs::remove_dir_all(&context).unwrap();
+    })
+    .await;
+  }
+}
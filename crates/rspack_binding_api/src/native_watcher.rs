@@ -32,6 +32,15 @@ pub struct NativeWatcherOptions {
 
   pub aggregate_timeout: Option<u32>,
 
+  /// The max timeout to aggregate events while events keep arriving.
+  pub max_aggregate_timeout: Option<u32>,
+
+  /// The number of files above which the aggregated events are handled as a massive change.
+  pub massive_change_threshold: Option<u32>,
+
+  /// Whether to ignore the changes of files whose content is not changed.
+  pub content_hash: Option<bool>,
+
   #[napi(ts_type = "string | string[] | RegExp")]
   /// The ignored paths for the watcher.
   /// It can be a single path, an array of paths, or a regular expression.
@@ -42,6 +51,8 @@ pub struct NativeWatcherOptions {
 pub struct NativeWatchResult {
   pub changed_files: Vec<String>,
   pub removed_files: Vec<String>,
+  /// Whether the number of changed files is above the `massiveChangeThreshold`.
+  pub massive_change: bool,
 }
 
 #[napi]
@@ -63,6 +74,9 @@ impl NativeWatcher {
         follow_symlinks: options.follow_symlinks.unwrap_or(false),
         poll_interval: options.poll_interval,
         aggregate_timeout: options.aggregate_timeout,
+        max_aggregate_timeout: options.max_aggregate_timeout,
+        massive_change_threshold: options.massive_change_threshold,
+        content_hash: options.content_hash.unwrap_or(false),
       },
       to_fs_watcher_ignored(options.ignored.clone()),
     );
@@ -193,25 +207,43 @@ impl JsEventHandler {
 
     Ok(Self { inner: callback })
   }
-}
 
-impl rspack_watcher::EventAggregateHandler for JsEventHandler {
-  fn on_event_handle(
+  fn call(
     &self,
     changed_files: rspack_util::fx_hash::FxHashSet<String>,
     deleted_files: rspack_util::fx_hash::FxHashSet<String>,
+    massive_change: bool,
   ) {
     let changed_files_vec: Vec<String> = changed_files.into_iter().collect();
     let deleted_files_vec: Vec<String> = deleted_files.into_iter().collect();
     let result = NativeWatchResult {
       changed_files: changed_files_vec,
       removed_files: deleted_files_vec,
+      massive_change,
     };
     self.inner.call(
       Ok(result),
       napi::threadsafe_function::ThreadsafeFunctionCallMode::NonBlocking,
     );
   }
+}
+
+impl rspack_watcher::EventAggregateHandler for JsEventHandler {
+  fn on_event_handle(
+    &self,
+    changed_files: rspack_util::fx_hash::FxHashSet<String>,
+    deleted_files: rspack_util::fx_hash::FxHashSet<String>,
+  ) {
+    self.call(changed_files, deleted_files, false);
+  }
+
+  fn on_massive_change(
+    &self,
+    changed_files: rspack_util::fx_hash::FxHashSet<String>,
+    deleted_files: rspack_util::fx_hash::FxHashSet<String>,
+  ) {
+    self.call(changed_files, deleted_files, true);
+  }
 
   fn on_error(&self, error: rspack_error::Error) {
     // Handle error, maybe log it or notify the user
@@ -60,6 +60,8 @@ pub struct RawOptions {
   pub profile: bool,
   pub amd: Option<String>,
   pub bail: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
   pub __references: References,
   #[napi(js_name = "__virtual_files")]
@@ -77,7 +79,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let mode = value.mode.unwrap_or_default().into();
     let module: ModuleOptions = value.module.try_into()?;
     let cache = value.cache.into();
-    let mut experiments: Experiments = value.experiments.into();
+    let mut experiments: Experiments = value.experiments.try_into()?;
     if let CacheOptions::Disabled = cache {
       experiments.incremental.passes = IncrementalPasses::empty();
     }
@@ -134,6 +136,8 @@ impl TryFrom<RawOptions> for CompilerOptions {
       profile: value.profile,
       amd: value.amd,
       bail: value.bail,
+      records_input_path: value.records_input_path.map(Into::into),
+      records_output_path: value.records_output_path.map(Into::into),
       __references: value.__references,
     })
   }
@@ -67,8 +67,8 @@ use rspack_plugin_ignore::IgnorePlugin;
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin,
   InlineExportsPlugin, JsPlugin, MangleExportsPlugin, ModuleConcatenationPlugin,
-  SideEffectsFlagPlugin, api_plugin::APIPlugin, define_plugin::DefinePlugin,
-  provide_plugin::ProvidePlugin, url_plugin::URLPlugin,
+  RemoveUnusedClassMembersPlugin, SideEffectsFlagPlugin, api_plugin::APIPlugin,
+  define_plugin::DefinePlugin, provide_plugin::ProvidePlugin, url_plugin::URLPlugin,
 };
 use rspack_plugin_json::JsonPlugin;
 use rspack_plugin_library::enable_library_plugin;
@@ -199,6 +199,7 @@ pub enum BuiltinPluginName {
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
   InlineExportsPlugin,
+  RemoveUnusedClassMembersPlugin,
   MangleExportsPlugin,
   ModuleConcatenationPlugin,
   CssModulesPlugin,
@@ -620,6 +621,9 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::InlineExportsPlugin => {
         plugins.push(InlineExportsPlugin::default().boxed())
       }
+      BuiltinPluginName::RemoveUnusedClassMembersPlugin => {
+        plugins.push(RemoveUnusedClassMembersPlugin::default().boxed())
+      }
       BuiltinPluginName::MangleExportsPlugin => plugins.push(
         MangleExportsPlugin::new(
           downcast_into::<bool>(self.options)
@@ -23,9 +23,12 @@ pub struct RawExperimentCacheOptionsPersistent {
   pub storage: Option<RawStorageOptions>,
 }
 
-impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
-  fn from(value: RawExperimentCacheOptionsPersistent) -> Self {
-    Self {
+impl TryFrom<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExperimentCacheOptionsPersistent) -> Result<Self, Self::Error> {
+    let storage = value.storage.unwrap_or_default();
+    Ok(Self {
       build_dependencies: value
         .build_dependencies
         .unwrap_or_default()
@@ -34,11 +37,13 @@ impl From<RawExperimentCacheOptionsPersistent> for PersistentCacheOptions {
         .collect(),
       version: value.version.unwrap_or_default(),
       snapshot: value.snapshot.unwrap_or_default().into(),
-      storage: value.storage.unwrap_or_default().into(),
-    }
+      storage_pack: (&storage).into(),
+      storage: storage.try_into()?,
+    })
   }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Default)]
 pub enum RawExperimentCache {
   #[default]
@@ -83,8 +88,8 @@ impl FromNapiValue for RawExperimentCache {
 
 pub fn normalize_raw_experiment_cache_options(
   options: RawExperimentCacheOptions,
-) -> ExperimentCacheOptions {
-  match options {
+) -> rspack_error::Result<ExperimentCacheOptions> {
+  Ok(match options {
     Either::A(options) => {
       if options {
         ExperimentCacheOptions::Memory
@@ -93,8 +98,10 @@ pub fn normalize_raw_experiment_cache_options(
       }
     }
     Either::B(options) => match options {
-      RawExperimentCache::Persistent(options) => ExperimentCacheOptions::Persistent(options.into()),
+      RawExperimentCache::Persistent(options) => {
+        ExperimentCacheOptions::Persistent(options.try_into()?)
+      }
       RawExperimentCache::Memory => ExperimentCacheOptions::Memory,
     },
-  }
+  })
 }
@@ -1,21 +1,60 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{
+  StorageCompression, StorageOptions, StoragePackOptions,
+};
+use rspack_error::error;
 
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawStorageOptions {
-  #[napi(ts_type = r#""filesystem""#)]
+  #[napi(ts_type = r#""filesystem" | "remote""#)]
   pub r#type: String,
   pub directory: String,
+  /// The directory to share the cache with other machines, required by remote storage.
+  pub remote_directory: Option<String>,
+  /// Whether to push the cache to `remoteDirectory` after saved.
+  pub push: Option<bool>,
+  #[napi(ts_type = r#""none" | "lz4" | "zstd""#)]
+  pub compression: Option<String>,
+  /// The max total size of all versions in the cache directory in bytes.
+  pub max_size: Option<f64>,
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
-    match value.r#type.as_str() {
+impl From<&RawStorageOptions> for StoragePackOptions {
+  fn from(value: &RawStorageOptions) -> Self {
+    let compression = match value.compression.as_deref() {
+      None | Some("none") => StorageCompression::None,
+      Some("lz4") => StorageCompression::Lz4,
+      Some("zstd") => StorageCompression::Zstd,
+      Some(s) => panic!("unsupported storage compression {s}"),
+    };
+    Self {
+      compression,
+      max_size: value.max_size.map(|size| size as u64),
+      ..Default::default()
+    }
+  }
+}
+
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStorageOptions) -> Result<Self, Self::Error> {
+    Ok(match value.r#type.as_str() {
       "filesystem" => StorageOptions::FileSystem {
         directory: value.directory.into(),
       },
+      "remote" => StorageOptions::Remote {
+        directory: value.directory.into(),
+        remote_directory: value
+          .remote_directory
+          .ok_or_else(|| {
+            error!("experiments.cache.storage.remoteDirectory is required by the remote storage")
+          })?
+          .into(),
+        push: value.push.unwrap_or(false),
+      },
       s => panic!("unsupported storage type {s}"),
-    }
+    })
   }
 }
@@ -5,6 +5,7 @@ use rspack_core::incremental::{IncrementalOptions, IncrementalPasses};
 #[napi(object)]
 pub struct RawIncremental {
   pub silent: bool,
+  pub verify: bool,
   // passes
   pub make: bool,
   pub infer_async_modules: bool,
@@ -12,6 +13,10 @@ pub struct RawIncremental {
   pub dependencies_diagnostics: bool,
   pub side_effects: bool,
   pub build_chunk_graph: bool,
+  pub module_concatenation: bool,
+  pub split_chunks: bool,
+  pub inline_exports: bool,
+  pub mangle_exports: bool,
   pub module_ids: bool,
   pub chunk_ids: bool,
   pub modules_hashes: bool,
@@ -44,6 +49,18 @@ impl From<RawIncremental> for IncrementalOptions {
     if value.build_chunk_graph {
       passes.insert(IncrementalPasses::BUILD_CHUNK_GRAPH);
     }
+    if value.module_concatenation {
+      passes.insert(IncrementalPasses::MODULE_CONCATENATION);
+    }
+    if value.split_chunks {
+      passes.insert(IncrementalPasses::SPLIT_CHUNKS);
+    }
+    if value.inline_exports {
+      passes.insert(IncrementalPasses::INLINE_EXPORTS);
+    }
+    if value.mangle_exports {
+      passes.insert(IncrementalPasses::MANGLE_EXPORTS);
+    }
     if value.module_ids {
       passes.insert(IncrementalPasses::MODULE_IDS);
     }
@@ -73,6 +90,7 @@ impl From<RawIncremental> for IncrementalOptions {
     }
     Self {
       silent: value.silent,
+      verify: value.verify,
       passes,
     }
   }
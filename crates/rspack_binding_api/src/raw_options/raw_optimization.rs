@@ -17,7 +17,9 @@ pub struct RawOptimizationOptions {
   #[napi(ts_type = "boolean | string")]
   pub mangle_exports: WithBool<String>,
   pub inline_exports: bool,
+  pub remove_unused_class_members: bool,
   pub concatenate_modules: bool,
+  pub concatenate_commonjs_modules: bool,
   pub avoid_entry_iife: bool,
 }
 
@@ -51,7 +53,9 @@ impl TryFrom<RawOptimizationOptions> for Optimization {
       inner_graph: value.inner_graph,
       mangle_exports: value.mangle_exports.into(),
       inline_exports: value.inline_exports,
+      remove_unused_class_members: value.remove_unused_class_members,
       concatenate_modules: value.concatenate_modules,
+      concatenate_commonjs_modules: value.concatenate_commonjs_modules,
       avoid_entry_iife: value.avoid_entry_iife,
       real_content_hash: value.real_content_hash,
     })
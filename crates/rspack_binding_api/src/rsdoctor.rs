@@ -302,6 +302,7 @@ pub struct JsRsdoctorModuleGraph {
   pub modules: Vec<JsRsdoctorModule>,
   pub dependencies: Vec<JsRsdoctorDependency>,
   pub chunk_modules: Vec<JsRsdoctorChunkModules>,
+  pub side_effects: Vec<JsRsdoctorSideEffect>,
 }
 
 impl From<RsdoctorModuleGraph> for JsRsdoctorModuleGraph {
@@ -310,6 +311,7 @@ impl From<RsdoctorModuleGraph> for JsRsdoctorModuleGraph {
       modules: value.modules.into_iter().map(|m| m.into()).collect(),
       dependencies: value.dependencies.into_iter().map(|d| d.into()).collect(),
       chunk_modules: value.chunk_modules.into_iter().map(|c| c.into()).collect(),
+      side_effects: value.side_effects.into_iter().map(|s| s.into()).collect(),
     }
   }
 }
@@ -9,6 +9,10 @@ use napi_derive::napi;
 use rspack_collections::IdentifierMap;
 use rspack_core::{
   EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  cache::persistent::{
+    CacheInvalidationReport, CacheResetReason,
+    snapshot::{InvalidatedPath, ValidateResult},
+  },
   rspack_sources::{RawBufferSource, Source, SourceValue},
 };
 use rspack_error::Severity;
@@ -459,6 +463,23 @@ impl<'a> From<rspack_core::StatsAssetInfoRelated<'a>> for JsStatsAssetInfoRelate
   }
 }
 
+#[napi(object, object_from_js = false)]
+pub struct JsStatsSideEffectsBailout<'a> {
+  pub r#type: &'a str,
+  pub loc: String,
+  pub statement: &'a str,
+}
+
+impl<'a> From<&'a rspack_core::SideEffectsBailoutItem> for JsStatsSideEffectsBailout<'a> {
+  fn from(item: &'a rspack_core::SideEffectsBailoutItem) -> Self {
+    Self {
+      r#type: &item.ty,
+      loc: item.loc.to_string(),
+      statement: &item.statement,
+    }
+  }
+}
+
 type JsStatsModuleSource<'a> = Either<CowStrWrapper<'a>, Buffer>;
 type JsStatsUsedExports = Either<AtomWrapper, AtomVecWrapper>;
 
@@ -506,6 +527,9 @@ pub struct JsStatsModuleCommonAttributes<'a> {
   #[napi(ts_type = "Array<string>")]
   pub optimization_bailout: Option<StringSliceWrapper<'a>>,
 
+  // sideEffectsBailout
+  pub side_effects_bailout: Option<JsStatsSideEffectsBailout<'a>>,
+
   // depth
   pub depth: Option<u32>,
 
@@ -634,6 +658,9 @@ impl<'a> TryFrom<StatsModule<'a>> for JsStatsModule<'a> {
       orphan: stats.orphan,
       provided_exports: stats.provided_exports.map(AtomVecWrapper::new),
       optimization_bailout: stats.optimization_bailout.map(StringSliceWrapper::new),
+      side_effects_bailout: stats
+        .side_effects_bailout
+        .map(JsStatsSideEffectsBailout::from),
       pre_order_index: stats.pre_order_index,
       post_order_index: stats.post_order_index,
       cached: stats.cached,
@@ -954,9 +981,73 @@ impl<'a> From<rspack_core::StatsAssetsByChunkName<'a>> for JsStatsAssetsByChunkN
   }
 }
 
+#[napi(object, object_from_js = false)]
+pub struct JsStatsInvalidatedPath {
+  pub path: String,
+  #[napi(ts_type = r#""modified" | "deleted""#)]
+  pub r#type: &'static str,
+  pub message: String,
+}
+
+impl From<&InvalidatedPath> for JsStatsInvalidatedPath {
+  fn from(item: &InvalidatedPath) -> Self {
+    Self {
+      path: item.path.to_string_lossy().to_string(),
+      r#type: match item.result {
+        ValidateResult::Deleted => "deleted",
+        _ => "modified",
+      },
+      message: item.to_string(),
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsCacheReset {
+  #[napi(ts_type = r#""versionNotFound" | "buildDependencies""#)]
+  pub r#type: &'static str,
+  pub version: Option<String>,
+  pub paths: Vec<JsStatsInvalidatedPath>,
+}
+
+impl From<&CacheResetReason> for JsStatsCacheReset {
+  fn from(reason: &CacheResetReason) -> Self {
+    match reason {
+      CacheResetReason::VersionNotFound { version } => Self {
+        r#type: "versionNotFound",
+        version: Some(version.clone()),
+        paths: vec![],
+      },
+      CacheResetReason::BuildDependencies(paths) => Self {
+        r#type: "buildDependencies",
+        version: None,
+        paths: paths.iter().map(Into::into).collect(),
+      },
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsCacheInvalidation {
+  pub reset: Option<JsStatsCacheReset>,
+  pub paths: Vec<JsStatsInvalidatedPath>,
+  pub modules: Vec<String>,
+}
+
+impl From<&CacheInvalidationReport> for JsStatsCacheInvalidation {
+  fn from(report: &CacheInvalidationReport) -> Self {
+    Self {
+      reset: report.reset.as_ref().map(Into::into),
+      paths: report.paths.iter().map(Into::into).collect(),
+      modules: report.modules.iter().map(|id| id.to_string()).collect(),
+    }
+  }
+}
+
 #[napi(object, object_to_js = false)]
 pub struct JsStatsOptions {
   pub assets: bool,
+  pub cache_invalidation: bool,
   pub cached_modules: bool,
   pub chunks: bool,
   pub chunk_group_auxiliary: bool,
@@ -975,6 +1066,7 @@ pub struct JsStatsOptions {
   pub optimization_bailout: bool,
   pub provided_exports: bool,
   pub reasons: bool,
+  pub side_effects_bailout: bool,
   pub source: bool,
   pub used_exports: bool,
   pub warnings: bool,
@@ -989,6 +1081,7 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
 
     Self {
       assets: value.assets,
+      cache_invalidation: value.cache_invalidation,
       cached_modules: value.cached_modules,
       chunks: value.chunks,
       chunk_group_auxiliary: value.chunk_group_auxiliary,
@@ -1007,6 +1100,7 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
       optimization_bailout: value.optimization_bailout,
       provided_exports: value.provided_exports,
       reasons: value.reasons,
+      side_effects_bailout: value.side_effects_bailout,
       source: value.source,
       used_exports: value.used_exports,
       warnings: value.warnings,
@@ -1024,6 +1118,7 @@ pub struct JsStatsGetAssets<'a> {
 pub struct JsStatsCompilation<'a> {
   pub assets: Option<Vec<JsStatsAsset<'a>>>,
   pub assets_by_chunk_name: Option<Vec<JsStatsAssetsByChunkName<'a>>>,
+  pub cache_invalidation: Option<JsStatsCacheInvalidation>,
   #[napi(ts_type = "Array<JsStatsChunk>")]
   pub chunks: Option<napi_value>,
   pub entrypoints: Option<Vec<JsStatsChunkGroup<'a>>>,
@@ -1082,6 +1177,11 @@ impl JsStats {
 
     let hash = options.hash.then(|| self.hash()).flatten();
 
+    let cache_invalidation = options
+      .cache_invalidation
+      .then(|| self.inner.get_cache_invalidation_report().map(Into::into))
+      .flatten();
+
     let (assets, assets_by_chunk_name) = if options.assets {
       let asts = self.assets();
       (Some(asts.assets), Some(asts.assets_by_chunk_name))
@@ -1121,6 +1221,7 @@ impl JsStats {
     Ok(JsStatsCompilationWrapper(JsStatsCompilation {
       assets,
       assets_by_chunk_name,
+      cache_invalidation,
       chunks,
       entrypoints,
       errors,
@@ -33,7 +33,7 @@ pub trait ItemUkey {
 }
 
 /// Ukey stands for Unique key
-#[rspack_cacheable::cacheable]
+#[rspack_cacheable::cacheable(hashable)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(allocative, derive(allocative::Allocative))]
 pub struct Ukey(u32);
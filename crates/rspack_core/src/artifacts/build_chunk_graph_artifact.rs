@@ -8,22 +8,26 @@ use tracing::instrument;
 
 use crate::{
   ArtifactExt, ChunkByUkey, ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation,
-  Logger, ModuleIdentifier,
+  Logger, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
   build_chunk_graph::code_splitter::{CodeSplitter, DependenciesBlockIdentifier},
   incremental::{IncrementalPasses, Mutation},
 };
 
 #[derive(Debug, Default)]
 pub struct CodeSplittingCache {
-  chunk_by_ukey: ChunkByUkey,
-  chunk_graph: ChunkGraph,
-  chunk_group_by_ukey: ChunkGroupByUkey,
-  entrypoints: IndexMap<String, ChunkGroupUkey>,
-  async_entrypoints: Vec<ChunkGroupUkey>,
-  named_chunk_groups: HashMap<String, ChunkGroupUkey>,
-  named_chunks: HashMap<String, ChunkUkey>,
+  pub(crate) chunk_by_ukey: ChunkByUkey,
+  pub(crate) chunk_graph: ChunkGraph,
+  pub(crate) chunk_group_by_ukey: ChunkGroupByUkey,
+  pub(crate) entrypoints: IndexMap<String, ChunkGroupUkey>,
+  pub(crate) async_entrypoints: Vec<ChunkGroupUkey>,
+  pub(crate) named_chunk_groups: HashMap<String, ChunkGroupUkey>,
+  pub(crate) named_chunks: HashMap<String, ChunkUkey>,
   pub(crate) code_splitter: CodeSplitter,
   pub(crate) module_idx: IdentifierMap<(u32, u32)>,
+  /// The active outgoing modules of each module, only exists when the cache is restored
+  /// from persistent cache. The code splitter is not persisted, so the restored chunk graph
+  /// is validated by comparing these outgoings with the restored module graph.
+  pub(crate) module_outgoings: Option<IdentifierMap<Vec<ModuleIdentifier>>>,
 }
 
 impl CodeSplittingCache {
@@ -32,23 +36,57 @@ impl CodeSplittingCache {
   // we don't need to check if module has changed its incomings
   // if it changes, the incoming module changes its outgoings as well
   fn can_skip_rebuilding(&self, this_compilation: &Compilation) -> bool {
+    let logger = this_compilation.get_logger("rspack.Compilation.codeSplittingCache");
+
+    if !this_compilation.entries.keys().eq(self.entrypoints.keys()) {
+      logger.log("entrypoints change detected, rebuilding chunk graph");
+      return false;
+    }
+
+    if let Some(module_outgoings) = &self.module_outgoings {
+      return Self::can_skip_rebuilding_restored(this_compilation, module_outgoings);
+    }
     self.can_skip_rebuilding_legacy(this_compilation)
   }
 
-  fn can_skip_rebuilding_legacy(&self, this_compilation: &Compilation) -> bool {
+  // the restored cache has no mutations to rely on,
+  // so all modules are checked against the persisted outgoings
+  fn can_skip_rebuilding_restored(
+    this_compilation: &Compilation,
+    module_outgoings: &IdentifierMap<Vec<ModuleIdentifier>>,
+  ) -> bool {
     let logger = this_compilation.get_logger("rspack.Compilation.codeSplittingCache");
+    let module_graph = this_compilation.get_module_graph();
+    let module_graph_cache = &this_compilation.module_graph_cache_artifact;
+    let modules = module_graph.modules();
 
-    if !this_compilation.entries.keys().eq(
-      this_compilation
-        .build_chunk_graph_artifact
-        .code_splitting_cache
-        .entrypoints
-        .keys(),
-    ) {
-      logger.log("entrypoints change detected, rebuilding chunk graph");
+    if modules.len() != module_outgoings.len() {
+      logger.log("module count change detected, rebuilding restored chunk graph");
       return false;
     }
 
+    for module in modules.keys() {
+      let Some(previous_outgoings) = module_outgoings.get(module) else {
+        logger.log(format!(
+          "new module detected: {module}, rebuilding restored chunk graph"
+        ));
+        return false;
+      };
+      if *previous_outgoings != get_module_outgoings(module_graph, module_graph_cache, module) {
+        logger.log(format!(
+          "module outgoings change detected: {module}, rebuilding restored chunk graph"
+        ));
+        return false;
+      }
+    }
+
+    logger.log("restored chunk graph is valid, skip rebuilding");
+    true
+  }
+
+  fn can_skip_rebuilding_legacy(&self, this_compilation: &Compilation) -> bool {
+    let logger = this_compilation.get_logger("rspack.Compilation.codeSplittingCache");
+
     let Some(mutations) = this_compilation
       .incremental
       .mutations_read(IncrementalPasses::MAKE)
@@ -78,41 +116,7 @@ impl CodeSplittingCache {
     }
 
     for module in affected_modules {
-      let outgoings: Vec<ModuleIdentifier> = {
-        let mut res = vec![];
-        let mut active_modules = IdentifierIndexMap::<Vec<_>>::default();
-        let module = module_graph
-          .module_graph_module_by_identifier(&module)
-          .expect("should have module");
-        module
-          .all_dependencies
-          .iter()
-          .filter(|dep_id| {
-            module_graph
-              .dependency_by_id(dep_id)
-              .as_module_dependency()
-              .is_none_or(|module_dep| !module_dep.weak())
-          })
-          .filter_map(|dep| module_graph.connection_by_dependency_id(dep))
-          .for_each(|conn| {
-            let m = *conn.module_identifier();
-            active_modules.entry(m).or_default().push(conn);
-          });
-
-        'outer: for (m, connections) in active_modules {
-          for conn in connections {
-            if conn
-              .active_state(module_graph, None, module_graph_cache)
-              .is_not_false()
-            {
-              res.push(m);
-              continue 'outer;
-            }
-          }
-        }
-
-        res
-      };
+      let outgoings = get_module_outgoings(module_graph, module_graph_cache, &module);
 
       // get outgoings from all runtimes in the previous compilation
       let mut previous_modules = IdentifierIndexMap::default();
@@ -149,7 +153,7 @@ impl CodeSplittingCache {
         .filter(|(_, conn_state)| conn_state.is_not_false())
         .map(|(m, _)| *m)
         .collect::<Vec<_>>()
-        != outgoings.clone()
+        != outgoings
       {
         // we find one module's outgoings has changed
         // we cannot skip rebuilding
@@ -162,6 +166,48 @@ impl CodeSplittingCache {
   }
 }
 
+/// Get the modules that are imported by the module through active non-weak connections,
+/// in the order of the dependencies
+pub(crate) fn get_module_outgoings(
+  module_graph: &ModuleGraph,
+  module_graph_cache: &ModuleGraphCacheArtifact,
+  module: &ModuleIdentifier,
+) -> Vec<ModuleIdentifier> {
+  let mut res = vec![];
+  let mut active_modules = IdentifierIndexMap::<Vec<_>>::default();
+  let module = module_graph
+    .module_graph_module_by_identifier(module)
+    .expect("should have module");
+  module
+    .all_dependencies
+    .iter()
+    .filter(|dep_id| {
+      module_graph
+        .dependency_by_id(dep_id)
+        .as_module_dependency()
+        .is_none_or(|module_dep| !module_dep.weak())
+    })
+    .filter_map(|dep| module_graph.connection_by_dependency_id(dep))
+    .for_each(|conn| {
+      let m = *conn.module_identifier();
+      active_modules.entry(m).or_default().push(conn);
+    });
+
+  'outer: for (m, connections) in active_modules {
+    for conn in connections {
+      if conn
+        .active_state(module_graph, None, module_graph_cache)
+        .is_not_false()
+      {
+        res.push(m);
+        continue 'outer;
+      }
+    }
+  }
+
+  res
+}
+
 #[instrument(name = "Compilation:code_splitting",target=TRACING_BENCH_TARGET, skip_all)]
 pub(crate) async fn use_code_splitting_cache<'a, T, F>(
   compilation: &'a mut Compilation,
@@ -179,12 +225,12 @@ where
   let incremental_code_splitting = compilation
     .incremental
     .passes_enabled(IncrementalPasses::BUILD_CHUNK_GRAPH);
-  let no_change = compilation
-    .build_chunk_graph_artifact
-    .code_splitting_cache
-    .can_skip_rebuilding(compilation);
+  let cache = &compilation.build_chunk_graph_artifact.code_splitting_cache;
+  // the restored cache has no code splitter state, so it can only be reused as a whole
+  let restored = cache.module_outgoings.is_some();
+  let no_change = cache.can_skip_rebuilding(compilation);
 
-  if incremental_code_splitting || no_change {
+  if (incremental_code_splitting && !restored) || no_change {
     let cache = &mut compilation.build_chunk_graph_artifact.code_splitting_cache;
     rayon::scope(|s| {
       s.spawn(|_| compilation.chunk_by_ukey = cache.chunk_by_ukey.clone());
@@ -232,6 +278,7 @@ where
   }
   let cache = &mut compilation.build_chunk_graph_artifact.code_splitting_cache;
   cache.module_idx = map;
+  cache.module_outgoings = None;
   Ok(())
 }
 
@@ -240,6 +287,12 @@ pub struct BuildChunkGraphArtifact {
   pub code_splitting_cache: CodeSplittingCache,
 }
 
+impl BuildChunkGraphArtifact {
+  pub fn is_empty(&self) -> bool {
+    self.code_splitting_cache.chunk_by_ukey.is_empty()
+  }
+}
+
 impl ArtifactExt for BuildChunkGraphArtifact {
   const PASS: IncrementalPasses = IncrementalPasses::BUILD_CHUNK_GRAPH;
 }
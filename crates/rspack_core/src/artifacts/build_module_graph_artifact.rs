@@ -50,6 +50,9 @@ pub struct BuildModuleGraphArtifact {
   /// Module graph data
   pub module_graph: ModuleGraph,
   pub module_to_lazy_make: ModuleToLazyMake,
+  /// The dependencies in the branches guarded by imported constants, they are processed after the
+  /// modules declaring the constants are built, unless the branches turn out to be dead.
+  pub deferred_branch_dependencies: HashSet<DependencyId>,
 
   // statistical data, which can be regenerated from module_graph_partial and used as index.
   /// Diagnostic non-empty modules in the module graph.
@@ -112,6 +115,7 @@ impl BuildModuleGraphArtifact {
       .chain(mgm.incoming_connections().clone())
     {
       self.make_failed_dependencies.remove(&dep_id);
+      self.deferred_branch_dependencies.remove(&dep_id);
 
       let dep = mg.dependency_by_id_mut(&dep_id);
       if let Some(info) = FactorizeInfo::revoke(dep) {
@@ -33,6 +33,36 @@ impl ChunkRenderCacheArtifact {
       storage.start_next_generation();
     }
   }
+  /// Record the changed cache items so that they can be synced to persistent cache.
+  pub(crate) fn track_changes(&mut self) {
+    if let Some(storage) = &mut self.storage {
+      storage.track_changes();
+    }
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self
+      .storage
+      .as_ref()
+      .is_none_or(|storage| storage.is_empty())
+  }
+
+  /// Restore a cache item from persistent cache.
+  pub(crate) fn restore(&self, cache_key: Identifier, source: BoxSource) {
+    if let Some(storage) = &self.storage {
+      storage.restore(cache_key, source);
+    }
+  }
+
+  /// Take the cache items which are set or removed since the last call.
+  pub(crate) fn take_changes(&self) -> (Vec<(Identifier, BoxSource)>, Vec<Identifier>) {
+    self
+      .storage
+      .as_ref()
+      .map(|storage| storage.take_changes())
+      .unwrap_or_default()
+  }
+
   pub async fn use_cache<G, F>(
     &self,
     compilation: &Compilation,
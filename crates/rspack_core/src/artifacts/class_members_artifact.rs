@@ -0,0 +1,24 @@
+use std::ops::{Deref, DerefMut};
+
+use rustc_hash::FxHashSet;
+
+use crate::DependencyId;
+
+/// The dependencies of the class members that are unused and will be removed
+/// in code generation, see `optimization.removeUnusedClassMembers`
+#[derive(Debug, Default, Clone)]
+pub struct ClassMembersArtifact(FxHashSet<DependencyId>);
+
+impl Deref for ClassMembersArtifact {
+  type Target = FxHashSet<DependencyId>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl DerefMut for ClassMembersArtifact {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
@@ -6,6 +6,7 @@ use std::{
 };
 
 use anymap::CloneAny;
+use rspack_cacheable::cacheable;
 use rspack_collections::IdentifierMap;
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use rspack_sources::BoxSource;
@@ -18,6 +19,7 @@ use crate::{
   RuntimeGlobals, RuntimeSpec, RuntimeSpecMap, SourceType, incremental::IncrementalPasses,
 };
 
+#[cacheable]
 #[derive(Clone, Debug)]
 pub struct CodeGenerationDataUrl {
   inner: String,
@@ -34,12 +36,15 @@ impl CodeGenerationDataUrl {
 }
 
 // For performance, mark the js modules containing AUTO_PUBLIC_PATH_PLACEHOLDER
+#[cacheable]
 #[derive(Clone, Debug)]
 pub struct CodeGenerationPublicPathAutoReplace(pub bool);
 
+#[cacheable]
 #[derive(Clone, Debug)]
 pub struct URLStaticMode;
 
+#[cacheable]
 #[derive(Clone, Debug)]
 pub struct CodeGenerationDataFilename {
   filename: String,
@@ -63,6 +68,7 @@ impl CodeGenerationDataFilename {
   }
 }
 
+#[cacheable]
 #[derive(Clone, Debug)]
 pub struct CodeGenerationDataAssetInfo {
   inner: AssetInfo,
@@ -0,0 +1,38 @@
+use std::ops::{Deref, DerefMut};
+
+use rspack_collections::IdentifierMap;
+use rspack_util::atom::Atom;
+
+use crate::{ArtifactExt, incremental::IncrementalPasses};
+
+/// The inlined exports of a module in the last compilation
+#[derive(Debug, Clone)]
+pub struct InlineExportsEntry {
+  /// The fingerprint of the exports info of the module,
+  /// the inlined exports can be reused only when it is unchanged
+  pub fingerprint: u64,
+  /// The paths of the (nested) exports that are inlined
+  pub inlined: Vec<Vec<Atom>>,
+}
+
+/// The inlined exports keyed by module
+#[derive(Debug, Default, Clone)]
+pub struct InlineExportsArtifact(IdentifierMap<InlineExportsEntry>);
+
+impl ArtifactExt for InlineExportsArtifact {
+  const PASS: IncrementalPasses = IncrementalPasses::INLINE_EXPORTS;
+}
+
+impl Deref for InlineExportsArtifact {
+  type Target = IdentifierMap<InlineExportsEntry>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl DerefMut for InlineExportsArtifact {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
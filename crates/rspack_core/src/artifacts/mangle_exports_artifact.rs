@@ -0,0 +1,38 @@
+use std::ops::{Deref, DerefMut};
+
+use rspack_collections::IdentifierMap;
+use rspack_util::atom::Atom;
+
+use crate::{ArtifactExt, incremental::IncrementalPasses};
+
+/// The mangled names assigned to the exports of a module in the last compilation
+#[derive(Debug, Clone)]
+pub struct MangleExportsEntry {
+  /// The fingerprint of the exports info of the module,
+  /// the mangled names can be reused only when it is unchanged
+  pub fingerprint: u64,
+  /// The mangled names keyed by the path of the (nested) export
+  pub used_names: Vec<(Vec<Atom>, Atom)>,
+}
+
+/// The mangled names of exports keyed by module
+#[derive(Debug, Default, Clone)]
+pub struct MangleExportsArtifact(IdentifierMap<MangleExportsEntry>);
+
+impl ArtifactExt for MangleExportsArtifact {
+  const PASS: IncrementalPasses = IncrementalPasses::MANGLE_EXPORTS;
+}
+
+impl Deref for MangleExportsArtifact {
+  type Target = IdentifierMap<MangleExportsEntry>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl DerefMut for MangleExportsArtifact {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
@@ -8,15 +8,20 @@ mod chunk_hashes_artifact;
 mod chunk_ids_artifact;
 mod chunk_render_artifact;
 mod chunk_render_cache_artifact;
+mod class_members_artifact;
 mod code_generate_cache_artifact;
 mod code_generation_results;
 mod dependencies_diagnostics_artifact;
 mod imported_by_defer_modules_artifact;
+mod inline_exports_artifact;
+mod mangle_exports_artifact;
+mod module_concatenation_artifact;
 mod module_graph_cache_artifact;
 mod module_ids_artifact;
 mod module_static_cache_artifact;
 mod process_runtime_requirements_cache_artifact;
 mod side_effects_do_optimize_artifact;
+mod split_chunks_artifact;
 
 use std::{mem, sync::Arc};
 
@@ -94,8 +99,8 @@ impl<T: ArtifactExt> ArtifactExt for Arc<AtomicRefCell<T>> {
 }
 
 pub use async_modules_artifact::AsyncModulesArtifact;
-pub(crate) use build_chunk_graph_artifact::use_code_splitting_cache;
 pub use build_chunk_graph_artifact::*;
+pub(crate) use build_chunk_graph_artifact::{get_module_outgoings, use_code_splitting_cache};
 pub use build_module_graph_artifact::*;
 pub use cgc_runtime_requirements_artifact::CgcRuntimeRequirementsArtifact;
 pub use cgm_hash_artifact::*;
@@ -104,12 +109,17 @@ pub use chunk_hashes_artifact::*;
 pub use chunk_ids_artifact::*;
 pub use chunk_render_artifact::ChunkRenderArtifact;
 pub use chunk_render_cache_artifact::ChunkRenderCacheArtifact;
+pub use class_members_artifact::ClassMembersArtifact;
 pub use code_generate_cache_artifact::CodeGenerateCacheArtifact;
 pub use code_generation_results::*;
 pub use dependencies_diagnostics_artifact::DependenciesDiagnosticsArtifact;
 pub use imported_by_defer_modules_artifact::ImportedByDeferModulesArtifact;
+pub use inline_exports_artifact::*;
+pub use mangle_exports_artifact::*;
+pub use module_concatenation_artifact::*;
 pub use module_graph_cache_artifact::*;
 pub use module_ids_artifact::ModuleIdsArtifact;
 pub use module_static_cache_artifact::*;
 pub use process_runtime_requirements_cache_artifact::ProcessRuntimeRequirementsCacheArtifact;
 pub use side_effects_do_optimize_artifact::*;
+pub use split_chunks_artifact::*;
@@ -0,0 +1,46 @@
+use std::ops::{Deref, DerefMut};
+
+use rspack_collections::{IdentifierIndexSet, IdentifierMap};
+
+use crate::{ArtifactExt, ModuleIdentifier, RuntimeSpec, incremental::IncrementalPasses};
+
+/// The reason why a module can not be concatenated into a configuration
+#[derive(Debug, Clone)]
+pub enum ModuleConcatenationBailout {
+  /// Bailout because of the bailout reason of the module
+  Module(ModuleIdentifier),
+  Problem(String),
+}
+
+/// The concatenation configuration calculated for a root module in the last compilation
+#[derive(Debug, Clone)]
+pub struct ModuleConcatenationEntry {
+  pub runtime: Option<RuntimeSpec>,
+  pub modules: IdentifierIndexSet,
+  pub warnings: IdentifierMap<ModuleConcatenationBailout>,
+  /// The fingerprints of all modules visited while calculating the configuration,
+  /// the configuration can be reused only when none of them changed
+  pub fingerprints: IdentifierMap<u64>,
+}
+
+/// The concatenation configurations keyed by root module
+#[derive(Debug, Default, Clone)]
+pub struct ModuleConcatenationArtifact(IdentifierMap<ModuleConcatenationEntry>);
+
+impl ArtifactExt for ModuleConcatenationArtifact {
+  const PASS: IncrementalPasses = IncrementalPasses::MODULE_CONCATENATION;
+}
+
+impl Deref for ModuleConcatenationArtifact {
+  type Target = IdentifierMap<ModuleConcatenationEntry>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl DerefMut for ModuleConcatenationArtifact {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
@@ -0,0 +1,35 @@
+use rspack_collections::IdentifierMap;
+
+use crate::{ArtifactExt, ChunkUkey, incremental::IncrementalPasses};
+
+/// A module group that a module is matched into by a cache group
+#[derive(Debug, Clone)]
+pub struct SplitChunksModuleMatch {
+  /// The key of the module group
+  pub key: String,
+  pub chunk_name: Option<String>,
+  pub cache_group_index: usize,
+  pub chunks: Vec<ChunkUkey>,
+}
+
+/// The module groups that a module is matched into in the last compilation
+#[derive(Debug, Clone)]
+pub struct SplitChunksModuleEntry {
+  /// The fingerprint of the chunks the module belongs to,
+  /// the matches can be reused only when it is unchanged
+  pub fingerprint: u64,
+  pub matches: Vec<SplitChunksModuleMatch>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SplitChunksArtifact {
+  /// The fingerprint of all chunks and chunk combinations,
+  /// all modules need to be matched again when it changes
+  pub fingerprint: u64,
+  /// The module matches of each priority of cache groups
+  pub matches: Vec<IdentifierMap<SplitChunksModuleEntry>>,
+}
+
+impl ArtifactExt for SplitChunksArtifact {
+  const PASS: IncrementalPasses = IncrementalPasses::SPLIT_CHUNKS;
+}
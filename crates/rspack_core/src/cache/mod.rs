@@ -5,10 +5,13 @@ pub mod persistent;
 use std::{fmt::Debug, sync::Arc};
 
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
+use rspack_paths::ArcPath;
 
-use self::{disable::DisableCache, memory::MemoryCache, persistent::PersistentCache};
+pub(crate) use self::disable::DisableCache;
+use self::{memory::MemoryCache, persistent::PersistentCache};
 use crate::{
-  Compilation, CompilerOptions, ExperimentCacheOptions,
+  BuildChunkGraphArtifact, ChunkRenderCacheArtifact, CodeGenerateCacheArtifact, Compilation,
+  CompilerOptions, ExperimentCacheOptions,
   compilation::build_module_graph::BuildModuleGraphArtifact,
 };
 
@@ -34,6 +37,21 @@ pub trait Cache: Debug + Send + Sync {
 
   async fn before_build_module_graph(&mut self, _make_artifact: &mut BuildModuleGraphArtifact) {}
   async fn after_build_module_graph(&mut self, _make_artifact: &BuildModuleGraphArtifact) {}
+
+  async fn before_build_chunk_graph(&mut self, _artifact: &mut BuildChunkGraphArtifact) {}
+  async fn after_build_chunk_graph(&mut self, _compilation: &Compilation) {}
+
+  async fn before_code_generation(&mut self, _artifact: &mut CodeGenerateCacheArtifact) {}
+  async fn after_code_generation(&mut self, _artifact: &CodeGenerateCacheArtifact) {}
+
+  async fn before_create_chunk_assets(&mut self, _artifact: &mut ChunkRenderCacheArtifact) {}
+  async fn after_create_chunk_assets(&mut self, _artifact: &ChunkRenderCacheArtifact) {}
+
+  /// The modified times in milliseconds and content hashes of the files computed by the cache,
+  /// which can be reused by the watcher to filter out the files whose content is not changed.
+  fn content_hashes(&self) -> Vec<(ArcPath, u64, u64)> {
+    vec![]
+  }
 }
 
 pub fn new_cache(
@@ -10,7 +10,7 @@ use rustc_hash::FxHashSet as HashSet;
 use self::helper::{Helper, is_node_package_path};
 use super::{
   codec::CacheCodec,
-  snapshot::{Snapshot, SnapshotOptions},
+  snapshot::{InvalidatedPath, Snapshot, SnapshotOptions},
   storage::Storage,
 };
 
@@ -90,21 +90,19 @@ impl BuildDeps {
 
   /// Validate build dependencies
   ///
-  /// If any build dependencies have changed, this method will reset storage.
-  pub async fn validate(&mut self) -> Result<()> {
-    let (_, modified_files, removed_files, no_changed_files) =
-      self.snapshot.calc_modified_paths().await?;
+  /// If any build dependencies have changed, this method will reset storage
+  /// and return the changed build dependencies.
+  pub async fn validate(&mut self) -> Result<Vec<InvalidatedPath>> {
+    let (_, invalidated_paths, no_changed_files) = self.snapshot.calc_invalidated_paths().await?;
 
-    if !modified_files.is_empty() || !removed_files.is_empty() {
+    if !invalidated_paths.is_empty() {
       self.storage.reset().await;
 
-      tracing::info!(
-        "BuildDependencies: cache invalidate by modified_files {modified_files:?} and removed_files {removed_files:?}"
-      );
-      return Ok(());
+      tracing::info!("BuildDependencies: cache invalidate by {invalidated_paths:?}");
+      return Ok(invalidated_paths);
     }
     self.added = no_changed_files;
-    Ok(())
+    Ok(vec![])
   }
 }
 
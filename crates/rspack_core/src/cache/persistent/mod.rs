@@ -1,6 +1,7 @@
 pub mod build_dependencies;
 pub mod codec;
 pub mod occasion;
+mod report;
 pub mod snapshot;
 pub mod storage;
 
@@ -15,19 +16,23 @@ use rspack_cacheable::{
   with::{As, AsVec},
 };
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
-use rspack_paths::ArcPathSet;
+use rspack_paths::{ArcPath, ArcPathSet};
 use rspack_workspace::rspack_pkg_version;
 
+pub use self::report::{CacheInvalidationReport, CacheResetReason};
 use self::{
   build_dependencies::{BuildDeps, BuildDepsOptions},
   codec::CacheCodec,
-  occasion::{MakeOccasion, MetaOccasion},
-  snapshot::{Snapshot, SnapshotOptions},
-  storage::{Storage, StorageOptions, create_storage},
+  occasion::{
+    BuildChunkGraphOccasion, ChunkRenderOccasion, CodeGenerateOccasion, MakeOccasion, MetaOccasion,
+  },
+  snapshot::{Snapshot, SnapshotOptions, ValidateResult},
+  storage::{Storage, StorageOptions, StoragePackOptions, create_storage},
 };
 use super::Cache;
 use crate::{
-  Compilation, CompilerOptions, Logger,
+  BuildChunkGraphArtifact, ChunkRenderCacheArtifact, CodeGenerateCacheArtifact, Compilation,
+  CompilerOptions, Logger, Mode,
   compilation::build_module_graph::{BuildModuleGraphArtifact, BuildModuleGraphArtifactState},
 };
 
@@ -39,6 +44,39 @@ pub struct PersistentCacheOptions {
   pub version: String,
   pub snapshot: SnapshotOptions,
   pub storage: StorageOptions,
+  pub storage_pack: StoragePackOptions,
+}
+
+/// Calculate the version of storage.
+///
+/// Only the options which affect the content of storage are used, so that the machines which push
+/// and pull the remote storage share the same version, and changing the pack options will not
+/// invalidate the cache.
+fn calc_version(
+  compiler_path: &str,
+  option: &PersistentCacheOptions,
+  codec: &CacheCodec,
+  name: Option<&str>,
+  mode: &Mode,
+) -> String {
+  let option = PersistentCacheOptions {
+    storage: option.storage.local(),
+    // pack files record their own compression and the size budget only affects cleaning
+    storage_pack: StoragePackOptions::default(),
+    ..option.clone()
+  };
+  // use codec.encode to transform the absolute path in option,
+  // it will ensure that same project in different directory have the same version.
+  let option_bytes = codec
+    .encode(&option)
+    .expect("should persistent cache options can be serialized");
+  let mut hasher = DefaultHasher::new();
+  compiler_path.hash(&mut hasher);
+  option_bytes.hash(&mut hasher);
+  rspack_pkg_version!().hash(&mut hasher);
+  name.hash(&mut hasher);
+  mode.hash(&mut hasher);
+  hex::encode(hasher.finish().to_ne_bytes())
 }
 
 /// Persistent cache implementation
@@ -49,8 +87,14 @@ pub struct PersistentCache {
   snapshot: Snapshot,
   make_occasion: MakeOccasion,
   meta_occasion: MetaOccasion,
+  build_chunk_graph_occasion: BuildChunkGraphOccasion,
+  code_generate_occasion: CodeGenerateOccasion,
+  chunk_render_occasion: ChunkRenderOccasion,
   async_mode: bool,
   storage: Arc<dyn Storage>,
+  version: String,
+  /// The reason of storage reset found in initialization, it will be reported in the first build.
+  reset_reason: Option<CacheResetReason>,
   // TODO replace to logger and output warnings directly.
   warnings: Vec<String>,
 }
@@ -65,21 +109,19 @@ impl PersistentCache {
   ) -> Self {
     let async_mode = compiler_options.mode.is_development();
     let codec = Arc::new(CacheCodec::new(None));
-    // use codec.encode to transform the absolute path in option,
-    // it will ensure that same project in different directory have the same version.
-    let option_bytes = codec
-      .encode(option)
-      .expect("should persistent cache options can be serialized");
-    let version = {
-      let mut hasher = DefaultHasher::new();
-      compiler_path.hash(&mut hasher);
-      option_bytes.hash(&mut hasher);
-      rspack_pkg_version!().hash(&mut hasher);
-      compiler_options.name.hash(&mut hasher);
-      compiler_options.mode.hash(&mut hasher);
-      hex::encode(hasher.finish().to_ne_bytes())
-    };
-    let storage = create_storage(option.storage.clone(), version, intermediate_filesystem);
+    let version = calc_version(
+      compiler_path,
+      option,
+      &codec,
+      compiler_options.name.as_deref(),
+      &compiler_options.mode,
+    );
+    let storage = create_storage(
+      option.storage.clone(),
+      &option.storage_pack,
+      version.clone(),
+      intermediate_filesystem,
+    );
 
     Self {
       initialized: false,
@@ -97,10 +139,15 @@ impl PersistentCache {
         codec.clone(),
       ),
       make_occasion: MakeOccasion::new(storage.clone(), codec.clone()),
-      meta_occasion: MetaOccasion::new(storage.clone(), codec),
+      meta_occasion: MetaOccasion::new(storage.clone(), codec.clone()),
+      build_chunk_graph_occasion: BuildChunkGraphOccasion::new(storage.clone(), codec.clone()),
+      code_generate_occasion: CodeGenerateOccasion::new(storage.clone(), codec.clone()),
+      chunk_render_occasion: ChunkRenderOccasion::new(storage.clone(), codec),
       warnings: Default::default(),
       async_mode,
       storage,
+      version,
+      reset_reason: None,
     }
   }
 
@@ -110,8 +157,12 @@ impl PersistentCache {
     }
     self.initialized = true;
 
-    if let Err(err) = self.build_deps.validate().await {
-      self.warnings.push(err.to_string());
+    match self.build_deps.validate().await {
+      Ok(paths) if !paths.is_empty() => {
+        self.reset_reason = Some(CacheResetReason::BuildDependencies(paths));
+      }
+      Ok(_) => {}
+      Err(err) => self.warnings.push(err.to_string()),
     }
     if let Err(err) = self.meta_occasion.recovery().await {
       self.warnings.push(err.to_string());
@@ -147,17 +198,33 @@ impl Cache for PersistentCache {
     // rebuild will pass modified_files and removed_files from js side,
     // so only calculate them when build.
     if !compilation.is_rebuild {
-      let (is_hot_start, modified_paths, removed_paths, _) =
-        match self.snapshot.calc_modified_paths().await {
-          Ok(res) => res,
-          Err(err) => {
-            self.warnings.push(err.to_string());
-            return false;
-          }
-        };
-      tracing::debug!("cache::snapshot recovery {modified_paths:?} {removed_paths:?}",);
-      compilation.modified_files.extend(modified_paths);
-      compilation.removed_files.extend(removed_paths);
+      let (is_hot_start, invalidated_paths, _) = match self.snapshot.calc_invalidated_paths().await
+      {
+        Ok(res) => res,
+        Err(err) => {
+          self.warnings.push(err.to_string());
+          return false;
+        }
+      };
+      tracing::debug!("cache::snapshot recovery {invalidated_paths:?}");
+      for item in &invalidated_paths {
+        if matches!(item.result, ValidateResult::Deleted) {
+          compilation.removed_files.insert(item.path.clone());
+        } else {
+          compilation.modified_files.insert(item.path.clone());
+        }
+      }
+
+      let reset = self.reset_reason.take().or_else(|| {
+        (!is_hot_start).then(|| CacheResetReason::VersionNotFound {
+          version: self.version.clone(),
+        })
+      });
+      compilation.cache_invalidation_report = Some(CacheInvalidationReport {
+        reset,
+        paths: invalidated_paths,
+        modules: vec![],
+      });
       return is_hot_start;
     }
     false
@@ -203,6 +270,10 @@ impl Cache for PersistentCache {
     }
   }
 
+  fn content_hashes(&self) -> Vec<(ArcPath, u64, u64)> {
+    self.snapshot.content_hashes()
+  }
+
   async fn before_build_module_graph(&mut self, make_artifact: &mut BuildModuleGraphArtifact) {
     // TODO When does not need to pass variables through make_artifact.state, use compilation.is_rebuild to check
     if matches!(
@@ -219,4 +290,123 @@ impl Cache for PersistentCache {
   async fn after_build_module_graph(&mut self, make_artifact: &BuildModuleGraphArtifact) {
     self.make_occasion.save(make_artifact);
   }
+
+  async fn before_build_chunk_graph(&mut self, artifact: &mut BuildChunkGraphArtifact) {
+    // the memory cache is reused in rebuild, only recovery when it is empty
+    if artifact.is_empty()
+      && let Err(err) = self.build_chunk_graph_occasion.recovery(artifact).await
+    {
+      self.warnings.push(err.to_string());
+    }
+  }
+
+  async fn after_build_chunk_graph(&mut self, compilation: &Compilation) {
+    self.build_chunk_graph_occasion.save(
+      &compilation.build_chunk_graph_artifact,
+      compilation.get_module_graph(),
+      &compilation.module_graph_cache_artifact,
+    );
+  }
+
+  async fn before_code_generation(&mut self, artifact: &mut CodeGenerateCacheArtifact) {
+    artifact.track_changes();
+    // the memory cache items are reused in rebuild, only recovery when it is empty
+    if artifact.is_empty()
+      && let Err(err) = self.code_generate_occasion.recovery(artifact).await
+    {
+      self.warnings.push(err.to_string());
+    }
+  }
+
+  async fn after_code_generation(&mut self, artifact: &CodeGenerateCacheArtifact) {
+    self.code_generate_occasion.save(artifact);
+  }
+
+  async fn before_create_chunk_assets(&mut self, artifact: &mut ChunkRenderCacheArtifact) {
+    artifact.track_changes();
+    // the memory cache items are reused in rebuild, only recovery when it is empty
+    if artifact.is_empty()
+      && let Err(err) = self.chunk_render_occasion.recovery(artifact).await
+    {
+      self.warnings.push(err.to_string());
+    }
+  }
+
+  async fn after_create_chunk_assets(&mut self, artifact: &ChunkRenderCacheArtifact) {
+    self.chunk_render_occasion.save(artifact);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    PersistentCacheOptions, calc_version,
+    codec::CacheCodec,
+    storage::{StorageCompression, StorageOptions, StoragePackOptions},
+  };
+  use crate::Mode;
+
+  fn version_of(storage: StorageOptions) -> String {
+    version_with_pack_of(storage, StoragePackOptions::default())
+  }
+
+  fn version_with_pack_of(storage: StorageOptions, storage_pack: StoragePackOptions) -> String {
+    let option = PersistentCacheOptions {
+      build_dependencies: vec![],
+      version: String::new(),
+      snapshot: Default::default(),
+      storage,
+      storage_pack,
+    };
+    calc_version(
+      "/",
+      &option,
+      &CacheCodec::new(None),
+      None,
+      &Mode::Development,
+    )
+  }
+
+  #[test]
+  fn should_share_version_between_remote_machines() {
+    let local = version_of(StorageOptions::FileSystem {
+      directory: "/cache".into(),
+    });
+    let push = version_of(StorageOptions::Remote {
+      directory: "/cache".into(),
+      remote_directory: "/remote_a".into(),
+      push: true,
+    });
+    let pull = version_of(StorageOptions::Remote {
+      directory: "/cache".into(),
+      remote_directory: "/remote_b".into(),
+      push: false,
+    });
+    assert_eq!(local, push);
+    assert_eq!(push, pull);
+    assert_ne!(
+      local,
+      version_of(StorageOptions::FileSystem {
+        directory: "/other".into(),
+      })
+    );
+  }
+
+  #[test]
+  fn should_ignore_pack_options_in_version() {
+    let storage = StorageOptions::FileSystem {
+      directory: "/cache".into(),
+    };
+    assert_eq!(
+      version_of(storage.clone()),
+      version_with_pack_of(
+        storage,
+        StoragePackOptions {
+          compression: StorageCompression::Zstd,
+          max_size: Some(1024),
+          ..Default::default()
+        }
+      )
+    );
+  }
 }
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{cacheable, utils::OwnedOrRef};
+use rspack_collections::DatabaseItem;
+use rspack_error::Result;
+
+use super::super::{Storage, codec::CacheCodec};
+use crate::{
+  BuildChunkGraphArtifact, Chunk, ChunkGraph, ChunkGroup, ChunkGroupUkey, ChunkUkey, ModuleGraph,
+  ModuleGraphCacheArtifact, ModuleIdentifier, artifacts::get_module_outgoings,
+};
+
+pub const SCOPE: &str = "occasion_build_chunk_graph";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node<'a> {
+  chunks: Vec<OwnedOrRef<'a, Chunk>>,
+  chunk_groups: Vec<OwnedOrRef<'a, ChunkGroup>>,
+  chunk_graph: OwnedOrRef<'a, ChunkGraph>,
+  entrypoints: Vec<(String, ChunkGroupUkey)>,
+  async_entrypoints: Vec<ChunkGroupUkey>,
+  named_chunk_groups: Vec<(String, ChunkGroupUkey)>,
+  named_chunks: Vec<(String, ChunkUkey)>,
+  module_idx: Vec<(ModuleIdentifier, (u32, u32))>,
+  module_outgoings: Vec<(ModuleIdentifier, Vec<ModuleIdentifier>)>,
+}
+
+/// Build Chunk Graph Occasion is used to save the code splitting cache of BuildChunkGraphArtifact
+///
+/// The code splitter itself is not saved, the restored chunk graph is reused
+/// only when the outgoings of all modules in the restored module graph are unchanged.
+#[derive(Debug)]
+pub struct BuildChunkGraphOccasion {
+  codec: Arc<CacheCodec>,
+  storage: Arc<dyn Storage>,
+}
+
+impl BuildChunkGraphOccasion {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self { storage, codec }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::BuildChunkGraph::save", skip_all)]
+  pub fn save(
+    &self,
+    artifact: &BuildChunkGraphArtifact,
+    module_graph: &ModuleGraph,
+    module_graph_cache: &ModuleGraphCacheArtifact,
+  ) {
+    let cache = &artifact.code_splitting_cache;
+    if cache.module_outgoings.is_some() {
+      // the restored chunk graph is reused, the storage is up to date
+      return;
+    }
+
+    let module_outgoings = module_graph
+      .modules()
+      .into_keys()
+      .par_bridge()
+      .map(|module| {
+        let outgoings = get_module_outgoings(module_graph, module_graph_cache, &module);
+        (module, outgoings)
+      })
+      .collect::<Vec<_>>();
+    let node = Node {
+      chunks: cache.chunk_by_ukey.values().map(Into::into).collect(),
+      chunk_groups: cache.chunk_group_by_ukey.values().map(Into::into).collect(),
+      chunk_graph: (&cache.chunk_graph).into(),
+      entrypoints: cache
+        .entrypoints
+        .iter()
+        .map(|(name, ukey)| (name.clone(), *ukey))
+        .collect(),
+      async_entrypoints: cache.async_entrypoints.clone(),
+      named_chunk_groups: cache
+        .named_chunk_groups
+        .iter()
+        .map(|(name, ukey)| (name.clone(), *ukey))
+        .collect(),
+      named_chunks: cache
+        .named_chunks
+        .iter()
+        .map(|(name, ukey)| (name.clone(), *ukey))
+        .collect(),
+      module_idx: cache
+        .module_idx
+        .iter()
+        .map(|(module, idx)| (*module, *idx))
+        .collect(),
+      module_outgoings,
+    };
+
+    match self.codec.encode(&node) {
+      Ok(bytes) => self
+        .storage
+        .set(SCOPE, "default".as_bytes().to_vec(), bytes),
+      Err(err) => {
+        // the chunk graph may contain unsupported fields such as function filename,
+        // remove the outdated one to avoid restoring it.
+        tracing::warn!("to bytes failed {:?}", err);
+        self.storage.remove(SCOPE, "default".as_bytes());
+      }
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::BuildChunkGraph::recovery", skip_all)]
+  pub async fn recovery(&self, artifact: &mut BuildChunkGraphArtifact) -> Result<()> {
+    let Some((_, value)) = self.storage.load(SCOPE).await?.pop() else {
+      return Ok(());
+    };
+    let node: Node = self.codec.decode(&value)?;
+
+    let cache = &mut artifact.code_splitting_cache;
+    for chunk in node.chunks {
+      let chunk = chunk.into_owned();
+      chunk.ukey().mark_restored();
+      cache.chunk_by_ukey.add(chunk);
+    }
+    for chunk_group in node.chunk_groups {
+      let chunk_group = chunk_group.into_owned();
+      chunk_group.ukey.mark_restored();
+      cache.chunk_group_by_ukey.add(chunk_group);
+    }
+    cache.chunk_graph = node.chunk_graph.into_owned();
+    cache.entrypoints = node.entrypoints.into_iter().collect();
+    cache.async_entrypoints = node.async_entrypoints;
+    cache.named_chunk_groups = node.named_chunk_groups.into_iter().collect();
+    cache.named_chunks = node.named_chunks.into_iter().collect();
+    cache.module_idx = node.module_idx.into_iter().collect();
+    cache.module_outgoings = Some(node.module_outgoings.into_iter().collect());
+
+    tracing::debug!("recovery {} chunks", cache.chunk_by_ukey.len());
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_collections::DatabaseItem;
+
+  use super::{
+    super::super::{
+      codec::CacheCodec,
+      storage::{MemoryStorage, Storage},
+    },
+    BuildChunkGraphOccasion, SCOPE,
+  };
+  use crate::{
+    BuildChunkGraphArtifact, Chunk, ChunkGroup, ChunkGroupKind, ChunkKind, ChunkUkey, EntryOptions,
+    ModuleGraph,
+  };
+
+  #[tokio::test]
+  async fn should_build_chunk_graph_occasion_recovery_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let codec = Arc::new(CacheCodec::new(None));
+    let occasion = BuildChunkGraphOccasion::new(storage.clone(), codec.clone());
+
+    let mut artifact = BuildChunkGraphArtifact::default();
+    let cache = &mut artifact.code_splitting_cache;
+    let chunk_ukey = cache
+      .chunk_by_ukey
+      .add(Chunk::new(Some("main".into()), ChunkKind::Normal))
+      .ukey();
+    let mut entrypoint = ChunkGroup::new(ChunkGroupKind::new_entrypoint(
+      true,
+      Box::new(EntryOptions {
+        name: Some("main".into()),
+        ..Default::default()
+      }),
+    ));
+    entrypoint.chunks.push(chunk_ukey);
+    let entrypoint_ukey = entrypoint.ukey;
+    cache.chunk_group_by_ukey.add(entrypoint);
+    cache.entrypoints.insert("main".into(), entrypoint_ukey);
+    cache.named_chunks.insert("main".into(), chunk_ukey);
+    occasion.save(&artifact, &ModuleGraph::default(), &Default::default());
+
+    let mut restored = BuildChunkGraphArtifact::default();
+    assert!(restored.is_empty());
+    occasion.recovery(&mut restored).await.unwrap();
+    assert!(!restored.is_empty());
+    let cache = &restored.code_splitting_cache;
+    assert_eq!(cache.entrypoints.get("main"), Some(&entrypoint_ukey));
+    assert_eq!(cache.named_chunks.get("main"), Some(&chunk_ukey));
+    assert_eq!(
+      cache
+        .chunk_group_by_ukey
+        .expect_get(&entrypoint_ukey)
+        .chunks,
+      vec![chunk_ukey]
+    );
+    assert!(
+      cache
+        .module_outgoings
+        .as_ref()
+        .is_some_and(|m| m.is_empty())
+    );
+
+    // the restored chunk graph is reused and should not be saved again
+    storage.reset().await;
+    occasion.save(&restored, &ModuleGraph::default(), &Default::default());
+    assert!(storage.load(SCOPE).await.unwrap().is_empty());
+
+    // the ukeys created later should not conflict with the restored ones
+    assert!(ChunkUkey::new().as_u32() > chunk_ukey.as_u32());
+  }
+}
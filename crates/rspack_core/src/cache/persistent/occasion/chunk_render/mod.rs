@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_collections::Identifier;
+use rspack_error::Result;
+use rspack_sources::BoxSource;
+
+use super::super::{Storage, codec::CacheCodec};
+use crate::ChunkRenderCacheArtifact;
+
+pub const SCOPE: &str = "occasion_chunk_render";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+}
+
+/// Chunk Render Occasion is used to save ChunkRenderCacheArtifact
+///
+/// The cache key of each item is the content hash of the chunk,
+/// so the restored items can be used directly without any validation.
+#[derive(Debug)]
+pub struct ChunkRenderOccasion {
+  codec: Arc<CacheCodec>,
+  storage: Arc<dyn Storage>,
+}
+
+impl ChunkRenderOccasion {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self { storage, codec }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::save", skip_all)]
+  pub fn save(&self, artifact: &ChunkRenderCacheArtifact) {
+    let (updated, removed) = artifact.take_changes();
+    for key in removed {
+      self.storage.remove(SCOPE, key.as_bytes());
+    }
+
+    let nodes = updated
+      .into_par_iter()
+      .filter_map(|(key, source)| match self.codec.encode(&Node { source }) {
+        Ok(bytes) => Some((key.as_bytes().to_vec(), bytes)),
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+
+    tracing::debug!("save {} chunk render results", nodes.len());
+    for (key, bytes) in nodes {
+      self.storage.set(SCOPE, key, bytes);
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::recovery", skip_all)]
+  pub async fn recovery(&self, artifact: &ChunkRenderCacheArtifact) -> Result<()> {
+    let nodes = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_par_iter()
+      .map(|(key, value)| {
+        let key = Identifier::from(String::from_utf8_lossy(&key).as_ref());
+        let node: Node = self.codec.decode(&value)?;
+        Ok((key, node.source))
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    tracing::debug!("recovery {} chunk render results", nodes.len());
+    for (key, source) in nodes {
+      artifact.restore(key, source);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_sources::{RawStringSource, SourceExt};
+
+  use super::{
+    super::super::{
+      codec::CacheCodec,
+      storage::{MemoryStorage, Storage},
+    },
+    ChunkRenderOccasion, Node, SCOPE,
+  };
+  use crate::{ChunkRenderCacheArtifact, MemoryGCStorage};
+
+  #[tokio::test]
+  async fn should_chunk_render_occasion_recovery_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let codec = Arc::new(CacheCodec::new(None));
+    let occasion = ChunkRenderOccasion::new(storage.clone(), codec.clone());
+
+    let node = Node {
+      source: RawStringSource::from_static("console.log(1)").boxed(),
+    };
+    storage.set(
+      SCOPE,
+      "hash_a".as_bytes().to_vec(),
+      codec.encode(&node).unwrap(),
+    );
+
+    let mut artifact = ChunkRenderCacheArtifact::new(MemoryGCStorage::new(1));
+    artifact.track_changes();
+    assert!(artifact.is_empty());
+    occasion.recovery(&artifact).await.unwrap();
+    assert!(!artifact.is_empty());
+
+    // restored items should not be saved again
+    let (updated, removed) = artifact.take_changes();
+    assert!(updated.is_empty());
+    assert!(removed.is_empty());
+
+    // items which are not accessed will be removed from storage
+    artifact.start_next_generation();
+    artifact.start_next_generation();
+    occasion.save(&artifact);
+    assert!(artifact.is_empty());
+    assert!(storage.load(SCOPE).await.unwrap().is_empty());
+  }
+}
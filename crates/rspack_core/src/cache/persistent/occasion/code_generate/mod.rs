@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{
+  cacheable,
+  with::{AsCacheable, AsPreset, AsTuple2, AsVec},
+};
+use rspack_collections::Identifier;
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+
+use super::super::{Storage, codec::CacheCodec};
+use crate::{
+  BindingCell, ChunkInitFragments, CodeGenerateCacheArtifact, CodeGenerationData,
+  CodeGenerationDataAssetInfo, CodeGenerationDataFilename, CodeGenerationDataUrl,
+  CodeGenerationPublicPathAutoReplace, CodeGenerationResult, RuntimeGlobals, SourceType,
+  URLStaticMode,
+};
+
+pub const SCOPE: &str = "occasion_code_generate";
+
+/// The code generation data which can be saved to persistent cache.
+///
+/// `CodeGenerationData` is an any map, only the types listed here are supported.
+#[cacheable]
+#[derive(Default)]
+struct NodeData {
+  url: Option<CodeGenerationDataUrl>,
+  filename: Option<CodeGenerationDataFilename>,
+  asset_info: Option<CodeGenerationDataAssetInfo>,
+  public_path_auto_replace: Option<CodeGenerationPublicPathAutoReplace>,
+  url_static_mode: Option<URLStaticMode>,
+  has_chunk_init_fragments: bool,
+}
+
+impl NodeData {
+  /// Return None if the data contains any type which can not be saved.
+  fn try_from_data(data: &CodeGenerationData) -> Option<Self> {
+    let mut res = Self::default();
+    let mut count = 0;
+    if let Some(fragments) = data.get::<ChunkInitFragments>() {
+      // chunk init fragments are trait objects and not cacheable
+      if !fragments.is_empty() {
+        return None;
+      }
+      res.has_chunk_init_fragments = true;
+      count += 1;
+    }
+    macro_rules! collect {
+      ($field:ident, $ty:ty) => {
+        if let Some(value) = data.get::<$ty>() {
+          res.$field = Some(value.clone());
+          count += 1;
+        }
+      };
+    }
+    collect!(url, CodeGenerationDataUrl);
+    collect!(filename, CodeGenerationDataFilename);
+    collect!(asset_info, CodeGenerationDataAssetInfo);
+    collect!(
+      public_path_auto_replace,
+      CodeGenerationPublicPathAutoReplace
+    );
+    collect!(url_static_mode, URLStaticMode);
+    (count == data.len()).then_some(res)
+  }
+
+  fn into_data(self) -> CodeGenerationData {
+    let mut data = CodeGenerationData::default();
+    if self.has_chunk_init_fragments {
+      data.insert(ChunkInitFragments::default());
+    }
+    if let Some(value) = self.url {
+      data.insert(value);
+    }
+    if let Some(value) = self.filename {
+      data.insert(value);
+    }
+    if let Some(value) = self.asset_info {
+      data.insert(value);
+    }
+    if let Some(value) = self.public_path_auto_replace {
+      data.insert(value);
+    }
+    if let Some(value) = self.url_static_mode {
+      data.insert(value);
+    }
+    data
+  }
+}
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsVec<AsTuple2<AsCacheable, AsPreset>>)]
+  sources: Vec<(SourceType, BoxSource)>,
+  data: NodeData,
+  runtime_requirements: RuntimeGlobals,
+  hash: Option<RspackHashDigest>,
+}
+
+impl Node {
+  /// Return None if the code generation result can not be saved.
+  fn try_from_result(result: &CodeGenerationResult) -> Option<Self> {
+    // concatenated module results depend on the concatenation scope of the current chunk graph
+    if result.concatenation_scope.is_some() || !result.chunk_init_fragments.is_empty() {
+      return None;
+    }
+    Some(Self {
+      sources: result
+        .inner()
+        .iter()
+        .map(|(source_type, source)| (*source_type, source.clone()))
+        .collect(),
+      data: NodeData::try_from_data(&result.data)?,
+      runtime_requirements: result.runtime_requirements,
+      hash: result.hash.clone(),
+    })
+  }
+
+  fn into_result(self) -> CodeGenerationResult {
+    CodeGenerationResult {
+      inner: BindingCell::from(
+        self
+          .sources
+          .into_iter()
+          .collect::<rustc_hash::FxHashMap<_, _>>(),
+      ),
+      data: self.data.into_data(),
+      runtime_requirements: self.runtime_requirements,
+      hash: self.hash,
+      ..Default::default()
+    }
+  }
+}
+
+/// Code Generate Occasion is used to save CodeGenerateCacheArtifact
+///
+/// The cache key of each item is composed of the module identifier and the module hash of
+/// the runtime, so the restored items can be used directly without any validation.
+#[derive(Debug)]
+pub struct CodeGenerateOccasion {
+  codec: Arc<CacheCodec>,
+  storage: Arc<dyn Storage>,
+}
+
+impl CodeGenerateOccasion {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self { storage, codec }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::save", skip_all)]
+  pub fn save(&self, artifact: &CodeGenerateCacheArtifact) {
+    let (updated, removed) = artifact.take_changes();
+    for key in removed {
+      self.storage.remove(SCOPE, key.as_bytes());
+    }
+
+    let nodes = updated
+      .par_iter()
+      .filter_map(|(key, result)| {
+        let node = Node::try_from_result(result)?;
+        match self.codec.encode(&node) {
+          Ok(bytes) => Some((key.as_bytes().to_vec(), bytes)),
+          Err(err) => {
+            tracing::warn!("to bytes failed {:?}", err);
+            None
+          }
+        }
+      })
+      .collect::<Vec<_>>();
+
+    tracing::debug!("save {} code generation results", nodes.len());
+    for (key, bytes) in nodes {
+      self.storage.set(SCOPE, key, bytes);
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::recovery", skip_all)]
+  pub async fn recovery(&self, artifact: &CodeGenerateCacheArtifact) -> Result<()> {
+    let nodes = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_par_iter()
+      .map(|(key, value)| {
+        let key = Identifier::from(String::from_utf8_lossy(&key).as_ref());
+        let node: Node = self.codec.decode(&value)?;
+        Ok((key, node.into_result()))
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    tracing::debug!("recovery {} code generation results", nodes.len());
+    for (key, result) in nodes {
+      artifact.restore(key, result);
+    }
+    Ok(())
+  }
+}
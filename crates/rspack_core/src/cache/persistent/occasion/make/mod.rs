@@ -11,7 +11,9 @@ use rustc_hash::FxHashSet;
 use super::super::{Storage, codec::CacheCodec};
 use crate::{
   FactorizeInfo,
-  compilation::build_module_graph::{BuildModuleGraphArtifact, BuildModuleGraphArtifactState},
+  compilation::build_module_graph::{
+    BuildModuleGraphArtifact, BuildModuleGraphArtifactState, should_defer_branch_dependency,
+  },
   utils::{FileCounter, ResourceId},
 };
 
@@ -46,6 +48,7 @@ impl MakeOccasion {
       state: _,
       make_failed_dependencies: _,
       make_failed_module: _,
+      deferred_branch_dependencies: _,
     } = artifact;
 
     let mut need_update_modules = issuer_update_modules.clone();
@@ -108,6 +111,20 @@ impl MakeOccasion {
       }
     }
 
+    // recovery deferred_branch_dependencies, which are never factorized
+    let mut deferred_branch_dependencies = FxHashSet::default();
+    for module in mg.modules().values() {
+      let branch_conditions = &module.build_info().branch_conditions;
+      for dep_id in branch_conditions.keys() {
+        if mg.connection_by_dependency_id(dep_id).is_none()
+          && !make_failed_dependencies.contains(dep_id)
+          && should_defer_branch_dependency(&mg, branch_conditions, dep_id)
+        {
+          deferred_branch_dependencies.insert(*dep_id);
+        }
+      }
+    }
+
     Ok(BuildModuleGraphArtifact {
       // write all of field here to avoid forget to update occasion when add new fields
       // temporary data set to default
@@ -121,6 +138,7 @@ impl MakeOccasion {
 
       make_failed_module,
       make_failed_dependencies,
+      deferred_branch_dependencies,
       entry_dependencies,
       file_dependencies: file_dep,
       context_dependencies: context_dep,
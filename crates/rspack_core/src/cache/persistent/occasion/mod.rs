@@ -1,5 +1,11 @@
+pub mod build_chunk_graph;
+pub mod chunk_render;
+pub mod code_generate;
 pub mod make;
 pub mod meta;
 
+pub use build_chunk_graph::BuildChunkGraphOccasion;
+pub use chunk_render::ChunkRenderOccasion;
+pub use code_generate::CodeGenerateOccasion;
 pub use make::MakeOccasion;
 pub use meta::MetaOccasion;
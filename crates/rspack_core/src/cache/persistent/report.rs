@@ -0,0 +1,82 @@
+use rspack_paths::ArcPathSet;
+
+use super::snapshot::InvalidatedPath;
+use crate::{Logger, ModuleGraph, ModuleIdentifier};
+
+/// The reason why the whole persistent cache is not used
+#[derive(Debug, Clone)]
+pub enum CacheResetReason {
+  /// The storage has no data of the current version.
+  ///
+  /// The version is calculated from the compiler path, cache options, rspack version,
+  /// compiler name and mode, so this happens in the first build or after any of them changed.
+  VersionNotFound { version: String },
+  /// Some build dependencies have changed and the storage has been reset.
+  BuildDependencies(Vec<InvalidatedPath>),
+}
+
+/// The report of the persistent cache entries which are invalidated in a build
+#[derive(Debug, Clone, Default)]
+pub struct CacheInvalidationReport {
+  pub reset: Option<CacheResetReason>,
+  /// The paths which are changed since the last build
+  pub paths: Vec<InvalidatedPath>,
+  /// The modules restored from cache which depend on the invalidated paths
+  pub modules: Vec<ModuleIdentifier>,
+}
+
+impl CacheInvalidationReport {
+  /// Collect the modules which depend on the invalidated paths
+  pub(crate) fn collect_modules(&mut self, module_graph: &ModuleGraph) {
+    if self.paths.is_empty() {
+      return;
+    }
+    let paths = self
+      .paths
+      .iter()
+      .map(|item| item.path.clone())
+      .collect::<ArcPathSet>();
+    self.modules = module_graph
+      .modules()
+      .into_iter()
+      .filter(|(_, module)| {
+        let build_info = module.build_info();
+        [
+          &build_info.file_dependencies,
+          &build_info.context_dependencies,
+          &build_info.missing_dependencies,
+          &build_info.build_dependencies,
+        ]
+        .into_iter()
+        .any(|deps| deps.iter().any(|path| paths.contains(path)))
+      })
+      .map(|(identifier, _)| identifier)
+      .collect();
+    self.modules.sort();
+  }
+
+  pub(crate) fn log(&self, logger: &impl Logger) {
+    match &self.reset {
+      Some(CacheResetReason::VersionNotFound { version }) => logger.info(format!(
+        "no cache found for version {version}, it is the first build or the compiler path, cache options, rspack version, compiler name or mode has changed"
+      )),
+      Some(CacheResetReason::BuildDependencies(paths)) => {
+        logger.info("cache is reset because build dependencies have changed");
+        for item in paths {
+          logger.log(format!("build dependency {item}"));
+        }
+      }
+      None => {}
+    }
+    if !self.paths.is_empty() {
+      logger.info(format!(
+        "{} paths changed since the last build, {} modules invalidated",
+        self.paths.len(),
+        self.modules.len()
+      ));
+      for item in &self.paths {
+        logger.log(item.to_string());
+      }
+    }
+  }
+}
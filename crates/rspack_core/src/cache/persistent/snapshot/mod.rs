@@ -5,18 +5,44 @@ use std::sync::Arc;
 
 use rspack_error::Result;
 use rspack_fs::ReadableFileSystem;
-use rspack_paths::{ArcPath, ArcPathSet};
+use rspack_paths::{ArcPath, ArcPathDashMap, ArcPathSet};
 
-use self::strategy::{StrategyHelper, ValidateResult};
 pub use self::{
   option::{PathMatcher, SnapshotOptions},
-  strategy::Strategy,
+  strategy::{Strategy, StrategyHelper, ValidateResult},
 };
 use super::{codec::CacheCodec, storage::Storage};
 use crate::FutureConsumer;
 
 pub const SCOPE: &str = "snapshot";
 
+/// A path which is modified or deleted since the snapshot was taken
+#[derive(Debug, Clone)]
+pub struct InvalidatedPath {
+  pub path: ArcPath,
+  /// The strategy saved in snapshot which detected the change
+  pub strategy: Strategy,
+  /// Either `Modified` or `Deleted`
+  pub result: ValidateResult,
+}
+
+impl std::fmt::Display for InvalidatedPath {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let result = match self.result {
+      ValidateResult::Deleted => "deleted",
+      _ => "modified",
+    };
+    write!(f, "{} {result}, ", self.path.display())?;
+    match &self.strategy {
+      Strategy::PackageVersion(version) => write!(f, "package version was {version}"),
+      Strategy::PathHash { mtime, hash } => {
+        write!(f, "content hash was {hash:016x} with mtime {mtime}")
+      }
+      Strategy::Missing => write!(f, "it was missing"),
+    }
+  }
+}
+
 /// Snapshot is used to check if files have been modified or deleted.
 ///
 /// Snapshot will generate `Strategy` for target file, and check the modification
@@ -28,6 +54,8 @@ pub struct Snapshot {
   fs: Arc<dyn ReadableFileSystem>,
   storage: Arc<dyn Storage>,
   codec: Arc<CacheCodec>,
+  /// The modified times and file hashes of the paths which are added or validated as no change
+  content_hashes: ArcPathDashMap<(u64, u64)>,
 }
 
 impl Snapshot {
@@ -43,6 +71,7 @@ impl Snapshot {
       fs,
       storage,
       codec,
+      content_hashes: Default::default(),
     }
   }
 
@@ -59,6 +88,7 @@ impl Snapshot {
       fs,
       storage,
       codec,
+      content_hashes: Default::default(),
     }
   }
 
@@ -97,12 +127,19 @@ impl Snapshot {
           Some((
             codec.encode(&path).expect("should encode success"),
             codec.encode(&strategy).expect("should encode success"),
+            path,
+            strategy,
           ))
         }
       })
       .fut_consume(|data| {
-        if let Some((key, value)) = data {
+        if let Some((key, value, path, strategy)) = data {
           self.storage.set(self.scope, key, value);
+          if let Strategy::PathHash { mtime, hash } = strategy {
+            self.content_hashes.insert(path, (mtime, hash));
+          } else {
+            self.content_hashes.remove(&path);
+          }
         }
       })
       .await;
@@ -112,15 +149,45 @@ impl Snapshot {
     for item in paths {
       self
         .storage
-        .remove(self.scope, item.as_os_str().as_encoded_bytes())
+        .remove(self.scope, item.as_os_str().as_encoded_bytes());
+      self.content_hashes.remove(&item);
     }
   }
 
+  /// The modified times in milliseconds and file hashes of the paths in snapshot, which are
+  /// computed when the paths are added or validated as no change.
+  pub fn content_hashes(&self) -> Vec<(ArcPath, u64, u64)> {
+    self
+      .content_hashes
+      .iter()
+      .map(|item| {
+        let (mtime, hash) = *item.value();
+        (item.key().clone(), mtime, hash)
+      })
+      .collect()
+  }
+
   #[allow(clippy::type_complexity)]
-  #[tracing::instrument("Cache::Snapshot::calc_modified_path", skip_all)]
   pub async fn calc_modified_paths(&self) -> Result<(bool, ArcPathSet, ArcPathSet, ArcPathSet)> {
-    let mut modified_path = ArcPathSet::default();
-    let mut deleted_path = ArcPathSet::default();
+    let (is_hot_start, invalidated_paths, no_change_path) = self.calc_invalidated_paths().await?;
+    let (deleted_path, modified_path) = invalidated_paths
+      .into_iter()
+      .partition::<Vec<_>, _>(|item| matches!(item.result, ValidateResult::Deleted));
+    Ok((
+      is_hot_start,
+      modified_path.into_iter().map(|item| item.path).collect(),
+      deleted_path.into_iter().map(|item| item.path).collect(),
+      no_change_path,
+    ))
+  }
+
+  /// Validate all paths in snapshot.
+  ///
+  /// Return whether the snapshot is not empty, the invalidated paths with the strategy
+  /// which detected the change, and the paths with no change.
+  #[tracing::instrument("Cache::Snapshot::calc_invalidated_paths", skip_all)]
+  pub async fn calc_invalidated_paths(&self) -> Result<(bool, Vec<InvalidatedPath>, ArcPathSet)> {
+    let mut invalidated_paths = vec![];
     let mut no_change_path = ArcPathSet::default();
     let helper = Arc::new(StrategyHelper::new(self.fs.clone()));
     let codec = self.codec.clone();
@@ -135,24 +202,29 @@ impl Snapshot {
         async move {
           let path: ArcPath = codec.decode(&key).expect("should decode success");
           let strategy: Strategy = codec.decode(&value).expect("should decode success");
-          let validate = helper.validate(&path, &strategy).await;
-          (path, validate)
+          let result = helper.validate(&path, &strategy).await;
+          InvalidatedPath {
+            path,
+            strategy,
+            result,
+          }
         }
       })
-      .fut_consume(|(path, validate)| match validate {
-        ValidateResult::Modified => {
-          modified_path.insert(path);
-        }
-        ValidateResult::Deleted => {
-          deleted_path.insert(path);
-        }
+      .fut_consume(|item| match item.result {
         ValidateResult::NoChanged => {
-          no_change_path.insert(path);
+          if let Strategy::PathHash { mtime, hash } = item.strategy {
+            self.content_hashes.insert(item.path.clone(), (mtime, hash));
+          }
+          no_change_path.insert(item.path);
+        }
+        ValidateResult::Modified | ValidateResult::Deleted => {
+          invalidated_paths.push(item);
         }
       })
       .await;
 
-    Ok((is_hot_start, modified_path, deleted_path, no_change_path))
+    invalidated_paths.sort_by(|a, b| a.path.as_os_str().cmp(b.path.as_os_str()));
+    Ok((is_hot_start, invalidated_paths, no_change_path))
   }
 }
 
@@ -165,7 +237,7 @@ mod tests {
 
   use super::{
     super::{codec::CacheCodec, storage::MemoryStorage},
-    PathMatcher, Snapshot, SnapshotOptions,
+    PathMatcher, Snapshot, SnapshotOptions, Strategy, ValidateResult,
   };
 
   macro_rules! p {
@@ -264,4 +336,94 @@ mod tests {
     assert!(modified_paths.contains(&p!("/node_modules/lib/file1")));
     assert_eq!(no_change_paths.len(), 1);
   }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_keep_content_hashes() {
+    use std::hash::{BuildHasher, BuildHasherDefault};
+
+    use rustc_hash::FxHasher;
+
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let codec = Arc::new(CacheCodec::new(None));
+    let options = SnapshotOptions::new(vec![], vec![], vec![]);
+
+    fs.create_dir_all("/src".into()).await.unwrap();
+    fs.write("/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    let snapshot = Snapshot::new(options, fs.clone(), storage, codec);
+    snapshot.add([p!("/src/file1")].into_iter()).await;
+
+    // the hash is the same as the content hash of the watcher
+    let hash = BuildHasherDefault::<FxHasher>::default().hash_one("abc".as_bytes());
+    let content_hashes = snapshot.content_hashes();
+    assert_eq!(content_hashes.len(), 1);
+    assert_eq!(content_hashes[0].0, p!("/src/file1"));
+    assert_eq!(content_hashes[0].2, hash);
+
+    snapshot.remove([p!("/src/file1")].into_iter());
+    assert!(snapshot.content_hashes().is_empty());
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_report_invalidated_paths() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let codec = Arc::new(CacheCodec::new(None));
+    let options = SnapshotOptions::new(vec![], vec![], vec![]);
+
+    fs.create_dir_all("/src".into()).await.unwrap();
+    fs.write("/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/src/file2".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/src/file3".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(options, fs.clone(), storage, codec);
+    snapshot
+      .add(
+        [
+          p!("/src/file1"),
+          p!("/src/file2"),
+          p!("/src/file3"),
+          p!("/src/missing"),
+        ]
+        .into_iter(),
+      )
+      .await;
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/src/file1".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    fs.remove_file("/src/file2".into()).await.unwrap();
+    fs.write("/src/missing".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let (is_hot_start, invalidated_paths, no_change_paths) =
+      snapshot.calc_invalidated_paths().await.unwrap();
+    assert!(is_hot_start);
+    assert_eq!(no_change_paths.len(), 1);
+    assert_eq!(invalidated_paths.len(), 3);
+    assert_eq!(invalidated_paths[0].path, p!("/src/file1"));
+    assert_eq!(invalidated_paths[0].result, ValidateResult::Modified);
+    assert!(matches!(
+      invalidated_paths[0].strategy,
+      Strategy::PathHash { .. }
+    ));
+    assert_eq!(invalidated_paths[1].path, p!("/src/file2"));
+    assert_eq!(invalidated_paths[1].result, ValidateResult::Deleted);
+    assert_eq!(invalidated_paths[2].path, p!("/src/missing"));
+    assert_eq!(invalidated_paths[2].result, ValidateResult::Modified);
+    assert!(matches!(invalidated_paths[2].strategy, Strategy::Missing));
+    assert_eq!(
+      invalidated_paths[2].to_string(),
+      "/src/missing modified, it was missing"
+    );
+  }
 }
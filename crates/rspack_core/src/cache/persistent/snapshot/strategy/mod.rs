@@ -14,7 +14,7 @@ use self::{
 
 /// Snapshot check strategy
 #[cacheable]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Strategy {
   /// Check by package version
   ///
@@ -47,7 +47,7 @@ impl PartialEq for Strategy {
 }
 
 /// Validate Result
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValidateResult {
   /// The target file has been deleted
   Deleted,
@@ -6,8 +6,12 @@ use std::{path::PathBuf, sync::Arc};
 pub use memory::MemoryStorage;
 use rspack_cacheable::{cacheable, utils::PortablePath, with::As};
 use rspack_fs::IntermediateFileSystem;
+use rspack_paths::AssertUtf8;
 pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BridgeFileSystem, DirectoryTransport, PackCompression, PackStorage, PackStorageOptions,
+  RemoteStorage, RemoteStorageOptions,
+};
 
 /// Storage Options
 ///
@@ -20,28 +24,125 @@ pub enum StorageOptions {
     #[cacheable(with=As<PortablePath>)]
     directory: PathBuf,
   },
+  /// Use a local directory as `FileSystem` does, and share the packs with other machines
+  /// through a remote directory.
+  ///
+  /// The local directory will be pulled from remote when it does not exist.
+  Remote {
+    #[cacheable(with=As<PortablePath>)]
+    directory: PathBuf,
+    #[cacheable(with=As<PortablePath>)]
+    remote_directory: PathBuf,
+    /// Push the local packs to remote after saved.
+    push: bool,
+  },
+}
+
+impl StorageOptions {
+  /// The options of the local storage, without the options of sharing it.
+  pub fn local(&self) -> Self {
+    match self {
+      Self::FileSystem { directory } | Self::Remote { directory, .. } => Self::FileSystem {
+        directory: directory.clone(),
+      },
+    }
+  }
+}
+
+/// Compression algorithm of pack files
+#[cacheable]
+#[derive(Debug, Clone, Copy, Default, Hash)]
+pub enum StorageCompression {
+  #[default]
+  None,
+  Lz4,
+  Zstd,
+}
+
+impl From<StorageCompression> for PackCompression {
+  fn from(value: StorageCompression) -> Self {
+    match value {
+      StorageCompression::None => PackCompression::None,
+      StorageCompression::Lz4 => PackCompression::Lz4,
+      StorageCompression::Zstd => PackCompression::Zstd,
+    }
+  }
+}
+
+/// Options of pack files in storage
+#[cacheable]
+#[derive(Debug, Clone, Hash)]
+pub struct StoragePackOptions {
+  /// The number of buckets in each scope
+  pub bucket_size: usize,
+  /// The max size of each pack before compression in bytes
+  pub pack_size: usize,
+  /// The time in milliseconds to keep a version after its last save
+  pub expire: u64,
+  pub compression: StorageCompression,
+  /// The max total size of all versions in the cache directory in bytes.
+  ///
+  /// The least recently saved versions are removed when it is exceeded,
+  /// the version in use is always kept.
+  pub max_size: Option<u64>,
+}
+
+impl Default for StoragePackOptions {
+  fn default() -> Self {
+    Self {
+      bucket_size: 20,
+      pack_size: 500 * 1024,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      compression: StorageCompression::None,
+      max_size: None,
+    }
+  }
+}
+
+fn create_pack_options(
+  directory: PathBuf,
+  version: String,
+  options: &StoragePackOptions,
+  fs: Arc<BridgeFileSystem>,
+) -> PackStorageOptions {
+  PackStorageOptions {
+    temp_root: directory.join(".temp"),
+    root: directory,
+    clean: true,
+    bucket_size: options.bucket_size,
+    pack_size: options.pack_size,
+    expire: options.expire,
+    fs,
+    fresh_generation: Some(1),
+    release_generation: Some(2),
+    version,
+    compression: options.compression.into(),
+    max_size: options.max_size,
+  }
 }
 
 pub fn create_storage(
   options: StorageOptions,
+  pack_options: &StoragePackOptions,
   version: String,
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
+  let fs = Arc::new(BridgeFileSystem(fs));
   match options {
-    StorageOptions::FileSystem { directory } => {
-      let option = PackStorageOptions {
-        temp_root: directory.join(".temp"),
-        root: directory,
-        clean: true,
-        bucket_size: 20,
-        pack_size: 500 * 1024,
-        expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
-        fresh_generation: Some(1),
-        release_generation: Some(2),
-        version,
-      };
-      Arc::new(PackStorage::new(option))
-    }
+    StorageOptions::FileSystem { directory } => Arc::new(PackStorage::new(create_pack_options(
+      directory,
+      version,
+      pack_options,
+      fs,
+    ))),
+    StorageOptions::Remote {
+      directory,
+      remote_directory,
+      push,
+    } => Arc::new(RemoteStorage::new(RemoteStorageOptions {
+      pack: create_pack_options(directory, version, pack_options, fs.clone()),
+      transport: Arc::new(DirectoryTransport::new(remote_directory.assert_utf8(), fs)),
+      push,
+    })),
   }
 }
@@ -7,6 +7,7 @@ use std::{
 use indexmap::IndexMap;
 use itertools::Itertools;
 use rayon::prelude::*;
+use rspack_cacheable::cacheable;
 use rspack_collections::{DatabaseItem, IdentifierSet, UkeyIndexMap, UkeyIndexSet, UkeySet};
 use rspack_error::Diagnostic;
 use rspack_hash::{RspackHash, RspackHashDigest};
@@ -18,6 +19,7 @@ use crate::{
   chunk_graph_chunk::ChunkId, compare_chunk_group, sort_group_by_index,
 };
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChunkKind {
   HotUpdate,
@@ -52,6 +54,7 @@ pub struct ChunkRenderResult {
   pub diagnostics: Vec<Diagnostic>,
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct Chunk {
   ukey: ChunkUkey,
@@ -5,7 +5,7 @@ use std::{borrow::Borrow, collections::VecDeque, fmt, sync::Arc};
 use hashlink::LinkedHashMap;
 use indexmap::IndexSet;
 use itertools::Itertools;
-use rspack_cacheable::cacheable;
+use rspack_cacheable::{cacheable, with::AsMap};
 use rspack_collections::{DatabaseItem, IdentifierLinkedMap, IdentifierMap, IdentifierSet};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet};
 use serde::{Serialize, Serializer};
@@ -69,11 +69,13 @@ impl ChunkId {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Default)]
 pub struct ChunkGraphChunk {
   /// URI of modules => ChunkGroupUkey
   ///
   /// use `LinkedHashMap` to keep the ordered from entry array.
+  #[cacheable(with=AsMap)]
   pub(super) entry_modules: IdentifierLinkedMap<ChunkGroupUkey>,
   pub(super) modules: IdentifierSet,
   pub(super) runtime_modules: Vec<ModuleIdentifier>,
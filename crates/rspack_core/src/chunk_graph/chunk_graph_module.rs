@@ -73,6 +73,7 @@ impl ModuleId {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Default)]
 pub struct ChunkGraphModule {
   pub(super) entry_in_chunks: UkeySet<ChunkUkey>,
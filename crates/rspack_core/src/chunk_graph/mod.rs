@@ -2,6 +2,7 @@ use core::fmt;
 use std::borrow::Cow;
 
 use itertools::Itertools;
+use rspack_cacheable::cacheable;
 use rspack_collections::{IdentifierMap, UkeyMap};
 use rspack_util::env::has_query;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
@@ -15,6 +16,7 @@ pub mod chunk_graph_module;
 pub use chunk_graph_chunk::{ChunkGraphChunk, ChunkSizeOptions};
 pub use chunk_graph_module::{ChunkGraphModule, ModuleId};
 
+#[cacheable]
 #[derive(Debug, Clone, Default)]
 pub struct ChunkGraph {
   /// If a module is imported dynamically, it will be assigned to a unique ChunkGroup
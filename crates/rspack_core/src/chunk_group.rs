@@ -5,7 +5,7 @@ use std::{
 
 use indexmap::IndexSet;
 use itertools::Itertools;
-use rspack_cacheable::cacheable;
+use rspack_cacheable::{cacheable, with::AsVec};
 use rspack_collections::{DatabaseItem, IdentifierMap, UkeySet};
 use rspack_error::{Result, error};
 use rustc_hash::FxHashMap as HashMap;
@@ -16,6 +16,7 @@ use crate::{
   ModuleLayer, PublicPath, WasmLoading, compare_chunk_group,
 };
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct OriginRecord {
   pub module: Option<ModuleIdentifier>,
@@ -31,6 +32,7 @@ impl DatabaseItem for ChunkGroup {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ChunkGroup {
   pub ukey: ChunkGroupUkey,
@@ -42,6 +44,7 @@ pub struct ChunkGroup {
   pub(crate) module_post_order_indices: IdentifierMap<usize>,
 
   // keep order for children
+  #[cacheable(with=AsVec)]
   pub children: IndexSet<ChunkGroupUkey>,
   async_entrypoints: UkeySet<ChunkGroupUkey>,
   // ChunkGroupInfo
@@ -369,6 +372,7 @@ impl ChunkGroup {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub enum ChunkGroupKind {
   Entrypoint {
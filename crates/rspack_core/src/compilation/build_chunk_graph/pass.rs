@@ -1,12 +1,19 @@
 use rspack_error::Result;
 
 use crate::{
-  Compilation, build_chunk_graph::build_chunk_graph, logger::Logger, use_code_splitting_cache,
+  Compilation, build_chunk_graph::build_chunk_graph, cache::Cache, logger::Logger,
+  use_code_splitting_cache,
 };
 
-pub async fn build_chunk_graph_pass(compilation: &mut Compilation) -> Result<()> {
+pub async fn build_chunk_graph_pass(
+  compilation: &mut Compilation,
+  cache: &mut dyn Cache,
+) -> Result<()> {
   let logger = compilation.get_logger("rspack.Compilation");
   compilation.module_graph_cache_artifact.freeze();
+  cache
+    .before_build_chunk_graph(&mut compilation.build_chunk_graph_artifact)
+    .await;
   use_code_splitting_cache(compilation, |compilation| async {
     let start = logger.time("rebuild chunk graph");
     build_chunk_graph(compilation)?;
@@ -18,5 +25,6 @@ pub async fn build_chunk_graph_pass(compilation: &mut Compilation) -> Result<()>
     Ok(compilation)
   })
   .await?;
+  cache.after_build_chunk_graph(compilation).await;
   Ok(())
 }
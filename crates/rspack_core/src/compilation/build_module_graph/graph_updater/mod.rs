@@ -6,6 +6,7 @@ use rspack_error::Result;
 use rspack_paths::ArcPathSet;
 use rustc_hash::FxHashSet as HashSet;
 
+pub(crate) use self::repair::should_defer_branch_dependency;
 use self::{cutout::Cutout, repair::repair};
 use super::{BuildModuleGraphArtifact, BuildModuleGraphArtifactState};
 use crate::{Compilation, DependencyId};
@@ -1,6 +1,6 @@
 use rspack_error::Result;
 
-use super::{TaskContext, build::BuildTask, lazy::ProcessUnlazyDependenciesTask};
+use super::{TaskContext, build::BuildTask, lazy::forward_ids_to_module};
 use crate::{
   BoxDependency, BoxModule, ModuleIdentifier, ModuleProfile,
   compilation::build_module_graph::ForwardedIdSet,
@@ -46,7 +46,10 @@ impl Task<TaskContext> for AddTask {
     }
 
     let forwarded_ids = if context.compiler_options.experiments.lazy_barrel {
-      ForwardedIdSet::from_dependencies(&self.dependencies)
+      context
+        .artifact
+        .module_to_lazy_make
+        .forwarded_ids(&self.dependencies)
     } else {
       ForwardedIdSet::All
     };
@@ -71,28 +74,11 @@ impl Task<TaskContext> for AddTask {
             .mark_as_add(&module_identifier);
         }
 
-        if module_graph
-          .module_by_identifier(&module_identifier)
-          .is_some()
-        {
-          if context
-            .artifact
-            .module_to_lazy_make
-            .has_lazy_dependencies(&module_identifier)
-            && !forwarded_ids.is_empty()
-          {
-            return Ok(vec![Box::new(ProcessUnlazyDependenciesTask {
-              forwarded_ids,
-              original_module_identifier: module_identifier,
-            })]);
-          }
-        } else {
-          let pending_forwarded_ids = context
-            .artifact
-            .module_to_lazy_make
-            .pending_forwarded_ids(module_identifier);
-          pending_forwarded_ids.append(forwarded_ids);
-        }
+        return Ok(
+          forward_ids_to_module(context, module_identifier, forwarded_ids)
+            .into_iter()
+            .collect(),
+        );
       }
 
       return Ok(vec![]);
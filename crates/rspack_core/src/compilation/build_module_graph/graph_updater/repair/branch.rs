@@ -0,0 +1,86 @@
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::{TaskContext, process_dependencies::ProcessDependenciesTask};
+use crate::{
+  BranchCondition, BranchTestValue, DependencyId, DependencyType, ModuleGraph,
+  ModuleGraphCacheArtifact, internal, utils::task_loop::Task,
+};
+
+/// Whether the dependency is in a branch guarded by an imported constant, the value of the
+/// constant is unknown until the imported module is built.
+///
+/// The `import` specifiers are not deferred, the imported modules are built by the `import`
+/// statements anyway, and neither are the lazy dependencies of `experiments.lazyBarrel`.
+pub(crate) fn should_defer_branch_dependency(
+  module_graph: &ModuleGraph,
+  branch_conditions: &HashMap<DependencyId, Vec<BranchCondition>>,
+  dependency_id: &DependencyId,
+) -> bool {
+  branch_conditions
+    .get(dependency_id)
+    .is_some_and(|conditions| {
+      conditions
+        .iter()
+        .any(|condition| matches!(condition.value, BranchTestValue::Import { .. }))
+    })
+    && {
+      let dependency = module_graph.dependency_by_id(dependency_id);
+      (dependency.as_module_dependency().is_some() || dependency.as_context_dependency().is_some())
+        && dependency.lazy().is_none()
+        && dependency.dependency_type() != &DependencyType::EsmImportSpecifier
+    }
+}
+
+/// Processes the deferred dependencies that are not known to be in dead branches, it runs after
+/// all the other tasks are finished so that the imported constants are built.
+///
+/// The dependencies in dead branches stay deferred and are never factorized, they are processed
+/// by a later rebuild if the constants change.
+pub(super) fn process_deferred_branch_dependencies(
+  context: &mut TaskContext,
+  mg_cache: &ModuleGraphCacheArtifact,
+) -> Vec<Box<dyn Task<TaskContext>>> {
+  let module_graph = &context.artifact.module_graph;
+  let mut dependencies_to_process = HashMap::default();
+  let mut removed = HashSet::default();
+  for dependency_id in &context.artifact.deferred_branch_dependencies {
+    let Some(module) = internal::try_dependency_by_id(module_graph, dependency_id)
+      .and_then(|_| module_graph.get_parent_module(dependency_id))
+      .and_then(|module| module_graph.module_by_identifier(module))
+    else {
+      removed.insert(*dependency_id);
+      continue;
+    };
+    let is_dead = module
+      .build_info()
+      .branch_conditions
+      .get(dependency_id)
+      .is_some_and(|conditions| {
+        conditions
+          .iter()
+          .any(|condition| condition.evaluate_declared(module_graph, mg_cache) == Some(false))
+      });
+    if !is_dead {
+      removed.insert(*dependency_id);
+      dependencies_to_process
+        .entry(module.identifier())
+        .or_insert_with(Vec::new)
+        .push(*dependency_id);
+    }
+  }
+  context
+    .artifact
+    .deferred_branch_dependencies
+    .retain(|dependency_id| !removed.contains(dependency_id));
+
+  dependencies_to_process
+    .into_iter()
+    .map(|(original_module_identifier, dependencies)| {
+      Box::new(ProcessDependenciesTask {
+        original_module_identifier,
+        dependencies,
+        from_unlazy: false,
+      }) as Box<dyn Task<TaskContext>>
+    })
+    .collect()
+}
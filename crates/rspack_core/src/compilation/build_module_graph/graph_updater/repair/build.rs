@@ -4,7 +4,8 @@ use rspack_fs::ReadableFileSystem;
 use rustc_hash::FxHashSet;
 
 use super::{
-  TaskContext, lazy::ProcessUnlazyDependenciesTask, process_dependencies::ProcessDependenciesTask,
+  TaskContext, branch::should_defer_branch_dependency, lazy::ProcessUnlazyDependenciesTask,
+  process_dependencies::ProcessDependenciesTask,
 };
 use crate::{
   AsyncDependenciesBlock, BoxDependency, BoxModule, BuildContext, BuildResult, CompilationId,
@@ -210,7 +211,7 @@ impl Task<TaskContext> for BuildResultTask {
 
     let mut tasks: Vec<Box<dyn Task<TaskContext>>> = vec![];
 
-    let dependencies_to_process = if !lazy_dependencies.is_empty() {
+    let mut dependencies_to_process = if !lazy_dependencies.is_empty() {
       let lazy_dependency_ids = lazy_dependencies
         .all_lazy_dependencies()
         .collect::<FxHashSet<_>>();
@@ -239,6 +240,23 @@ impl Task<TaskContext> for BuildResultTask {
       all_dependencies
     };
 
+    let module_graph = &context.artifact.module_graph;
+    let branch_conditions = &module_graph
+      .module_by_identifier(&module_identifier)
+      .expect("should have module")
+      .build_info()
+      .branch_conditions;
+    if !branch_conditions.is_empty() {
+      let deferred_branch_dependencies = &mut context.artifact.deferred_branch_dependencies;
+      dependencies_to_process.retain(|dependency_id| {
+        if should_defer_branch_dependency(module_graph, branch_conditions, dependency_id) {
+          deferred_branch_dependencies.insert(*dependency_id);
+          return false;
+        }
+        true
+      });
+    }
+
     tasks.push(Box::new(ProcessDependenciesTask {
       dependencies: dependencies_to_process,
       original_module_identifier: module_identifier,
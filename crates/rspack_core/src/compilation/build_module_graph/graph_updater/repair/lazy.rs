@@ -32,6 +32,40 @@ impl Task<TaskContext> for ProcessUnlazyDependenciesTask {
       .requested_lazy_dependencies(&forwarded_ids)
       .into_iter()
       .collect();
+    let requested_fallback_deps = lazy_dependencies
+      .requested_fallback_dependencies(&forwarded_ids)
+      .map(|(deps, ids)| (deps.iter().copied().collect::<Vec<_>>(), ids));
+
+    let mut tasks: Vec<Box<dyn Task<TaskContext>>> = vec![];
+
+    // the ids requested through `export * from` are forwarded to the target modules, the modules
+    // that have been built already need to unlazy the dependencies for the new ids
+    if let Some((fallback_deps, ids)) = requested_fallback_deps {
+      let mut forward_to_modules: Vec<(ModuleIdentifier, ForwardedIdSet)> = vec![];
+      for dep in fallback_deps {
+        let new_ids = context
+          .artifact
+          .module_to_lazy_make
+          .append_fallback_forwarded_ids(dep, &ids);
+        if new_ids.is_empty() {
+          continue;
+        }
+        let module_graph = &context.artifact.module_graph;
+        if module_graph.dependency_by_id(&dep).lazy().is_some() {
+          // the ids are forwarded when the dependency is processed
+          continue;
+        }
+        if let Some(module) = module_graph.module_identifier_by_dependency_id(&dep) {
+          match forward_to_modules.iter_mut().find(|(m, _)| m == module) {
+            Some((_, forwarded_ids)) => forwarded_ids.append(new_ids),
+            None => forward_to_modules.push((*module, new_ids)),
+          }
+        }
+      }
+      for (module, forwarded_ids) in forward_to_modules {
+        tasks.extend(forward_ids_to_module(context, module, forwarded_ids));
+      }
+    }
 
     let module_graph = &mut context.artifact.module_graph;
     let dependencies_to_process: Vec<DependencyId> = requested_deps
@@ -41,13 +75,47 @@ impl Task<TaskContext> for ProcessUnlazyDependenciesTask {
         dep.unset_lazy()
       })
       .collect();
-    if dependencies_to_process.is_empty() {
-      return Ok(vec![]);
+    if !dependencies_to_process.is_empty() {
+      tasks.push(Box::new(ProcessDependenciesTask {
+        dependencies: dependencies_to_process,
+        original_module_identifier,
+        from_unlazy: true,
+      }));
     }
-    return Ok(vec![Box::new(ProcessDependenciesTask {
-      dependencies: dependencies_to_process,
-      original_module_identifier,
-      from_unlazy: true,
-    })]);
+    Ok(tasks)
+  }
+}
+
+/// Forwards the ids to a module that is already added to the module graph, the lazy dependencies
+/// of the module are processed after it's built if it's still building.
+pub(super) fn forward_ids_to_module(
+  context: &mut TaskContext,
+  module: ModuleIdentifier,
+  forwarded_ids: ForwardedIdSet,
+) -> Option<Box<dyn Task<TaskContext>>> {
+  if context
+    .artifact
+    .module_graph
+    .module_by_identifier(&module)
+    .is_some()
+  {
+    if context
+      .artifact
+      .module_to_lazy_make
+      .has_lazy_dependencies(&module)
+      && !forwarded_ids.is_empty()
+    {
+      return Some(Box::new(ProcessUnlazyDependenciesTask {
+        forwarded_ids,
+        original_module_identifier: module,
+      }));
+    }
+  } else {
+    let pending_forwarded_ids = context
+      .artifact
+      .module_to_lazy_make
+      .pending_forwarded_ids(module);
+    pending_forwarded_ids.append(forwarded_ids);
   }
+  None
 }
@@ -1,4 +1,5 @@
 pub mod add;
+mod branch;
 pub mod build;
 pub mod context;
 pub mod factorize;
@@ -8,6 +9,7 @@ pub mod process_dependencies;
 use rspack_error::Result;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+pub(crate) use self::branch::should_defer_branch_dependency;
 use self::context::TaskContext;
 use super::BuildModuleGraphArtifact;
 use crate::{
@@ -67,5 +69,15 @@ pub async fn repair(
 
   let mut ctx = TaskContext::new(compilation, artifact);
   run_task_loop(&mut ctx, init_tasks).await?;
+  loop {
+    let tasks = branch::process_deferred_branch_dependencies(
+      &mut ctx,
+      &compilation.module_graph_cache_artifact,
+    );
+    if tasks.is_empty() {
+      break;
+    }
+    run_task_loop(&mut ctx, tasks).await?;
+  }
   Ok(ctx.artifact)
 }
@@ -15,7 +15,7 @@ pub enum ForwardId {
   Empty,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ForwardedIdSet {
   All,
   IdSet(FxHashSet<Atom>),
@@ -26,18 +26,12 @@ impl ForwardedIdSet {
     Self::IdSet(FxHashSet::default())
   }
 
-  pub fn from_dependencies(dependencies: &[BoxDependency]) -> Self {
-    let mut set = FxHashSet::default();
-    for dep in dependencies {
-      match dep.forward_id() {
-        ForwardId::All => return Self::All,
-        ForwardId::Id(id) => {
-          set.insert(id);
-        }
-        ForwardId::Empty => {}
-      }
+  pub fn from_forward_id(forward_id: ForwardId) -> Self {
+    match forward_id {
+      ForwardId::All => Self::All,
+      ForwardId::Id(id) => Self::IdSet(FxHashSet::from_iter([id])),
+      ForwardId::Empty => Self::empty(),
     }
-    Self::IdSet(set)
   }
 
   pub fn append(&mut self, other: Self) {
@@ -54,6 +48,26 @@ impl ForwardedIdSet {
     }
   }
 
+  /// Appends the ids of `other`, returns the ids that are not contained before.
+  pub fn append_new(&mut self, other: &Self) -> Self {
+    match self {
+      Self::All => Self::empty(),
+      Self::IdSet(set) => match other {
+        Self::All => {
+          *self = Self::All;
+          Self::All
+        }
+        Self::IdSet(other) => Self::IdSet(
+          other
+            .iter()
+            .filter(|id| set.insert((*id).clone()))
+            .cloned()
+            .collect(),
+        ),
+      },
+    }
+  }
+
   pub fn is_empty(&self) -> bool {
     match self {
       Self::All => false,
@@ -138,6 +152,10 @@ impl LazyDependencies {
       ForwardedIdSet::IdSet(set) => set
         .iter()
         .filter(|forward_id| !self.terminal_forward_ids.contains(*forward_id))
+        .filter(|forward_id| {
+          // `export *` never re-exports the default export
+          self.forward_id_to_request.contains_key(*forward_id) || *forward_id != "default"
+        })
         .flat_map(|forward_id| {
           self
             .forward_id_to_request
@@ -149,6 +167,32 @@ impl LazyDependencies {
         .collect(),
     }
   }
+
+  /// The dependencies of `export * from` and the ids they are requested by, the ids are forwarded
+  /// to the target modules instead of requesting all of their exports.
+  pub fn requested_fallback_dependencies(
+    &self,
+    forwarded_ids: &ForwardedIdSet,
+  ) -> Option<(&FxHashSet<DependencyId>, ForwardedIdSet)> {
+    if self.fallback_dependencies.is_empty() {
+      return None;
+    }
+    let ids = match forwarded_ids {
+      ForwardedIdSet::All => ForwardedIdSet::All,
+      ForwardedIdSet::IdSet(set) => ForwardedIdSet::IdSet(
+        set
+          .iter()
+          .filter(|forward_id| {
+            !self.terminal_forward_ids.contains(*forward_id)
+              && !self.forward_id_to_request.contains_key(*forward_id)
+              && *forward_id != "default"
+          })
+          .cloned()
+          .collect(),
+      ),
+    };
+    (!ids.is_empty()).then_some((&self.fallback_dependencies, ids))
+  }
 }
 
 #[derive(Debug)]
@@ -160,6 +204,8 @@ pub enum HasLazyDependencies {
 #[derive(Debug, Default)]
 pub struct ModuleToLazyMake {
   module_to_lazy_dependencies: IdentifierMap<HasLazyDependencies>,
+  /// The ids requested through the dependencies of `export * from`
+  fallback_forwarded_ids: FxHashMap<DependencyId, ForwardedIdSet>,
 }
 
 impl ModuleToLazyMake {
@@ -178,12 +224,46 @@ impl ModuleToLazyMake {
     module: ModuleIdentifier,
     to: Option<LazyDependencies>,
   ) -> Option<HasLazyDependencies> {
-    match to {
+    let old = match to {
       Some(lazy_dependencies) => self
         .module_to_lazy_dependencies
         .insert(module, HasLazyDependencies::Has(lazy_dependencies)),
       None => self.module_to_lazy_dependencies.remove(&module),
+    };
+    // the dependencies of the rebuilt module are recreated
+    if let Some(HasLazyDependencies::Has(old)) = &old {
+      for dependency in &old.fallback_dependencies {
+        self.fallback_forwarded_ids.remove(dependency);
+      }
+    }
+    old
+  }
+
+  /// The ids forwarded to the module that the dependencies are resolved to.
+  pub fn forwarded_ids(&self, dependencies: &[BoxDependency]) -> ForwardedIdSet {
+    let mut forwarded_ids = ForwardedIdSet::empty();
+    for dep in dependencies {
+      let ids = match self.fallback_forwarded_ids.get(dep.id()) {
+        Some(ids) => ids.clone(),
+        None => ForwardedIdSet::from_forward_id(dep.forward_id()),
+      };
+      forwarded_ids.append(ids);
     }
+    forwarded_ids
+  }
+
+  /// Records the ids requested through a dependency of `export * from`, returns the ids that are
+  /// not requested before.
+  pub fn append_fallback_forwarded_ids(
+    &mut self,
+    dependency: DependencyId,
+    forwarded_ids: &ForwardedIdSet,
+  ) -> ForwardedIdSet {
+    self
+      .fallback_forwarded_ids
+      .entry(dependency)
+      .or_insert_with(ForwardedIdSet::empty)
+      .append_new(forwarded_ids)
   }
 
   pub fn has_lazy_dependencies(&self, module: &ModuleIdentifier) -> bool {
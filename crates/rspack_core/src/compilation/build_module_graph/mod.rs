@@ -8,6 +8,7 @@ use rspack_error::Result;
 use rspack_util::tracing_preset::TRACING_BENCH_TARGET;
 use tracing::instrument;
 
+pub(crate) use self::graph_updater::should_defer_branch_dependency;
 pub use self::{
   graph_updater::{UpdateParam, update_module_graph},
   lazy_barrel_artifact::{
@@ -19,6 +19,13 @@ pub async fn chunk_ids_pass(
   let mut diagnostics = vec![];
   let mut chunk_by_ukey = mem::take(&mut compilation.chunk_by_ukey);
   let mut named_chunk_ids_artifact = mem::take(&mut compilation.named_chunk_ids_artifact);
+  if let Some(ids) = compilation
+    .records
+    .as_ref()
+    .and_then(|records| records.ids.as_ref())
+  {
+    ids.revive_chunk_ids(compilation, &mut chunk_by_ukey);
+  }
   plugin_driver
     .compilation_hooks
     .chunk_ids
@@ -1,12 +1,16 @@
 use super::*;
-use crate::logger::Logger;
+use crate::{cache::Cache, logger::Logger};
 
 pub async fn code_generation_pass(
   compilation: &mut Compilation,
   plugin_driver: SharedPluginDriver,
+  cache: &mut dyn Cache,
 ) -> Result<()> {
   let logger = compilation.get_logger("rspack.Compilation");
   let start = logger.time("code generation");
+  cache
+    .before_code_generation(&mut compilation.code_generate_cache_artifact)
+    .await;
   let code_generation_modules = if let Some(mutations) = compilation
     .incremental
     .mutations_read(IncrementalPasses::MODULES_CODEGEN)
@@ -39,7 +43,7 @@ pub async fn code_generation_pass(
     ));
     modules
   } else {
-    compilation.code_generation_results = Default::default();
+    compilation.code_generation_results = BindingCell::from(CodeGenerationResults::default());
     compilation
       .get_module_graph()
       .modules()
@@ -48,6 +52,9 @@ pub async fn code_generation_pass(
       .collect()
   };
   compilation.code_generation(code_generation_modules).await?;
+  cache
+    .after_code_generation(&compilation.code_generate_cache_artifact)
+    .await;
 
   let mut diagnostics = vec![];
   plugin_driver
@@ -13,6 +13,14 @@ pub async fn make_hook_pass(
     .before_build_module_graph(&mut compilation.build_module_graph_artifact)
     .await;
 
+  // the modules restored from persistent cache are available now
+  if let Some(report) = &mut compilation.cache_invalidation_report {
+    report.collect_modules(&compilation.build_module_graph_artifact.module_graph);
+  }
+  if let Some(report) = &compilation.cache_invalidation_report {
+    report.log(&compilation.get_logger("rspack.persistentCache"));
+  }
+
   let start = logger.time("make hook");
   plugin_driver.compiler_hooks.make.call(compilation).await?;
   logger.time_end(start);
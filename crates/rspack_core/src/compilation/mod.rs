@@ -66,17 +66,19 @@ use crate::{
   CacheOptions, CgcRuntimeRequirementsArtifact, CgmHashArtifact, CgmRuntimeRequirementsArtifact,
   Chunk, ChunkByUkey, ChunkContentHash, ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey,
   ChunkHashesArtifact, ChunkKind, ChunkNamedIdArtifact, ChunkRenderArtifact,
-  ChunkRenderCacheArtifact, ChunkRenderResult, ChunkUkey, CodeGenerateCacheArtifact,
-  CodeGenerationJob, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
-  CompilationLogging, CompilerOptions, CompilerPlatform, ConcatenationScope,
+  ChunkRenderCacheArtifact, ChunkRenderResult, ChunkUkey, ClassMembersArtifact,
+  CodeGenerateCacheArtifact, CodeGenerationJob, CodeGenerationResult, CodeGenerationResults,
+  CompilationLogger, CompilationLogging, CompilerOptions, CompilerPlatform, ConcatenationScope,
   DependenciesDiagnosticsArtifact, DependencyCodeGeneration, DependencyTemplate,
   DependencyTemplateType, DependencyType, DerefOption, Entry, EntryData, EntryOptions,
   EntryRuntime, Entrypoint, ExecuteModuleId, Filename, ImportPhase, ImportVarMap,
-  ImportedByDeferModulesArtifact, MemoryGCStorage, ModuleFactory, ModuleGraph,
-  ModuleGraphCacheArtifact, ModuleIdentifier, ModuleIdsArtifact, ModuleStaticCacheArtifact,
-  PathData, ProcessRuntimeRequirementsCacheArtifact, ResolverFactory, RuntimeGlobals,
-  RuntimeKeyMap, RuntimeMode, RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate,
-  SharedPluginDriver, SideEffectsOptimizeArtifact, SourceType, Stats, ValueCacheVersions,
+  ImportedByDeferModulesArtifact, InlineExportsArtifact, MangleExportsArtifact, MemoryGCStorage,
+  ModuleConcatenationArtifact, ModuleFactory, ModuleGraph, ModuleGraphCacheArtifact,
+  ModuleIdentifier, ModuleIdsArtifact, ModuleStaticCacheArtifact, PathData,
+  ProcessRuntimeRequirementsCacheArtifact, ResolverFactory, RuntimeGlobals, RuntimeKeyMap,
+  RuntimeMode, RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver,
+  SideEffectsOptimizeArtifact, SourceType, SplitChunksArtifact, Stats, ValueCacheVersions,
+  cache::persistent::CacheInvalidationReport,
   compilation::build_module_graph::{
     BuildModuleGraphArtifact, ModuleExecutor, UpdateParam, update_module_graph,
   },
@@ -227,6 +229,16 @@ pub struct Compilation {
   pub dependencies_diagnostics_artifact: Arc<AtomicRefCell<DependenciesDiagnosticsArtifact>>,
   // artifact for side_effects_flag_plugin
   pub side_effects_optimize_artifact: DerefOption<SideEffectsOptimizeArtifact>,
+  // artifact for inline_exports_plugin
+  pub inline_exports_artifact: Arc<AtomicRefCell<InlineExportsArtifact>>,
+  // artifact for mangle_exports_plugin
+  pub mangle_exports_artifact: Arc<AtomicRefCell<MangleExportsArtifact>>,
+  // artifact for remove_unused_class_members_plugin
+  pub class_members_artifact: Arc<AtomicRefCell<ClassMembersArtifact>>,
+  // artifact for module_concatenation_plugin
+  pub module_concatenation_artifact: ModuleConcatenationArtifact,
+  // artifact for split_chunks_plugin
+  pub split_chunks_artifact: SplitChunksArtifact,
   // artifact for module_ids
   pub module_ids_artifact: ModuleIdsArtifact,
   // artifact for named_chunk_ids
@@ -287,6 +299,8 @@ pub struct Compilation {
   ///
   /// Rebuild will include previous compilation data, so persistent cache will not recovery anything
   pub is_rebuild: bool,
+  /// The persistent cache entries invalidated in this compilation, only set when persistent cache is enabled.
+  pub cache_invalidation_report: Option<CacheInvalidationReport>,
   pub compiler_context: Arc<CompilerContext>,
 }
 
@@ -370,6 +384,11 @@ impl Compilation {
         DependenciesDiagnosticsArtifact::default(),
       )),
       side_effects_optimize_artifact: DerefOption::new(Default::default()),
+      inline_exports_artifact: Default::default(),
+      mangle_exports_artifact: Default::default(),
+      class_members_artifact: Default::default(),
+      module_concatenation_artifact: Default::default(),
+      split_chunks_artifact: Default::default(),
       module_ids_artifact: Default::default(),
       named_chunk_ids_artifact: Default::default(),
       code_generation_results: Default::default(),
@@ -417,6 +436,7 @@ impl Compilation {
       intermediate_filesystem,
       output_filesystem,
       is_rebuild,
+      cache_invalidation_report: None,
       compiler_context,
     }
   }
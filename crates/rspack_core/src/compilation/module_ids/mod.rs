@@ -18,6 +18,13 @@ pub async fn module_ids_pass(
 
   let mut diagnostics = vec![];
   let mut module_ids_artifact = mem::take(&mut compilation.module_ids_artifact);
+  if let Some(ids) = compilation
+    .records
+    .as_ref()
+    .and_then(|records| records.ids.as_ref())
+  {
+    ids.revive_module_ids(compilation, &mut module_ids_artifact);
+  }
   plugin_driver
     .compilation_hooks
     .module_ids
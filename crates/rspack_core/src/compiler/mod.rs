@@ -1,4 +1,6 @@
 mod rebuild;
+mod records;
+mod verify;
 use std::sync::{Arc, atomic::AtomicU32};
 
 use futures::future::join_all;
@@ -12,7 +14,7 @@ use rspack_util::{node_path::NodePath, tracing_preset::TRACING_BENCH_TARGET};
 use rustc_hash::FxHashMap as HashMap;
 use tracing::instrument;
 
-pub use self::rebuild::CompilationRecords;
+pub use self::{rebuild::CompilationRecords, records::IdsRecords};
 use crate::{
   BoxPlugin, CleanOptions, Compilation, CompilationAsset, CompilerOptions, CompilerPlatform,
   ContextModuleFactory, Filename, KeepPattern, NormalModuleFactory, PluginDriver, ResolverFactory,
@@ -213,6 +215,7 @@ impl Compiler {
     let compilation_id = self.compilation.id();
     let _guard = scopeguard::guard((), move |_| plugin_driver_clone.clear_cache(compilation_id));
 
+    let records = self.read_records().await?;
     fast_set(
       &mut self.compilation,
       Compilation::new(
@@ -223,7 +226,7 @@ impl Compiler {
         self.buildtime_plugin_driver.clone(),
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
-        None,
+        records,
         Incremental::new_cold(self.options.experiments.incremental),
         Some(Default::default()),
         Default::default(),
@@ -304,6 +307,10 @@ impl Compiler {
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
     Ok(())
   }
 
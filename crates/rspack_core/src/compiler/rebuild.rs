@@ -7,6 +7,7 @@ use rspack_paths::ArcPathSet;
 use rspack_tasks::within_compiler_context;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use super::records::IdsRecords;
 use crate::{
   ChunkGraph, ChunkKind, Compilation, Compiler, RuntimeSpec,
   chunk_graph_chunk::ChunkId,
@@ -40,6 +41,12 @@ impl Compiler {
     deleted_files: std::collections::HashSet<String>,
   ) -> Result<()> {
     let records = CompilationRecords::record(&self.compilation);
+    let verify_records = self
+      .options
+      .experiments
+      .incremental
+      .verify
+      .then(|| records.clone());
 
     // build without stats
     {
@@ -115,8 +122,28 @@ impl Compiler {
         &mut new_compilation.side_effects_optimize_artifact,
         &mut self.compilation.side_effects_optimize_artifact,
       );
+      recover_artifact(
+        incremental,
+        &mut new_compilation.inline_exports_artifact,
+        &mut self.compilation.inline_exports_artifact,
+      );
+      recover_artifact(
+        incremental,
+        &mut new_compilation.mangle_exports_artifact,
+        &mut self.compilation.mangle_exports_artifact,
+      );
 
       // Direct type artifacts
+      recover_artifact(
+        incremental,
+        &mut new_compilation.module_concatenation_artifact,
+        &mut self.compilation.module_concatenation_artifact,
+      );
+      recover_artifact(
+        incremental,
+        &mut new_compilation.split_chunks_artifact,
+        &mut self.compilation.split_chunks_artifact,
+      );
       recover_artifact(
         incremental,
         &mut new_compilation.module_ids_artifact,
@@ -178,6 +205,10 @@ impl Compiler {
       self.compile().await?;
     }
 
+    if let Some(records) = verify_records {
+      self.verify_incremental_rebuild(records).await?;
+    }
+
     self.compile_done().await?;
     self.cache.after_compile(&self.compilation).await;
 
@@ -188,13 +219,15 @@ impl Compiler {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CompilationRecords {
   pub runtimes: RuntimeSpec,
   pub runtime_modules: IdentifierMap<RspackHashDigest>,
   pub chunks: FxHashMap<ChunkId, (RuntimeSpec, FxHashSet<ModuleId>)>,
   pub modules: FxHashMap<ModuleId, FxHashMap<ChunkId, RspackHashDigest>>,
   pub hash: Option<RspackHashDigest>,
+  /// Only recorded when `recordsInputPath` or `recordsOutputPath` is configured
+  pub ids: Option<IdsRecords>,
 }
 
 impl CompilationRecords {
@@ -205,6 +238,9 @@ impl CompilationRecords {
       chunks: Self::record_chunks(compilation),
       modules: Self::record_modules(compilation),
       hash: Self::record_hash(compilation),
+      ids: (compilation.options.records_input_path.is_some()
+        || compilation.options.records_output_path.is_some())
+      .then(|| IdsRecords::record(compilation)),
     }
   }
 
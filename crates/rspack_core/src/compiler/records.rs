@@ -0,0 +1,427 @@
+use std::collections::BTreeMap;
+
+use rspack_collections::{DatabaseItem, Identifier};
+use rspack_error::{Result, error};
+use rspack_hash::RspackHashDigest;
+use rspack_paths::Utf8Path;
+use rspack_util::identifier::make_paths_relative;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::CompilationRecords;
+use crate::{
+  Chunk, ChunkByUkey, ChunkGraph, ChunkUkey, Compilation, Compiler, ModuleId, ModuleIdsArtifact,
+  RuntimeSpec, chunk_graph_chunk::ChunkId, incremental::Mutation,
+};
+
+/// The module ids and chunk ids of the last compilation, used to assign the same ids to the same
+/// modules and chunks in the next compilation, like webpack's `RecordIdsPlugin`.
+#[derive(Debug, Clone, Default)]
+pub struct IdsRecords {
+  /// Module ids by the module identifier relative to the context
+  pub modules: FxHashMap<String, ModuleId>,
+  /// Chunk ids by the chunk name
+  pub chunks_by_name: FxHashMap<String, ChunkId>,
+  /// Chunk ids by the sources of the chunk, see [`get_chunk_sources`]
+  pub chunks_by_source: FxHashMap<String, ChunkId>,
+}
+
+impl IdsRecords {
+  pub fn record(compilation: &Compilation) -> Self {
+    let context = compilation.options.context.as_str();
+    let modules = compilation
+      .module_ids_artifact
+      .iter()
+      .map(|(identifier, id)| (make_paths_relative(context, identifier), id.clone()))
+      .collect();
+
+    let mut chunks_by_name = FxHashMap::default();
+    let mut chunks_by_source = FxHashMap::default();
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some(id) = chunk.id() else {
+        continue;
+      };
+      if let Some(name) = chunk.name() {
+        chunks_by_name.insert(name.to_string(), id.clone());
+      }
+      for source in get_chunk_sources(compilation, chunk) {
+        chunks_by_source.insert(source, id.clone());
+      }
+    }
+
+    Self {
+      modules,
+      chunks_by_name,
+      chunks_by_source,
+    }
+  }
+
+  /// Assigns the recorded ids to the modules that don't have an id yet.
+  pub fn revive_module_ids(&self, compilation: &Compilation, module_ids: &mut ModuleIdsArtifact) {
+    if self.modules.is_empty() {
+      return;
+    }
+    let context = compilation.options.context.as_str();
+    let mut used_ids: FxHashSet<ModuleId> = module_ids.values().cloned().collect();
+    let mut revived = vec![];
+    for (identifier, module) in compilation.get_module_graph().modules() {
+      if !module.need_id() || ChunkGraph::get_module_id(module_ids, identifier).is_some() {
+        continue;
+      }
+      let Some(id) = self
+        .modules
+        .get(&make_paths_relative(context, identifier.as_str()))
+      else {
+        continue;
+      };
+      if !used_ids.insert(id.clone()) {
+        continue;
+      }
+      ChunkGraph::set_module_id(module_ids, identifier, id.clone());
+      revived.push(identifier);
+    }
+
+    if let Some(mut mutations) = compilation.incremental.mutations_write() {
+      for module in revived {
+        mutations.add(Mutation::ModuleSetId { module });
+      }
+    }
+  }
+
+  /// Assigns the recorded ids to the chunks that don't have an id yet, by the chunk name first and
+  /// then by the sources of the chunk.
+  pub fn revive_chunk_ids(&self, compilation: &Compilation, chunk_by_ukey: &mut ChunkByUkey) {
+    if self.chunks_by_name.is_empty() && self.chunks_by_source.is_empty() {
+      return;
+    }
+    let mut used_ids: FxHashSet<ChunkId> = chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.id().cloned())
+      .collect();
+    let mut revived: Vec<(ChunkUkey, ChunkId)> = vec![];
+
+    let mut chunks = chunk_by_ukey
+      .values()
+      .filter(|chunk| chunk.id().is_none())
+      .collect::<Vec<_>>();
+    chunks.retain(|chunk| {
+      if let Some(name) = chunk.name()
+        && let Some(id) = self.chunks_by_name.get(name)
+        && used_ids.insert(id.clone())
+      {
+        revived.push((chunk.ukey(), id.clone()));
+        return false;
+      }
+      true
+    });
+    for chunk in chunks {
+      let id = get_chunk_sources(compilation, chunk)
+        .into_iter()
+        .find_map(|source| {
+          self
+            .chunks_by_source
+            .get(&source)
+            .filter(|id| !used_ids.contains(*id))
+        });
+      if let Some(id) = id {
+        used_ids.insert(id.clone());
+        revived.push((chunk.ukey(), id.clone()));
+      }
+    }
+
+    let mut mutations = compilation.incremental.mutations_write();
+    for (chunk, id) in revived {
+      chunk_by_ukey.expect_get_mut(&chunk).set_id(id);
+      if let Some(mutations) = &mut mutations {
+        mutations.add(Mutation::ChunkSetId { chunk });
+      }
+    }
+  }
+}
+
+/// The sources of a chunk are the chunk groups it belongs to, identified by the chunk group name or
+/// the origins of the chunk group, together with the index of the chunk in the chunk group.
+fn get_chunk_sources(compilation: &Compilation, chunk: &Chunk) -> Vec<String> {
+  let context = compilation.options.context.as_str();
+  let mut sources = vec![];
+  for group in chunk.groups() {
+    let group = compilation.chunk_group_by_ukey.expect_get(group);
+    let Some(index) = group.chunks.iter().position(|c| *c == chunk.ukey()) else {
+      continue;
+    };
+    if let Some(name) = group.name() {
+      sources.push(format!("{index} {name}"));
+      continue;
+    }
+    for origin in group.origins() {
+      if let Some(module) = origin.module
+        && let Some(request) = &origin.request
+      {
+        sources.push(format!(
+          "{index} {} {request}",
+          make_paths_relative(context, module.as_str())
+        ));
+      }
+    }
+  }
+  sources
+}
+
+/// The records file, which is compatible with the records of the last compilation kept in memory.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordsFile {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  hash: Option<String>,
+  #[serde(default)]
+  runtimes: Vec<String>,
+  #[serde(default)]
+  runtime_modules: BTreeMap<String, String>,
+  #[serde(default)]
+  chunk_modules: BTreeMap<String, ChunkModulesRecord>,
+  #[serde(default)]
+  module_hashes: BTreeMap<String, BTreeMap<String, String>>,
+  #[serde(default)]
+  modules: ModulesRecord,
+  #[serde(default)]
+  chunks: ChunksRecord,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ChunkModulesRecord {
+  runtime: Vec<String>,
+  modules: Vec<RecordId>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModulesRecord {
+  #[serde(default)]
+  by_identifier: BTreeMap<String, RecordId>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChunksRecord {
+  #[serde(default)]
+  by_name: BTreeMap<String, RecordId>,
+  #[serde(default)]
+  by_source: BTreeMap<String, RecordId>,
+}
+
+/// Ids are written as numbers if possible, like the module ids in the output.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RecordId {
+  Number(u32),
+  String(String),
+}
+
+impl RecordId {
+  fn new(id: &str) -> Self {
+    match id.parse::<u32>() {
+      Ok(n) if n.to_string() == id => Self::Number(n),
+      _ => Self::String(id.to_string()),
+    }
+  }
+
+  fn into_string(self) -> String {
+    match self {
+      Self::Number(n) => n.to_string(),
+      Self::String(s) => s,
+    }
+  }
+}
+
+fn to_runtime(runtime: Vec<String>) -> RuntimeSpec {
+  runtime.iter().map(|r| Ustr::from(r)).collect()
+}
+
+fn from_runtime(runtime: &RuntimeSpec) -> Vec<String> {
+  let mut runtime = runtime.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+  runtime.sort_unstable();
+  runtime
+}
+
+impl CompilationRecords {
+  pub fn to_json(&self) -> Result<String> {
+    let ids = self.ids.as_ref();
+    let file = RecordsFile {
+      hash: self.hash.as_ref().map(|hash| hash.encoded().to_string()),
+      runtimes: from_runtime(&self.runtimes),
+      runtime_modules: self
+        .runtime_modules
+        .iter()
+        .map(|(identifier, hash)| (identifier.to_string(), hash.encoded().to_string()))
+        .collect(),
+      chunk_modules: self
+        .chunks
+        .iter()
+        .map(|(chunk_id, (runtime, modules))| {
+          let mut modules = modules.iter().collect::<Vec<_>>();
+          modules.sort_unstable();
+          (
+            chunk_id.to_string(),
+            ChunkModulesRecord {
+              runtime: from_runtime(runtime),
+              modules: modules
+                .into_iter()
+                .map(|id| RecordId::new(id.as_str()))
+                .collect(),
+            },
+          )
+        })
+        .collect(),
+      module_hashes: self
+        .modules
+        .iter()
+        .map(|(module_id, hashes)| {
+          (
+            module_id.to_string(),
+            hashes
+              .iter()
+              .map(|(chunk_id, hash)| (chunk_id.to_string(), hash.encoded().to_string()))
+              .collect(),
+          )
+        })
+        .collect(),
+      modules: ModulesRecord {
+        by_identifier: ids
+          .map(|ids| {
+            ids
+              .modules
+              .iter()
+              .map(|(identifier, id)| (identifier.clone(), RecordId::new(id.as_str())))
+              .collect()
+          })
+          .unwrap_or_default(),
+      },
+      chunks: ChunksRecord {
+        by_name: ids
+          .map(|ids| {
+            ids
+              .chunks_by_name
+              .iter()
+              .map(|(name, id)| (name.clone(), RecordId::new(id.as_str())))
+              .collect()
+          })
+          .unwrap_or_default(),
+        by_source: ids
+          .map(|ids| {
+            ids
+              .chunks_by_source
+              .iter()
+              .map(|(source, id)| (source.clone(), RecordId::new(id.as_str())))
+              .collect()
+          })
+          .unwrap_or_default(),
+      },
+    };
+    serde_json::to_string_pretty(&file).map_err(|e| error!("Failed to serialize records: {e}"))
+  }
+
+  pub fn from_json(content: &[u8]) -> Result<Self> {
+    let file: RecordsFile =
+      serde_json::from_slice(content).map_err(|e| error!("Cannot parse records: {e}"))?;
+    Ok(Self {
+      runtimes: to_runtime(file.runtimes),
+      runtime_modules: file
+        .runtime_modules
+        .into_iter()
+        .map(|(identifier, hash)| (Identifier::from(identifier), hash.as_str().into()))
+        .collect(),
+      chunks: file
+        .chunk_modules
+        .into_iter()
+        .map(|(chunk_id, record)| {
+          (
+            ChunkId::from(chunk_id),
+            (
+              to_runtime(record.runtime),
+              record
+                .modules
+                .into_iter()
+                .map(|id| ModuleId::from(id.into_string()))
+                .collect(),
+            ),
+          )
+        })
+        .collect(),
+      modules: file
+        .module_hashes
+        .into_iter()
+        .map(|(module_id, hashes)| {
+          (
+            ModuleId::from(module_id),
+            hashes
+              .into_iter()
+              .map(|(chunk_id, hash)| (ChunkId::from(chunk_id), hash.as_str().into()))
+              .collect(),
+          )
+        })
+        .collect(),
+      hash: file.hash.map(|hash| RspackHashDigest::from(hash.as_str())),
+      ids: Some(IdsRecords {
+        modules: file
+          .modules
+          .by_identifier
+          .into_iter()
+          .map(|(identifier, id)| (identifier, ModuleId::from(id.into_string())))
+          .collect(),
+        chunks_by_name: file
+          .chunks
+          .by_name
+          .into_iter()
+          .map(|(name, id)| (name, ChunkId::from(id.into_string())))
+          .collect(),
+        chunks_by_source: file
+          .chunks
+          .by_source
+          .into_iter()
+          .map(|(source, id)| (source, ChunkId::from(id.into_string())))
+          .collect(),
+      }),
+    })
+  }
+}
+
+impl Compiler {
+  /// Reads the records from `recordsInputPath`, returns `None` if the file doesn't exist.
+  pub(super) async fn read_records(&self) -> Result<Option<CompilationRecords>> {
+    let Some(records_input_path) = &self.options.records_input_path else {
+      return Ok(None);
+    };
+    if self
+      .input_filesystem
+      .metadata(records_input_path)
+      .await
+      .is_err()
+    {
+      return Ok(None);
+    }
+    let content = self.input_filesystem.read(records_input_path).await?;
+    CompilationRecords::from_json(&content)
+      .map(Some)
+      .map_err(|e| e.wrap_err(format!("Failed to read records from {records_input_path}")))
+  }
+
+  /// Writes the records of the current compilation to `recordsOutputPath`.
+  pub(super) async fn emit_records(&self) -> Result<()> {
+    let Some(records_output_path) = &self.options.records_output_path else {
+      return Ok(());
+    };
+    let records = CompilationRecords::record(&self.compilation);
+    if let Some(dir) = records_output_path
+      .parent()
+      .filter(|dir| dir != &Utf8Path::new(""))
+    {
+      self.output_filesystem.create_dir_all(dir).await?;
+    }
+    self
+      .output_filesystem
+      .write(records_output_path, records.to_json()?.as_bytes())
+      .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,240 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  sync::Arc,
+};
+
+use rspack_collections::DatabaseItem;
+use rspack_error::Result;
+use rspack_fs::MemoryFileSystem;
+
+use super::rebuild::CompilationRecords;
+use crate::{
+  Chunk, ChunkHashesResult, Compilation, Compiler, ModuleIdentifier,
+  cache::DisableCache,
+  chunk_graph_chunk::ChunkId,
+  compilation::build_module_graph::ModuleExecutor,
+  incremental::{Incremental, IncrementalPasses},
+  logger::Logger,
+};
+
+/// The first difference found between the output of an incremental rebuild and a full rebuild
+#[derive(Debug)]
+struct IncrementalDivergence {
+  passes: IncrementalPasses,
+  detail: String,
+}
+
+impl From<IncrementalDivergence> for rspack_error::Error {
+  fn from(value: IncrementalDivergence) -> rspack_error::Error {
+    let mut error = rspack_error::Error::warning(format!(
+      "The output of incremental rebuild diverges from a full rebuild at {}, {}. This is likely a bug of the incremental passes.",
+      value.passes, value.detail
+    ));
+    error.code = Some("IncrementalDivergence".into());
+    error
+  }
+}
+
+impl Compiler {
+  /// Run a shadow full rebuild with the same inputs as the incremental rebuild
+  /// and report the first diverging pass as a diagnostic.
+  ///
+  /// The shadow compilation does not touch the cache and writes its output to a memory filesystem,
+  /// so neither the assets nor the files written by plugins are emitted. But all the
+  /// compilation hooks run again, including the ones tapped by JavaScript plugins. Plugins which
+  /// produce different results in each compilation (e.g. counters or timestamps) will be reported
+  /// as divergences.
+  pub(super) async fn verify_incremental_rebuild(
+    &mut self,
+    records: CompilationRecords,
+  ) -> Result<()> {
+    let logger = self.compilation.get_logger("rspack.incremental.verify");
+    let start = logger.time("shadow full rebuild");
+
+    let mut shadow_compilation = Compilation::new(
+      self.id,
+      self.options.clone(),
+      self.platform.clone(),
+      self.plugin_driver.clone(),
+      self.buildtime_plugin_driver.clone(),
+      self.resolver_factory.clone(),
+      self.loader_resolver_factory.clone(),
+      Some(records),
+      Incremental::new_cold(self.options.experiments.incremental),
+      Some(ModuleExecutor::default()),
+      Default::default(),
+      Default::default(),
+      self.input_filesystem.clone(),
+      self.intermediate_filesystem.clone(),
+      Arc::new(MemoryFileSystem::default()),
+      true,
+      self.compiler_context.clone(),
+    );
+    shadow_compilation.hot_index = self.compilation.hot_index;
+
+    let incremental_compilation = std::mem::replace(&mut self.compilation, shadow_compilation);
+    let cache = std::mem::replace(&mut self.cache, Box::new(DisableCache));
+    let result = self.compile().await;
+    self.cache = cache;
+    let shadow_compilation = std::mem::replace(&mut self.compilation, incremental_compilation);
+    self.plugin_driver.clear_cache(shadow_compilation.id());
+    result?;
+
+    logger.time_end(start);
+
+    if let Some(divergence) = find_divergence(&self.compilation, &shadow_compilation) {
+      self
+        .compilation
+        .push_diagnostic(rspack_error::Error::from(divergence).into());
+    }
+    Ok(())
+  }
+}
+
+type DiffFn = fn(&Compilation, &Compilation) -> Option<String>;
+
+/// Compare the artifacts in the order of the passes producing them, and return the first difference
+fn find_divergence(incremental: &Compilation, full: &Compilation) -> Option<IncrementalDivergence> {
+  let checks: [(IncrementalPasses, DiffFn); 5] = [
+    (IncrementalPasses::MAKE, diff_module_graph),
+    (IncrementalPasses::MODULE_IDS, diff_module_ids),
+    (IncrementalPasses::CHUNK_IDS, diff_chunk_ids),
+    (IncrementalPasses::CHUNKS_HASHES, diff_chunk_hashes),
+    (IncrementalPasses::EMIT_ASSETS, diff_assets),
+  ];
+  checks.into_iter().find_map(|(passes, check)| {
+    check(incremental, full).map(|detail| IncrementalDivergence { passes, detail })
+  })
+}
+
+fn diff_keys<K: Ord + std::fmt::Display, V>(
+  incremental: &BTreeMap<K, V>,
+  full: &BTreeMap<K, V>,
+  what: &str,
+) -> Option<String> {
+  if let Some(key) = incremental.keys().find(|key| !full.contains_key(key)) {
+    return Some(format!(
+      "{what} {key} only exists in the incremental rebuild"
+    ));
+  }
+  if let Some(key) = full.keys().find(|key| !incremental.contains_key(key)) {
+    return Some(format!("{what} {key} only exists in the full rebuild"));
+  }
+  None
+}
+
+fn diff_module_graph(incremental: &Compilation, full: &Compilation) -> Option<String> {
+  fn collect(compilation: &Compilation) -> BTreeMap<ModuleIdentifier, BTreeSet<ModuleIdentifier>> {
+    let mg = compilation.get_module_graph();
+    mg.modules()
+      .into_keys()
+      .map(|module| {
+        let targets = mg
+          .get_outgoing_connections(&module)
+          .map(|connection| *connection.module_identifier())
+          .collect();
+        (module, targets)
+      })
+      .collect()
+  }
+
+  let incremental = collect(incremental);
+  let full = collect(full);
+  if let Some(detail) = diff_keys(&incremental, &full, "module") {
+    return Some(detail);
+  }
+  incremental
+    .iter()
+    .find(|(module, targets)| full.get(*module) != Some(*targets))
+    .map(|(module, _)| format!("the dependencies of module {module} are different"))
+}
+
+fn diff_module_ids(incremental: &Compilation, full: &Compilation) -> Option<String> {
+  let incremental = incremental
+    .module_ids_artifact
+    .iter()
+    .collect::<BTreeMap<_, _>>();
+  let full = full.module_ids_artifact.iter().collect::<BTreeMap<_, _>>();
+  if let Some(detail) = diff_keys(&incremental, &full, "the id of module") {
+    return Some(detail);
+  }
+  incremental
+    .iter()
+    .find(|(module, id)| full.get(*module) != Some(*id))
+    .map(|(module, id)| {
+      format!(
+        "module {module} has id {id} in the incremental rebuild but {} in the full rebuild",
+        full[module]
+      )
+    })
+}
+
+fn collect_chunks(compilation: &Compilation) -> BTreeMap<ChunkId, &Chunk> {
+  compilation
+    .chunk_by_ukey
+    .values()
+    .filter_map(|chunk| Some((chunk.id()?.clone(), chunk)))
+    .collect()
+}
+
+fn diff_chunk_ids(incremental: &Compilation, full: &Compilation) -> Option<String> {
+  fn collect(compilation: &Compilation) -> BTreeMap<ChunkId, BTreeSet<ModuleIdentifier>> {
+    collect_chunks(compilation)
+      .into_iter()
+      .map(|(id, chunk)| {
+        let modules = compilation
+          .chunk_graph
+          .get_chunk_modules_identifier(&chunk.ukey())
+          .iter()
+          .copied()
+          .collect();
+        (id, modules)
+      })
+      .collect()
+  }
+
+  let incremental = collect(incremental);
+  let full = collect(full);
+  if let Some(detail) = diff_keys(&incremental, &full, "chunk") {
+    return Some(detail);
+  }
+  incremental
+    .iter()
+    .find(|(id, modules)| full.get(*id) != Some(*modules))
+    .map(|(id, _)| format!("the modules of chunk {id} are different"))
+}
+
+fn diff_chunk_hashes(incremental: &Compilation, full: &Compilation) -> Option<String> {
+  fn collect(compilation: &Compilation) -> BTreeMap<ChunkId, Option<&ChunkHashesResult>> {
+    collect_chunks(compilation)
+      .into_iter()
+      .map(|(id, chunk)| (id, compilation.chunk_hashes_artifact.get(&chunk.ukey())))
+      .collect()
+  }
+
+  let incremental_hashes = collect(incremental);
+  let full_hashes = collect(full);
+  if let Some((id, _)) = incremental_hashes
+    .iter()
+    .find(|(id, hashes)| full_hashes.get(*id) != Some(*hashes))
+  {
+    return Some(format!("the hashes of chunk {id} are different"));
+  }
+  (incremental.hash != full.hash).then(|| "the hash of the compilation is different".to_string())
+}
+
+fn diff_assets(incremental: &Compilation, full: &Compilation) -> Option<String> {
+  let incremental = incremental.assets().iter().collect::<BTreeMap<_, _>>();
+  let full = full.assets().iter().collect::<BTreeMap<_, _>>();
+  if let Some(detail) = diff_keys(&incremental, &full, "asset") {
+    return Some(detail);
+  }
+  incremental
+    .iter()
+    .find(|(filename, asset)| {
+      let source = asset.get_source().map(|source| source.buffer());
+      let full_source = full[*filename].get_source().map(|source| source.buffer());
+      source != full_source
+    })
+    .map(|(filename, _)| format!("the content of asset {filename} is different"))
+}
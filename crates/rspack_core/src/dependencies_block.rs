@@ -41,7 +41,7 @@ pub fn dependencies_block_update_hash(
   }
 }
 
-#[cacheable]
+#[cacheable(hashable)]
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct AsyncDependenciesBlockIdentifier(Identifier);
 
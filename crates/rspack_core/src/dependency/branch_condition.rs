@@ -0,0 +1,218 @@
+use rspack_cacheable::{
+  cacheable,
+  with::{AsPreset, AsVec},
+};
+use swc_core::ecma::atoms::Atom;
+
+use super::{
+  DependencyCondition, DependencyConditionFn, DependencyId, DependencyType, ExportNameOrSpec,
+  ExportsOfExportsSpec,
+};
+use crate::{
+  ConnectionState, EvaluatedInlinableValue, ModuleGraph, ModuleGraphCacheArtifact,
+  ModuleGraphConnection, ModuleIdentifier, PrefetchExportsInfoMode, RuntimeSpec, TerminalBinding,
+  UsageState, get_terminal_binding,
+};
+
+/// A value in the test of a branch that is unknown when parsing the module, but can be
+/// evaluated with the module graph
+#[cacheable]
+#[derive(Debug, Clone)]
+pub enum BranchTestValue {
+  /// An imported constant, e.g. `DEBUG` of `import { DEBUG } from "./flags"`, the `dependency`
+  /// connects to the module that exports it
+  Import {
+    dependency: DependencyId,
+    #[cacheable(with=AsVec<AsPreset>)]
+    ids: Vec<Atom>,
+  },
+  /// `__webpack_exports_info__.<ids>.used` of the module itself
+  ExportUsed(#[cacheable(with=AsVec<AsPreset>)] Vec<Atom>),
+}
+
+/// A condition that must hold for a branch to be executed, e.g. the consequent of
+/// `if (LEVEL === "verbose")` requires the imported `LEVEL` to be `"verbose"`, the dependencies
+/// in the branch are inactive if any of its conditions is known to be false
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct BranchCondition {
+  pub value: BranchTestValue,
+  /// The literal that the value is strictly compared with, the truthiness of the value is
+  /// checked if it is `None`
+  pub compared_with: Option<EvaluatedInlinableValue>,
+  /// Whether the comparison or the truthiness is expected to be true
+  pub expected: bool,
+}
+
+impl BranchCondition {
+  pub fn negate(&self) -> Self {
+    Self {
+      value: self.value.clone(),
+      compared_with: self.compared_with.clone(),
+      expected: !self.expected,
+    }
+  }
+
+  /// Returns `None` if the condition can't be determined yet
+  pub fn evaluate(
+    &self,
+    module: &ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+    mg: &ModuleGraph,
+  ) -> Option<bool> {
+    let value = match &self.value {
+      BranchTestValue::Import { dependency, ids } => {
+        let module = mg.module_identifier_by_dependency_id(dependency)?;
+        // the ids are updated if the connection is redirected by the side effects optimization
+        let ids = mg
+          .get_dep_meta_if_existing(dependency)
+          .map_or(ids.as_slice(), |meta| meta.ids.as_slice());
+        get_inlinable_value(mg, module, ids)?
+      }
+      BranchTestValue::ExportUsed(ids) => {
+        let exports_info =
+          mg.get_prefetched_exports_info(module, PrefetchExportsInfoMode::Nested(ids));
+        match exports_info.get_used(ids, runtime) {
+          UsageState::Unused => EvaluatedInlinableValue::Boolean(false),
+          UsageState::NoInfo => return None,
+          _ => EvaluatedInlinableValue::Boolean(true),
+        }
+      }
+    };
+    Some(self.test(&value))
+  }
+
+  /// Evaluates the condition with the constant that the imported module declares itself, which
+  /// is known as soon as the module is built. Returns `None` if the value is not such a constant,
+  /// e.g. it is re-exported from another module
+  pub fn evaluate_declared(
+    &self,
+    mg: &ModuleGraph,
+    mg_cache: &ModuleGraphCacheArtifact,
+  ) -> Option<bool> {
+    let BranchTestValue::Import { dependency, ids } = &self.value else {
+      return None;
+    };
+    let module = mg.get_module_by_dependency_id(dependency)?;
+    let value = module.get_dependencies().iter().find_map(|dependency_id| {
+      let dependency = mg.dependency_by_id(dependency_id);
+      if dependency.dependency_type() != &DependencyType::EsmExportSpecifier {
+        return None;
+      }
+      let ExportsOfExportsSpec::Names(names) = dependency.get_exports(mg, mg_cache)?.exports else {
+        return None;
+      };
+      get_declared_value(&names, ids)
+    })?;
+    Some(self.test(&value))
+  }
+
+  fn test(&self, value: &EvaluatedInlinableValue) -> bool {
+    let result = match &self.compared_with {
+      Some(compared_with) => strict_equals(value, compared_with),
+      None => is_truthy(value),
+    };
+    result == self.expected
+  }
+}
+
+fn get_declared_value(names: &[ExportNameOrSpec], ids: &[Atom]) -> Option<EvaluatedInlinableValue> {
+  let (name, rest) = ids.split_first()?;
+  names.iter().find_map(|spec| match spec {
+    ExportNameOrSpec::ExportSpec(spec) if &spec.name == name => {
+      if rest.is_empty() {
+        spec.inlinable.clone()
+      } else {
+        get_declared_value(&spec.exports.as_ref()?.exports, rest)
+      }
+    }
+    _ => None,
+  })
+}
+
+fn get_inlinable_value(
+  mg: &ModuleGraph,
+  module: &ModuleIdentifier,
+  ids: &[Atom],
+) -> Option<EvaluatedInlinableValue> {
+  let exports_info = mg.get_prefetched_exports_info(module, PrefetchExportsInfoMode::Nested(ids));
+  let export_info = exports_info.get_read_only_export_info_recursive(ids)?;
+  if let Some(value) = export_info.can_inline_provide() {
+    return Some(value.clone());
+  }
+  let Some(TerminalBinding::ExportInfo(export_info)) = get_terminal_binding(export_info, mg) else {
+    return None;
+  };
+  export_info.as_data(mg).can_inline_provide().cloned()
+}
+
+fn is_truthy(value: &EvaluatedInlinableValue) -> bool {
+  match value {
+    EvaluatedInlinableValue::Null | EvaluatedInlinableValue::Undefined => false,
+    EvaluatedInlinableValue::Boolean(value) => *value,
+    EvaluatedInlinableValue::Number(value) => *value != 0.0 && !value.is_nan(),
+    EvaluatedInlinableValue::String(value) => !value.is_empty(),
+  }
+}
+
+fn strict_equals(left: &EvaluatedInlinableValue, right: &EvaluatedInlinableValue) -> bool {
+  match (left, right) {
+    (EvaluatedInlinableValue::Null, EvaluatedInlinableValue::Null)
+    | (EvaluatedInlinableValue::Undefined, EvaluatedInlinableValue::Undefined) => true,
+    (EvaluatedInlinableValue::Boolean(left), EvaluatedInlinableValue::Boolean(right)) => {
+      left == right
+    }
+    (EvaluatedInlinableValue::Number(left), EvaluatedInlinableValue::Number(right)) => {
+      left == right
+    }
+    (EvaluatedInlinableValue::String(left), EvaluatedInlinableValue::String(right)) => {
+      left == right
+    }
+    _ => false,
+  }
+}
+
+/// Makes the connection inactive if the dependency is in a branch that is never executed,
+/// otherwise the state is determined by the condition of the dependency itself
+struct BranchDependencyCondition {
+  conditions: Vec<BranchCondition>,
+  condition: Option<DependencyCondition>,
+}
+
+impl DependencyConditionFn for BranchDependencyCondition {
+  fn get_connection_state(
+    &self,
+    connection: &ModuleGraphConnection,
+    runtime: Option<&RuntimeSpec>,
+    module_graph: &ModuleGraph,
+    module_graph_cache: &ModuleGraphCacheArtifact,
+  ) -> ConnectionState {
+    if let Some(module) = &connection.original_module_identifier
+      && self
+        .conditions
+        .iter()
+        .any(|condition| condition.evaluate(module, runtime, module_graph) == Some(false))
+    {
+      return ConnectionState::Active(false);
+    }
+    match &self.condition {
+      Some(condition) => {
+        condition.get_connection_state(connection, runtime, module_graph, module_graph_cache)
+      }
+      None => ConnectionState::Active(true),
+    }
+  }
+}
+
+impl DependencyCondition {
+  /// Wraps the condition of a dependency with the conditions of the branches it is in
+  pub fn with_branch_conditions(
+    condition: Option<DependencyCondition>,
+    conditions: Vec<BranchCondition>,
+  ) -> Self {
+    Self::new(BranchDependencyCondition {
+      conditions,
+      condition,
+    })
+  }
+}
@@ -1,3 +1,4 @@
+mod branch_condition;
 mod cached_const_dependency;
 mod const_dependency;
 mod context_dependency;
@@ -17,6 +18,7 @@ mod static_exports_dependency;
 
 use std::sync::Arc;
 
+pub use branch_condition::{BranchCondition, BranchTestValue};
 pub use cached_const_dependency::{CachedConstDependency, CachedConstDependencyTemplate};
 pub use const_dependency::{ConstDependency, ConstDependencyTemplate};
 pub use context_dependency::{AsContextDependency, ContextDependency};
@@ -56,6 +58,8 @@ pub struct ExportSpec {
   pub from: Option<ModuleGraphConnection>,
   pub from_export: Option<ModuleGraphConnection>,
   pub inlinable: Option<EvaluatedInlinableValue>,
+  /// The export is a function annotated with `#__NO_SIDE_EFFECTS__`
+  pub no_side_effects: bool,
 }
 
 #[derive(Debug, Default)]
@@ -64,6 +64,9 @@ pub struct ExportInfoData {
   can_mangle_use: Option<bool>,
   can_inline_provide: Option<EvaluatedInlinableValue>,
   can_inline_use: Option<CanInlineUse>,
+  /// The export is a function annotated with `#__NO_SIDE_EFFECTS__`, calls to it
+  /// are side effects free as long as the arguments are side effects free
+  no_side_effects: bool,
   terminal_binding: bool,
   exports_info: Option<ExportsInfo>,
   exports_info_owned: bool,
@@ -140,6 +143,8 @@ impl ExportInfoData {
       can_inline_provide: None,
       // only specific export info can be inlined, so other_export_info.can_inline_use is always None
       can_inline_use: None,
+      // only specific export info can be annotated, so other_export_info.no_side_effects is always false
+      no_side_effects: false,
     }
   }
 
@@ -187,6 +192,10 @@ impl ExportInfoData {
     self.can_inline_use
   }
 
+  pub fn no_side_effects(&self) -> bool {
+    self.no_side_effects
+  }
+
   pub fn terminal_binding(&self) -> bool {
     self.terminal_binding
   }
@@ -250,6 +259,10 @@ impl ExportInfoData {
     self.can_inline_use = value;
   }
 
+  pub fn set_no_side_effects(&mut self, value: bool) {
+    self.no_side_effects = value;
+  }
+
   pub fn set_terminal_binding(&mut self, value: bool) {
     self.terminal_binding = value;
   }
@@ -10,6 +10,7 @@ impl ExportInfoData {
     self.set_provided(None);
     self.set_can_mangle_provide(None);
     self.set_can_inline_provide(None);
+    self.set_no_side_effects(false);
     self.set_exports_info(None);
     self.set_exports_info_owned(false);
     self.set_target_is_set(false);
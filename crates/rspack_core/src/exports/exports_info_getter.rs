@@ -359,6 +359,15 @@ impl<'a> PrefetchedExportsInfoWrapper<'a> {
     false
   }
 
+  /// Whether the module is included only for its side effects, i.e. none of its exports is used
+  pub fn is_used_only_for_side_effects(&self, runtime: Option<&RuntimeSpec>) -> bool {
+    !self.is_used(runtime)
+      && !matches!(
+        self.side_effects_only_info().get_used(runtime),
+        UsageState::Unused
+      )
+  }
+
   pub fn is_used(&self, runtime: Option<&RuntimeSpec>) -> bool {
     if self.other_exports_info().is_used(runtime) {
       return true;
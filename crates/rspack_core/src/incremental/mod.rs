@@ -4,7 +4,7 @@ use std::{
   fmt,
   sync::{
     Mutex, MutexGuard,
-    atomic::{AtomicU16, Ordering},
+    atomic::{AtomicU32, Ordering},
   },
 };
 
@@ -16,7 +16,7 @@ pub const TRACING_TARGET: &str = "rspack_incremental";
 
 bitflags! {
   #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-  pub struct IncrementalPasses: u16 {
+  pub struct IncrementalPasses: u32 {
     const MAKE = 1 << 0;
     const INFER_ASYNC_MODULES = 1 << 1;
     const PROVIDED_EXPORTS = 1 << 2;
@@ -32,6 +32,10 @@ bitflags! {
     const CHUNKS_HASHES = 1 << 12;
     const CHUNKS_RENDER = 1 << 13;
     const EMIT_ASSETS = 1 << 14;
+    const MODULE_CONCATENATION = 1 << 15;
+    const SPLIT_CHUNKS = 1 << 16;
+    const MANGLE_EXPORTS = 1 << 17;
+    const INLINE_EXPORTS = 1 << 18;
   }
 }
 
@@ -53,6 +57,10 @@ impl IncrementalPasses {
       Self::CHUNKS_HASHES => "chunksHashes",
       Self::CHUNKS_RENDER => "chunksRender",
       Self::EMIT_ASSETS => "emitAssets",
+      Self::MODULE_CONCATENATION => "moduleConcatenation",
+      Self::SPLIT_CHUNKS => "splitChunks",
+      Self::MANGLE_EXPORTS => "mangleExports",
+      Self::INLINE_EXPORTS => "inlineExports",
       _ => unreachable!(),
     }
   }
@@ -87,6 +95,8 @@ impl IncrementalPasses {
 #[derive(Debug, Clone, Copy)]
 pub struct IncrementalOptions {
   pub silent: bool,
+  /// Run a shadow full rebuild after each incremental rebuild and report the differences
+  pub verify: bool,
   pub passes: IncrementalPasses,
 }
 
@@ -94,6 +104,7 @@ impl IncrementalOptions {
   pub fn empty_passes() -> Self {
     Self {
       silent: true,
+      verify: false,
       passes: IncrementalPasses::empty(),
     }
   }
@@ -124,7 +135,7 @@ impl fmt::Debug for IncrementalState {
 
 pub struct Incremental {
   silent: bool,
-  passes: AtomicU16,
+  passes: AtomicU32,
   state: IncrementalState,
 }
 
@@ -142,7 +153,7 @@ impl Incremental {
   pub fn new_cold(options: IncrementalOptions) -> Self {
     Self {
       silent: options.silent,
-      passes: AtomicU16::new(options.passes.bits()),
+      passes: AtomicU32::new(options.passes.bits()),
       state: IncrementalState::Cold,
     }
   }
@@ -150,7 +161,7 @@ impl Incremental {
   pub fn new_hot(options: IncrementalOptions) -> Self {
     Self {
       silent: options.silent,
-      passes: AtomicU16::new(options.passes.bits()),
+      passes: AtomicU32::new(options.passes.bits()),
       state: IncrementalState::Hot {
         mutations: Mutex::new(Mutations::default()),
       },
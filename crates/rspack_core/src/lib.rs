@@ -110,7 +110,7 @@ pub use rspack_sources;
 #[cfg(debug_assertions)]
 pub mod debug_info;
 
-#[cacheable]
+#[cacheable(hashable)]
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SourceType {
   JavaScript,
@@ -29,14 +29,15 @@ use serde::Serialize;
 
 use crate::{
   AsyncDependenciesBlock, BindingCell, BoxDependency, BoxDependencyTemplate, BoxModuleDependency,
-  ChunkGraph, ChunkUkey, CodeGenerationResult, CollectedTypeScriptInfo, Compilation,
-  CompilationAsset, CompilationId, CompilerId, CompilerOptions, ConcatenationScope,
-  ConnectionState, Context, ContextModule, DependenciesBlock, DependencyId, ExportProvided,
-  ExternalModule, GetTargetResult, ModuleGraph, ModuleGraphCacheArtifact, ModuleLayer, ModuleType,
-  NormalModule, PrefetchExportsInfoMode, RawModule, Resolve, ResolverFactory, RuntimeSpec,
-  RuntimeTemplate, SelfModule, SharedPluginDriver, SourceType,
-  concatenated_module::ConcatenatedModule, dependencies_block::dependencies_block_update_hash,
-  get_target, value_cache_versions::ValueCacheVersions,
+  BranchCondition, ChunkGraph, ChunkUkey, CodeGenerationResult, CollectedClassMembersInfo,
+  CollectedTypeScriptInfo, Compilation, CompilationAsset, CompilationId, CompilerId,
+  CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule, DependenciesBlock,
+  DependencyId, ExportProvided, ExternalModule, GetTargetResult, ModuleGraph,
+  ModuleGraphCacheArtifact, ModuleLayer, ModuleType, NormalModule, PrefetchExportsInfoMode,
+  RawModule, Resolve, ResolverFactory, RuntimeSpec, RuntimeTemplate, SelfModule,
+  SharedPluginDriver, SideEffectsBailoutItem, SourceType, concatenated_module::ConcatenatedModule,
+  dependencies_block::dependencies_block_update_hash, get_target,
+  value_cache_versions::ValueCacheVersions,
 };
 
 pub struct BuildContext {
@@ -76,6 +77,11 @@ pub struct BuildInfo {
   pub module: bool,
   pub inline_exports: bool,
   pub collected_typescript_info: Option<CollectedTypeScriptInfo>,
+  pub collected_class_members_info: Option<CollectedClassMembersInfo>,
+  /// The conditions of the branches that the dependencies are in, see [BranchCondition]
+  pub branch_conditions: HashMap<DependencyId, Vec<BranchCondition>>,
+  /// The statement that makes the module have side effects, see `stats.sideEffectsBailout`
+  pub side_effects_bailout: Option<SideEffectsBailoutItem>,
   /// Stores external fields from the JS side (Record<string, any>),
   /// while other properties are stored in KnownBuildInfo.
   #[cacheable(with=AsPreset)]
@@ -105,6 +111,9 @@ impl Default for BuildInfo {
       module: false,
       inline_exports: false,
       collected_typescript_info: None,
+      collected_class_members_info: None,
+      branch_conditions: HashMap::default(),
+      side_effects_bailout: None,
       extras: Default::default(),
     }
   }
@@ -12,7 +12,7 @@ use swc_core::ecma::atoms::Atom;
 use crate::{
   AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, AsyncModulesArtifact, Compilation,
   DependenciesBlock, Dependency, ExportInfo, ExportName, ImportedByDeferModulesArtifact,
-  ModuleGraphCacheArtifact, RuntimeSpec, UsedNameItem,
+  ModuleGraphCacheArtifact, PrefetchExportsInfoMode, RuntimeSpec, UsedNameItem,
 };
 mod module;
 pub use module::*;
@@ -662,12 +662,11 @@ impl ModuleGraph {
     let dependency = self.dependency_by_id(&dependency_id);
     let is_module_dependency =
       dependency.as_module_dependency().is_some() || dependency.as_context_dependency().is_some();
-    let condition = dependency
-      .as_module_dependency()
-      .and_then(|dep| dep.get_condition());
     if !is_module_dependency {
       return Ok(());
     }
+    let condition =
+      self.get_dependency_condition(original_module_identifier.as_ref(), &dependency_id);
 
     let conditional = condition.is_some();
     let new_connection = ModuleGraphConnection::new(
@@ -871,6 +870,26 @@ impl ModuleGraph {
       .flatten()
   }
 
+  /// Whether the module is imported by other modules only for its side effects, the entry
+  /// modules are not included
+  pub fn is_used_only_for_side_effects(
+    &self,
+    module_id: &ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+  ) -> bool {
+    let mut imported = false;
+    for connection in self.get_incoming_connections(module_id) {
+      if connection.original_module_identifier.is_none() {
+        return false;
+      }
+      imported = true;
+    }
+    imported
+      && self
+        .get_prefetched_exports_info(module_id, PrefetchExportsInfoMode::Default)
+        .is_used_only_for_side_effects(runtime)
+  }
+
   pub fn get_module_hash(&self, module_id: &ModuleIdentifier) -> Option<&RspackHashDigest> {
     self
       .module_by_identifier(module_id)
@@ -922,6 +941,29 @@ impl ModuleGraph {
     &mgm.optimization_bailout
   }
 
+  /// The condition of the dependency, combined with the conditions of the branches that the
+  /// dependency is in
+  fn get_dependency_condition(
+    &self,
+    original_module_identifier: Option<&ModuleIdentifier>,
+    dependency_id: &DependencyId,
+  ) -> Option<DependencyCondition> {
+    let condition = self
+      .dependency_by_id(dependency_id)
+      .as_module_dependency()
+      .and_then(|dep| dep.get_condition());
+    let branch_conditions = original_module_identifier
+      .and_then(|module| self.module_by_identifier(module))
+      .and_then(|module| module.build_info().branch_conditions.get(dependency_id));
+    match branch_conditions {
+      Some(branch_conditions) => Some(DependencyCondition::with_branch_conditions(
+        condition,
+        branch_conditions.clone(),
+      )),
+      None => condition,
+    }
+  }
+
   pub fn get_condition_state(
     &self,
     connection: &ModuleGraphConnection,
@@ -938,10 +980,10 @@ impl ModuleGraph {
 
   // todo remove it after module_graph_partial remove all of dependency_id_to_*
   pub fn cache_recovery_connection(&mut self, connection: ModuleGraphConnection) {
-    let condition = self
-      .dependency_by_id(&connection.dependency_id)
-      .as_module_dependency()
-      .and_then(|dep| dep.get_condition());
+    let condition = self.get_dependency_condition(
+      connection.original_module_identifier.as_ref(),
+      &connection.dependency_id,
+    );
 
     // recovery condition
     if let Some(condition) = condition {
@@ -630,11 +630,11 @@ impl Module for NormalModule {
     if !diagnostics.is_empty() {
       self.add_diagnostics(diagnostics);
     }
-    let optimization_bailouts = if let Some(side_effects_bailout) = side_effects_bailout {
+    let optimization_bailouts = if let Some(side_effects_bailout) = &side_effects_bailout {
       let short_id = self.readable_identifier(&build_context.compiler_options.context);
       vec![format!(
         "{} with side_effects in source code at {short_id}:{}",
-        side_effects_bailout.ty, side_effects_bailout.msg
+        side_effects_bailout.ty, side_effects_bailout.loc
       )]
     } else {
       vec![]
@@ -642,6 +642,7 @@ impl Module for NormalModule {
     // Only side effects used in code_generate can stay here
     // Other side effects should be set outside use_cache
     let inner = self.inner_mut();
+    inner.build_info.side_effects_bailout = side_effects_bailout;
     inner.source = Some(source);
     inner.code_generation_dependencies = Some(code_generation_dependencies);
     inner.presentational_dependencies = Some(presentational_dependencies);
@@ -1,3 +1,5 @@
+use rspack_paths::Utf8PathBuf;
+
 use crate::{
   CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
   Resolve, StatsOptions,
@@ -20,6 +22,8 @@ pub struct CompilerOptions {
   pub profile: bool,
   pub amd: Option<String>,
   pub bail: bool,
+  pub records_input_path: Option<Utf8PathBuf>,
+  pub records_output_path: Option<Utf8PathBuf>,
   pub __references: References,
 }
 
@@ -1,5 +1,7 @@
 use crate::cache::persistent::PersistentCacheOptions;
 
+// the options are only created once for each compiler
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum CacheOptions {
   Disabled,
@@ -123,8 +123,10 @@ pub struct Optimization {
   pub used_exports: UsedExportsOption,
   pub inner_graph: bool,
   pub inline_exports: bool,
+  pub remove_unused_class_members: bool,
   pub mangle_exports: MangleExportsOption,
   pub concatenate_modules: bool,
+  pub concatenate_commonjs_modules: bool,
   pub avoid_entry_iife: bool,
   pub real_content_hash: bool,
 }
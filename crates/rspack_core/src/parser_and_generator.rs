@@ -3,7 +3,7 @@ use std::{any::Any, borrow::Cow, ops::Deref};
 use derive_more::with_trait::Debug;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsMap, AsPreset, AsVec},
+  with::{AsMap, AsOption, AsPreset, AsVec},
 };
 use rspack_error::{Result, TWithDiagnosticArray};
 use rspack_hash::RspackHashDigest;
@@ -17,8 +17,8 @@ use crate::{
   AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, BoxLoader, BoxModuleDependency,
   BuildInfo, BuildMeta, ChunkGraph, CodeGenerationData, Compilation, CompilerOptions,
   ConcatenationScope, Context, EvaluatedInlinableValue, FactoryMeta, Module, ModuleGraph,
-  ModuleIdentifier, ModuleLayer, ModuleType, NormalModule, ParserOptions, RuntimeGlobals,
-  RuntimeSpec, RuntimeTemplate, SourceType,
+  ModuleIdentifier, ModuleLayer, ModuleType, NormalModule, ParserOptions, RealDependencyLocation,
+  RuntimeGlobals, RuntimeSpec, RuntimeTemplate, SourceType,
 };
 
 #[derive(Debug)]
@@ -75,16 +75,56 @@ impl Deref for TSEnumValue {
   }
 }
 
-#[derive(Debug)]
+/// The class members collected for `optimization.removeUnusedClassMembers`
+#[cacheable]
+#[derive(Debug, Default, Clone)]
+pub struct CollectedClassMembersInfo {
+  /// Property names that may be accessed by the module, including the names of the
+  /// non-computed member accesses, the keys of the destructuring patterns and all the
+  /// string literals
+  #[cacheable(with=AsVec<AsPreset>)]
+  pub accessed_properties: FxHashSet<Atom>,
+  /// Top-level classes of the module that are not exposed dynamically in the module
+  pub classes: Vec<CollectedClass>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CollectedClass {
+  /// The local name of the class, `*default*` for the anonymous default exported class
+  #[cacheable(with=AsPreset)]
+  pub name: Atom,
+  /// The export names of the class
+  #[cacheable(with=AsVec<AsPreset>)]
+  pub exports: Vec<Atom>,
+  /// The local name of the super class, the super class must be a top-level class
+  /// of the same module
+  #[cacheable(with=AsOption<AsPreset>)]
+  pub super_class: Option<Atom>,
+}
+
+/// The first top-level statement that makes the module have side effects
+#[cacheable]
+#[derive(Debug, Clone)]
 pub struct SideEffectsBailoutItem {
-  pub msg: String,
+  pub loc: RealDependencyLocation,
   /// The type of AstNode
   pub ty: String,
+  /// The first line of the statement, truncated if it's too long
+  pub statement: String,
 }
 
 impl SideEffectsBailoutItem {
-  pub fn new(msg: String, ty: String) -> Self {
-    Self { msg, ty }
+  const MAX_STATEMENT_LENGTH: usize = 120;
+
+  pub fn new(loc: RealDependencyLocation, ty: String, statement: &str) -> Self {
+    let first_line = statement.lines().next().unwrap_or_default();
+    let statement = match first_line.char_indices().nth(Self::MAX_STATEMENT_LENGTH) {
+      Some((end, _)) => format!("{}...", &first_line[..end]),
+      None if first_line.len() < statement.len() => format!("{first_line}..."),
+      None => first_line.to_string(),
+    };
+    Self { loc, ty, statement }
   }
 }
 
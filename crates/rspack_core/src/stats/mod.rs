@@ -17,6 +17,7 @@ use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
   Compilation, LogType, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
   PrefetchExportsInfoMode, ProvidedExports, RuntimeSpec, SourceType, UsedExports,
+  cache::persistent::CacheInvalidationReport,
   compilation::build_module_graph::ExecutedRuntimeModule,
 };
 
@@ -728,6 +729,10 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  pub fn get_cache_invalidation_report(&self) -> Option<&CacheInvalidationReport> {
+    self.compilation.cache_invalidation_report.as_ref()
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
@@ -816,6 +821,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      side_effects_bailout: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
@@ -1080,6 +1086,14 @@ impl Stats<'_> {
       stats.optimization_bailout = Some(&mgm.optimization_bailout);
     }
 
+    if options.side_effects_bailout
+      && !executed
+      && let Some(side_effects_bailout) = &module.build_info().side_effects_bailout
+      && module_graph.is_used_only_for_side_effects(&identifier, None)
+    {
+      stats.side_effects_bailout = Some(side_effects_bailout);
+    }
+
     // 'depth' is used for sorting in the JavaScript side, so it should always be computed.
     stats.depth = module_graph.get_depth(&identifier);
 
@@ -1154,6 +1168,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      side_effects_bailout: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
@@ -1270,6 +1285,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      side_effects_bailout: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
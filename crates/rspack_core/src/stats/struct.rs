@@ -5,7 +5,10 @@ use rspack_sources::BoxSource;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
 
-use crate::{ChunkGroupOrderKey, ModuleId, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
+use crate::{
+  ChunkGroupOrderKey, ModuleId, ModuleIdentifier, ModuleType, RuntimeSpec, SideEffectsBailoutItem,
+  SourceType,
+};
 
 pub enum EntrypointsStatsOption {
   Bool(bool),
@@ -14,6 +17,7 @@ pub enum EntrypointsStatsOption {
 
 pub struct ExtendedStatsOptions {
   pub assets: bool,
+  pub cache_invalidation: bool,
   pub cached_modules: bool,
   pub chunks: bool,
   pub chunk_group_auxiliary: bool,
@@ -32,6 +36,7 @@ pub struct ExtendedStatsOptions {
   pub optimization_bailout: bool,
   pub provided_exports: bool,
   pub reasons: bool,
+  pub side_effects_bailout: bool,
   pub source: bool,
   pub used_exports: bool,
   pub warnings: bool,
@@ -47,6 +52,7 @@ impl Default for ExtendedStatsOptions {
       assets: true,
       hash: true,
 
+      cache_invalidation: false,
       cached_modules: false,
       chunk_group_auxiliary: false,
       chunk_group_children: false,
@@ -61,6 +67,7 @@ impl Default for ExtendedStatsOptions {
       optimization_bailout: false,
       provided_exports: false,
       reasons: false,
+      side_effects_bailout: false,
       source: false,
       used_exports: false,
     }
@@ -175,6 +182,7 @@ pub struct StatsModule<'a> {
   pub provided_exports: Option<Vec<Atom>>,
   pub used_exports: Option<StatsUsedExports>,
   pub optimization_bailout: Option<&'a [String]>,
+  pub side_effects_bailout: Option<&'a SideEffectsBailoutItem>,
   pub depth: Option<usize>,
   pub pre_order_index: Option<u32>,
   pub post_order_index: Option<u32>,
@@ -8,7 +8,7 @@ use crate::{Chunk, ChunkGroup};
 
 static NEXT_CHUNK_UKEY: AtomicU32 = AtomicU32::new(0);
 
-#[rspack_cacheable::cacheable]
+#[rspack_cacheable::cacheable(hashable)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ChunkUkey(Ukey, std::marker::PhantomData<Chunk>);
 
@@ -33,6 +33,11 @@ impl ChunkUkey {
   pub fn as_u32(&self) -> u32 {
     self.0.as_u32()
   }
+
+  /// Make sure the ukeys created later are not conflicted with the restored ukey
+  pub(crate) fn mark_restored(&self) {
+    NEXT_CHUNK_UKEY.fetch_max(self.as_u32() + 1, std::sync::atomic::Ordering::Relaxed);
+  }
 }
 
 impl From<u32> for ChunkUkey {
@@ -43,6 +48,7 @@ impl From<u32> for ChunkUkey {
 
 static NEXT_CHUNK_GROUP_UKEY: AtomicU32 = AtomicU32::new(0);
 
+#[rspack_cacheable::cacheable(hashable)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(allocative, derive(allocative::Allocative))]
 pub struct ChunkGroupUkey(Ukey, std::marker::PhantomData<ChunkGroup>);
@@ -68,6 +74,11 @@ impl ChunkGroupUkey {
   pub fn as_u32(&self) -> u32 {
     self.0.as_u32()
   }
+
+  /// Make sure the ukeys created later are not conflicted with the restored ukey
+  pub(crate) fn mark_restored(&self) {
+    NEXT_CHUNK_GROUP_UKEY.fetch_max(self.as_u32() + 1, std::sync::atomic::Ordering::Relaxed);
+  }
 }
 
 impl From<u32> for ChunkGroupUkey {
@@ -12,11 +12,13 @@ use swc_core::atoms::Atom;
 use crate::{
   ExportMode, ModuleIdentifier,
   concatenated_module::{ConcatenatedModuleInfo, ModuleInfo},
+  to_identifier,
 };
 
 pub static DEFAULT_EXPORT_ATOM: LazyLock<Atom> = LazyLock::new(|| "__rspack_default_export".into());
 pub const NAMESPACE_OBJECT_EXPORT: &str = "__rspack_ns_object";
 pub const DEFAULT_EXPORT: &str = "__rspack_default_export";
+pub const COMMONJS_EXPORT_PREFIX: &str = "__rspack_cjs_export_";
 
 static MODULE_REFERENCE_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
   Regex::new(
@@ -76,6 +78,25 @@ impl ConcatenationScope {
     export_map.insert(export_name, symbol);
   }
 
+  /// Registers an export of a concatenated CommonJS module, returns the top-level symbol that
+  /// replaces `exports.<export_name>`
+  pub fn register_commonjs_export(&mut self, export_name: &Atom) -> String {
+    let export_map = self.current_module.export_map.get_or_insert_default();
+    if let Some(symbol) = export_map.get(export_name) {
+      return symbol.clone();
+    }
+    let base = format!("{COMMONJS_EXPORT_PREFIX}{}", to_identifier(export_name));
+    let mut symbol = base.clone();
+    let mut index = 0;
+    // different export names may be escaped to the same identifier
+    while export_map.values().any(|existing| existing == &symbol) {
+      index += 1;
+      symbol = format!("{base}_{index}");
+    }
+    export_map.insert(export_name.clone(), symbol.clone());
+    symbol
+  }
+
   pub fn register_raw_export(&mut self, export_name: Atom, symbol: String) {
     let raw_export_map = self.current_module.raw_export_map.get_or_insert_default();
     raw_export_map.insert(export_name, symbol);
@@ -20,6 +20,10 @@ pub struct MemoryGCStorage<Item> {
   generation: AtomicU32,
   max_generations: u32,
   data: IdentifierDashMap<CacheData<Item>>,
+  /// Keys set or removed since the last `take_changes`, `true` means set and `false` means removed.
+  ///
+  /// Only recorded after `track_changes` is called, persistent cache uses it to sync the storage.
+  changes: Option<IdentifierDashMap<bool>>,
 }
 
 impl<Item> MemoryGCStorage<Item> {
@@ -28,6 +32,7 @@ impl<Item> MemoryGCStorage<Item> {
       generation: AtomicU32::new(0),
       max_generations,
       data: DashMap::default(),
+      changes: None,
     }
   }
 }
@@ -44,6 +49,9 @@ where
     })
   }
   pub(crate) fn set(&self, id: Identifier, data: Item) {
+    if let Some(changes) = &self.changes {
+      changes.insert(id, true);
+    }
     self.data.insert(
       id,
       CacheData::new(data, self.generation.load(Ordering::Relaxed)),
@@ -52,14 +60,54 @@ where
   /// notify storage that the current generation is over and start a new one
   pub(crate) fn start_next_generation(&self) {
     let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
-    self.data.retain(|_, cache_data| {
+    self.data.retain(|id, cache_data| {
       // Remove the data if it is not accessed for `max_generations`.
       // With `max_generations` set to x, the cache was generated on generation y, will be removed on generation x + y + 1.
       //
       // For example:
       // Cache created on generation 0 will be removed on generation 2 with `max_generations` set to 1,
       // If it's not accessed on generation 1.
-      cache_data.generation.saturating_add(self.max_generations) >= generation
+      let keep = cache_data.generation.saturating_add(self.max_generations) >= generation;
+      if !keep && let Some(changes) = &self.changes {
+        changes.insert(*id, false);
+      }
+      keep
     });
   }
+  /// start recording the changed keys
+  pub(crate) fn track_changes(&mut self) {
+    if self.changes.is_none() {
+      self.changes = Some(Default::default());
+    }
+  }
+  /// insert an item restored from persistent cache without recording it as a change
+  pub(crate) fn restore(&self, id: Identifier, data: Item) {
+    self.data.insert(
+      id,
+      CacheData::new(data, self.generation.load(Ordering::Relaxed)),
+    );
+  }
+  pub(crate) fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+  /// take the items set and the keys removed since the last call
+  pub(crate) fn take_changes(&self) -> (Vec<(Identifier, Item)>, Vec<Identifier>) {
+    let mut updated = vec![];
+    let mut removed = vec![];
+    let Some(changes) = &self.changes else {
+      return (updated, removed);
+    };
+    let keys = changes
+      .iter()
+      .map(|item| (*item.key(), *item.value()))
+      .collect::<Vec<_>>();
+    changes.clear();
+    for (id, is_set) in keys {
+      match self.data.get(&id) {
+        Some(item) if is_set => updated.push((id, item.item.clone())),
+        _ => removed.push(id),
+      }
+    }
+    (updated, removed)
+  }
 }
@@ -26,6 +26,7 @@ use rustc_hash::{FxHashSet, FxHasher};
 #[allow(clippy::collapsible_else_if)]
 pub fn get_used_module_ids_and_modules(
   compilation: &Compilation,
+  module_ids: &ModuleIdsArtifact,
   filter: Option<Box<dyn Fn(&BoxModule) -> bool>>,
 ) -> (FxHashSet<String>, Vec<ModuleIdentifier>) {
   let chunk_graph = &compilation.chunk_graph;
@@ -45,8 +46,7 @@ pub fn get_used_module_ids_and_modules(
     .values()
     .filter(|m| m.need_id())
     .for_each(|module| {
-      let module_id =
-        ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier());
+      let module_id = ChunkGraph::get_module_id(module_ids, module.identifier());
       if let Some(module_id) = module_id {
         used_ids.insert(module_id.to_string());
       } else {
@@ -29,9 +29,18 @@ async fn module_ids(
       diagnostics.push(diagnostic);
     }
     module_ids.clear();
+    // the ids revived from the records are cleared as well, assign them again
+    if let Some(ids) = compilation
+      .records
+      .as_ref()
+      .and_then(|records| records.ids.as_ref())
+    {
+      ids.revive_module_ids(compilation, module_ids);
+    }
   }
 
-  let (used_ids, mut modules_in_natural_order) = get_used_module_ids_and_modules(compilation, None);
+  let (used_ids, mut modules_in_natural_order) =
+    get_used_module_ids_and_modules(compilation, module_ids, None);
 
   let mut module_ids_artifact = std::mem::take(module_ids);
   let module_graph = compilation.get_module_graph();
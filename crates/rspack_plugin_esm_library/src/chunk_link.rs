@@ -29,6 +29,11 @@ pub struct SymbolRef {
   pub module: ModuleIdentifier,
   pub symbol: Atom,
   pub ids: Vec<Atom>,
+  /**
+  the rendered value of a constant binding, chunks that consume the binding
+  from other chunks inline it instead of importing it
+  */
+  pub inlined: Option<String>,
   renderer: Arc<dyn Fn(&SymbolRef) -> String + Send + Sync>,
 }
 
@@ -38,6 +43,7 @@ impl std::fmt::Debug for SymbolRef {
       .field("module", &self.module)
       .field("symbol", &self.symbol)
       .field("ids", &self.ids)
+      .field("inlined", &self.inlined)
       .finish()
   }
 }
@@ -53,10 +59,16 @@ impl SymbolRef {
       module,
       symbol,
       ids,
+      inlined: None,
       renderer,
     }
   }
 
+  pub fn with_inlined(mut self, inlined: Option<String>) -> Self {
+    self.inlined = inlined;
+    self
+  }
+
   pub fn render(&self) -> String {
     (self.renderer)(self)
   }
@@ -426,4 +438,10 @@ impl ChunkLinkContext {
   pub fn re_exports(&self) -> &FxIndexMap<ReExportFrom, FxHashMap<Atom, FxHashSet<Atom>>> {
     &self.re_exports
   }
+
+  pub fn re_exports_mut(
+    &mut self,
+  ) -> &mut FxIndexMap<ReExportFrom, FxHashMap<Atom, FxHashSet<Atom>>> {
+    &mut self.re_exports
+  }
 }
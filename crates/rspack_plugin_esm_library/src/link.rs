@@ -39,7 +39,7 @@ use swc_core::{
 
 use crate::{
   EsmLibraryPlugin,
-  chunk_link::{ChunkLinkContext, ExternalInterop, Ref, SymbolRef},
+  chunk_link::{ChunkLinkContext, ExternalInterop, ReExportFrom, Ref, SymbolRef},
 };
 
 pub(crate) trait GetMut<K, V> {
@@ -259,6 +259,9 @@ impl EsmLibraryPlugin {
       &escaped_identifiers,
     ));
 
+    // import and re-export the symbols from where they are defined rather than through other chunks
+    Self::collapse_re_export_chains(compilation, &mut link);
+
     let mut namespace_object_sources: IdentifierMap<String> = IdentifierMap::default();
     for (ukey, mut needed_namespace_objects) in needed_namespace_objects_by_ukey {
       let mut visited = FxHashSet::default();
@@ -1052,6 +1055,16 @@ var {} = {{}};
         &mut chunk_link.used_names,
       );
 
+      // declare the constants of other chunks in the entry chunk, rather than re-exporting them
+      let binding = match binding {
+        Ref::Symbol(SymbolRef {
+          module,
+          inlined: Some(inlined),
+          ..
+        }) if Self::get_module_chunk(module, compilation) != entry_chunk => Ref::Inline(inlined),
+        binding => binding,
+      };
+
       match binding {
         Ref::Symbol(symbol_binding) => {
           let ref_chunk = Self::get_module_chunk(symbol_binding.module, compilation);
@@ -1495,6 +1508,18 @@ var {} = {{}};
         }
 
         if needs_import_chunk && !from_external {
+          // constants are inlined rather than imported from the chunk that defines them
+          all_refs.retain(|(ref_str, cur_ref)| {
+            let Some(inlined) = &cur_ref.inlined else {
+              return true;
+            };
+            refs.insert(ref_str.clone(), Ref::Inline(inlined.clone()));
+            false
+          });
+          if all_refs.is_empty() {
+            continue;
+          }
+
           let readable_identifier = get_cached_readable_identifier(
             &m,
             module_graph,
@@ -1695,6 +1720,152 @@ var {} = {{}};
     errors
   }
 
+  /**
+  A chunk may re-export the symbols that it doesn't define, e.g. the symbols imported from the
+  external modules, or the entry exports defined by other chunks. The chunks that import or
+  re-export these symbols from it import or re-export them from where they are defined instead,
+  and keep importing the chunk for its side effects.
+  */
+  fn collapse_re_export_chains(
+    compilation: &Compilation,
+    link: &mut UkeyMap<ChunkUkey, ChunkLinkContext>,
+  ) {
+    // Map<chunk, Map<export_name, (re_export_from, imported_name)>>
+    let re_exported = link
+      .iter()
+      .map(|(chunk, chunk_link)| {
+        let mut names = FxHashMap::<Atom, (ReExportFrom, Atom)>::default();
+        for (re_export_from, symbols) in chunk_link.re_exports() {
+          for (imported, export_names) in symbols {
+            for export_name in export_names {
+              names.insert(
+                export_name.clone(),
+                (re_export_from.clone(), imported.clone()),
+              );
+            }
+          }
+        }
+        (*chunk, names)
+      })
+      .collect::<UkeyMap<_, _>>();
+    // a module of every chunk, importing it executes the chunk
+    let chunk_modules = link
+      .iter()
+      .filter_map(|(chunk, chunk_link)| {
+        chunk_link
+          .hoisted_modules
+          .iter()
+          .chain(chunk_link.decl_modules.iter())
+          .next()
+          .map(|m| (*chunk, *m))
+      })
+      .collect::<UkeyMap<_, _>>();
+
+    let resolve = |chunk: ChunkUkey, name: &Atom| {
+      let mut target = re_exported.get(&chunk)?.get(name)?;
+      let mut visited = FxHashSet::from_iter([chunk]);
+      while let ReExportFrom::Chunk(next_chunk) = &target.0
+        && visited.insert(*next_chunk)
+        && let Some(next_target) = re_exported
+          .get(next_chunk)
+          .and_then(|names| names.get(&target.1))
+      {
+        target = next_target;
+      }
+      Some(target.clone())
+    };
+
+    for (chunk, chunk_link) in link.iter_mut() {
+      let re_exports = chunk_link
+        .re_exports()
+        .iter()
+        .filter_map(|(re_export_from, symbols)| match re_export_from {
+          ReExportFrom::Chunk(ref_chunk) if chunk_modules.contains_key(ref_chunk) => {
+            Some((ref_chunk, symbols))
+          }
+          _ => None,
+        })
+        .flat_map(|(ref_chunk, symbols)| {
+          symbols.iter().filter_map(|(local, export_names)| {
+            let target = resolve(*ref_chunk, local)?;
+            (target.0 != ReExportFrom::Chunk(*chunk))
+              .then(|| (*ref_chunk, local.clone(), export_names.clone(), target))
+          })
+        })
+        .collect::<Vec<_>>();
+
+      for (ref_chunk, local, export_names, (re_export_from, imported)) in re_exports {
+        let re_exports = chunk_link.re_exports_mut();
+        let ref_chunk_key = ReExportFrom::Chunk(ref_chunk);
+        let symbols = re_exports
+          .get_mut(&ref_chunk_key)
+          .expect("should have re-exports");
+        symbols.remove(&local);
+        if symbols.is_empty() {
+          re_exports.shift_remove(&ref_chunk_key);
+        }
+        re_exports
+          .entry(re_export_from)
+          .or_default()
+          .entry(imported)
+          .or_default()
+          .extend(export_names);
+        chunk_link
+          .imports
+          .entry(chunk_modules[&ref_chunk])
+          .or_default();
+      }
+
+      let imports = chunk_link
+        .imports
+        .iter()
+        .filter(|(m, _)| Self::get_module_chunk(**m, compilation) != *chunk)
+        .flat_map(|(m, symbols)| {
+          let ref_chunk = Self::get_module_chunk(*m, compilation);
+          symbols.iter().filter_map(move |(imported, local)| {
+            match resolve(ref_chunk, imported)? {
+              (ReExportFrom::Request(request), imported_from_request) => Some((
+                *m,
+                imported.clone(),
+                local.clone(),
+                request,
+                imported_from_request,
+              )),
+              // the symbols of other chunks are imported from the chunks that define them already
+              (ReExportFrom::Chunk(_), _) => None,
+            }
+          })
+        })
+        .collect::<Vec<_>>();
+
+      for (m, imported, local, request, imported_from_request) in imports {
+        let import_spec = chunk_link
+          .raw_import_stmts
+          .entry((request, None))
+          .or_default();
+        let local_from_request = if imported_from_request == "default" {
+          import_spec
+            .default_import
+            .get_or_insert_with(|| local.clone())
+        } else {
+          import_spec
+            .atoms
+            .entry(imported_from_request)
+            .or_insert_with(|| local.clone())
+        };
+        // the symbol is already imported from the request by another name
+        if local_from_request != &local {
+          continue;
+        }
+        chunk_link
+          .imports
+          .get_mut(&m)
+          .expect("should have imports")
+          .remove(&imported);
+      }
+    }
+  }
+
   // the final name is the exact symbol in ref chunk
   #[allow(clippy::too_many_arguments)]
   fn get_binding(
@@ -1947,12 +2118,30 @@ var {} = {{}};
                   .get_internal_name(&direct_export)
                   .unwrap_or_else(|| panic!("should set internal name for {direct_export}"));
 
-                return Ref::Symbol(SymbolRef::new(
-                  info.module,
-                  symbol.clone(),
-                  used_name[1..].to_vec(),
-                  Arc::new(move |binding| normal_render(binding, as_call, call_context, asi_safe)),
-                ));
+                // the export is still used by name (e.g. it's exported by an entry), but the
+                // constant value can be inlined into the chunks that don't define it
+                let inlined = if export_name.len() == 1 {
+                  export_info.can_inline_provide().map(|value| {
+                    value.render(&to_normal_comment(&format!(
+                      "inlined export {}",
+                      property_access(&export_name, 0)
+                    )))
+                  })
+                } else {
+                  None
+                };
+
+                return Ref::Symbol(
+                  SymbolRef::new(
+                    info.module,
+                    symbol.clone(),
+                    used_name[1..].to_vec(),
+                    Arc::new(move |binding| {
+                      normal_render(binding, as_call, call_context, asi_safe)
+                    }),
+                  )
+                  .with_inlined(inlined),
+                );
               }
               UsedName::Inlined(inlined) => {
                 return Ref::Inline(inlined.render(&to_normal_comment(&format!(
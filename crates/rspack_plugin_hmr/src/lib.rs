@@ -62,6 +62,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     modules: old_all_modules,
     runtime_modules: old_runtime_modules,
     hash: old_hash,
+    ..
   }) = compilation.records.take()
   else {
     return Ok(());
@@ -0,0 +1,109 @@
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
+use rspack_core::{
+  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCodeGeneration,
+  DependencyId, DependencyRange, DependencyTemplate, DependencyTemplateType, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+/// A member of a top-level class, it is removed in code generation if it is unused,
+/// see `optimization.removeUnusedClassMembers`
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct ClassMemberDependency {
+  id: DependencyId,
+  range: DependencyRange,
+  #[cacheable(with=AsPreset)]
+  class_name: Atom,
+  #[cacheable(with=AsPreset)]
+  member_name: Atom,
+}
+
+impl ClassMemberDependency {
+  pub fn new(range: DependencyRange, class_name: Atom, member_name: Atom) -> Self {
+    Self {
+      id: DependencyId::new(),
+      range,
+      class_name,
+      member_name,
+    }
+  }
+
+  pub fn class_name(&self) -> &Atom {
+    &self.class_name
+  }
+
+  pub fn member_name(&self) -> &Atom {
+    &self.member_name
+  }
+
+  fn is_unused(&self, compilation: &Compilation) -> bool {
+    compilation
+      .class_members_artifact
+      .borrow()
+      .contains(&self.id)
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for ClassMemberDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn range(&self) -> Option<DependencyRange> {
+    Some(self.range)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for ClassMemberDependency {}
+
+impl AsContextDependency for ClassMemberDependency {}
+
+#[cacheable_dyn]
+impl DependencyCodeGeneration for ClassMemberDependency {
+  fn dependency_template(&self) -> Option<DependencyTemplateType> {
+    Some(ClassMemberDependencyTemplate::template_type())
+  }
+
+  fn update_hash(
+    &self,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+    hasher.write_u8(self.is_unused(compilation) as u8);
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Clone, Default)]
+pub struct ClassMemberDependencyTemplate;
+
+impl ClassMemberDependencyTemplate {
+  pub fn template_type() -> DependencyTemplateType {
+    DependencyTemplateType::Custom("ClassMemberDependency")
+  }
+}
+
+impl DependencyTemplate for ClassMemberDependencyTemplate {
+  fn render(
+    &self,
+    dep: &dyn DependencyCodeGeneration,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let dep = dep
+      .as_any()
+      .downcast_ref::<ClassMemberDependency>()
+      .expect("ClassMemberDependencyTemplate should be used for ClassMemberDependency");
+
+    if dep.is_unused(code_generatable_context.compilation) {
+      source.replace(dep.range.start, dep.range.end, "", None);
+    }
+  }
+}
@@ -3,16 +3,32 @@ use rspack_cacheable::{
   with::{AsPreset, AsVec},
 };
 use rspack_core::{
-  AsContextDependency, AsModuleDependency, Dependency, DependencyCategory,
+  AsContextDependency, AsModuleDependency, ConcatenationScope, Dependency, DependencyCategory,
   DependencyCodeGeneration, DependencyId, DependencyRange, DependencyTemplate,
   DependencyTemplateType, DependencyType, ExportNameOrSpec, ExportSpec, ExportsInfoGetter,
   ExportsOfExportsSpec, ExportsSpec, GetUsedNameParam, InitFragmentExt, InitFragmentKey,
-  InitFragmentStage, ModuleGraph, ModuleGraphCacheArtifact, NormalInitFragment,
-  PrefetchExportsInfoMode, RuntimeGlobals, TemplateContext, TemplateReplaceSource, UsedName,
-  property_access,
+  InitFragmentStage, ModuleGraph, ModuleGraphCacheArtifact, ModuleInitFragments,
+  NormalInitFragment, PrefetchExportsInfoMode, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource, UsedName, property_access, property_name,
 };
 use swc_core::atoms::Atom;
 
+/// Same as `name in {}` in webpack
+const OBJECT_PROTOTYPE_PROPERTIES: &[&str] = &[
+  "constructor",
+  "hasOwnProperty",
+  "isPrototypeOf",
+  "propertyIsEnumerable",
+  "toLocaleString",
+  "toString",
+  "valueOf",
+  "__defineGetter__",
+  "__defineSetter__",
+  "__lookupGetter__",
+  "__lookupSetter__",
+  "__proto__",
+];
+
 #[cacheable]
 #[derive(Debug, Clone, Copy)]
 pub enum ExportsBase {
@@ -22,6 +38,8 @@ pub enum ExportsBase {
   DefinePropertyExports,
   DefinePropertyModuleExports,
   DefinePropertyThis,
+  /// A property of the object literal assigned to `module.exports`
+  ModuleExportsProperty,
 }
 
 impl ExportsBase {
@@ -58,6 +76,8 @@ pub struct CommonJsExportsDependency {
   id: DependencyId,
   range: DependencyRange,
   value_range: Option<DependencyRange>,
+  /// The range of the key of a `ModuleExportsProperty`, which is replaced by the mangled name
+  key_range: Option<DependencyRange>,
   base: ExportsBase,
   #[cacheable(with=AsVec<AsPreset>)]
   names: Vec<Atom>,
@@ -74,10 +94,30 @@ impl CommonJsExportsDependency {
       id: DependencyId::new(),
       range,
       value_range,
+      key_range: None,
       base,
       names,
     }
   }
+
+  /// Creates a dependency for a property of `module.exports = { ... }`, the `range` is
+  /// removed if the property is unused and `removable`. The property can only be mangled if
+  /// it has a `key_range`, an empty `key_range` of a shorthand property inserts the key
+  pub fn new_module_exports_property(
+    range: DependencyRange,
+    removable: bool,
+    key_range: Option<DependencyRange>,
+    name: Atom,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      range,
+      value_range: removable.then_some(range),
+      key_range,
+      base: ExportsBase::ModuleExportsProperty,
+      names: vec![name],
+    }
+  }
 }
 
 #[cacheable_dyn]
@@ -103,9 +143,17 @@ impl Dependency for CommonJsExportsDependency {
     _mg: &ModuleGraph,
     _mg_cache: &ModuleGraphCacheArtifact,
   ) -> Option<ExportsSpec> {
+    // `module.exports = { ... }` only provides the exports of its properties
+    let name = self.names.first()?;
+    // the properties inherited from `Object.prototype` and `__esModule` which is checked by the
+    // interop helpers keep their names, and the key of an object literal property is only
+    // replaceable if it has a `key_range`
+    let can_mangle = !OBJECT_PROTOTYPE_PROPERTIES.contains(&name.as_str())
+      && name != "__esModule"
+      && (!matches!(self.base, ExportsBase::ModuleExportsProperty) || self.key_range.is_some());
     let vec = vec![ExportNameOrSpec::ExportSpec(ExportSpec {
-      name: self.names[0].clone(),
-      can_mangle: Some(false), // in webpack, object own property may not be mangled
+      name: name.clone(),
+      can_mangle: Some(can_mangle),
       ..Default::default()
     })];
     Some(ExportsSpec {
@@ -160,6 +208,7 @@ impl DependencyTemplate for CommonJsExportsDependencyTemplate {
       runtime,
       init_fragments,
       runtime_requirements,
+      concatenation_scope,
       ..
     } = code_generatable_context;
 
@@ -188,6 +237,64 @@ impl DependencyTemplate for CommonJsExportsDependencyTemplate {
       )
     };
 
+    if matches!(dep.base, ExportsBase::ModuleExportsProperty) {
+      // the property is kept if it is used or its value may have side effects
+      if used.is_none()
+        && let Some(value_range) = &dep.value_range
+      {
+        source.replace(value_range.start, value_range.end, "", None);
+      } else if let Some(UsedName::Normal(used)) = &used
+        && let Some(used_name) = used.first()
+        && dep.names.first() != Some(used_name)
+        && let Some(key_range) = &dep.key_range
+      {
+        let key = property_name(used_name).expect("should have property_name");
+        let key = if key_range.start == key_range.end {
+          // { a } -> { b: a }
+          format!("{key}: ")
+        } else {
+          key.into_owned()
+        };
+        source.replace(key_range.start, key_range.end, &key, None);
+      }
+      return;
+    }
+
+    // the concatenated module has no `exports` object, the exports are replaced by the
+    // top-level symbols registered to the concatenation scope
+    if let Some(concatenation_scope) = concatenation_scope
+      && let Some(name) = dep.names.first()
+    {
+      let Some(UsedName::Normal(used)) = used else {
+        render_unused_export(dep, used.as_ref(), source, init_fragments);
+        return;
+      };
+      let symbol = declare_concatenated_export(concatenation_scope, init_fragments, name);
+      if dep.base.is_expression() {
+        source.replace(
+          dep.range.start,
+          dep.range.end,
+          &format!("{symbol}{}", property_access(&used[1..], 0)),
+          None,
+        );
+      } else {
+        // only `Object.defineProperty(exports, "__esModule", { value: true })` is allowed to
+        // be concatenated
+        let value_range = dep
+          .value_range
+          .as_ref()
+          .expect("Define property need value range");
+        source.replace(
+          dep.range.start,
+          value_range.start,
+          &format!("{symbol} = ("),
+          None,
+        );
+        source.replace(value_range.end, dep.range.end, ").value", None);
+      }
+      return;
+    }
+
     let exports_argument = module.get_exports_argument();
     let module_argument = module.get_module_argument();
 
@@ -220,23 +327,7 @@ impl DependencyTemplate for CommonJsExportsDependencyTemplate {
           None,
         );
       } else {
-        // Export a inlinable const from cjs is not possible for now but we compat it here
-        let is_inlined = matches!(used, Some(UsedName::Inlined(_)));
-        let placeholder_var = format!(
-          "__webpack_{}_export__",
-          if is_inlined { "inlined" } else { "unused" }
-        );
-        source.replace(dep.range.start, dep.range.end, &placeholder_var, None);
-        init_fragments.push(
-          NormalInitFragment::new(
-            format!("var {placeholder_var};\n"),
-            InitFragmentStage::StageConstants,
-            0,
-            InitFragmentKey::CommonJsExports(placeholder_var),
-            None,
-          )
-          .boxed(),
-        );
+        render_unused_export(dep, used.as_ref(), source, init_fragments);
       }
     } else if dep.base.is_define_property() {
       if let Some(value_range) = &dep.value_range {
@@ -259,23 +350,7 @@ impl DependencyTemplate for CommonJsExportsDependencyTemplate {
             panic!("Unexpected base type");
           }
         } else {
-          init_fragments.push(
-            NormalInitFragment::new(
-              "var __webpack_unused_export__;\n".to_string(),
-              InitFragmentStage::StageConstants,
-              0,
-              InitFragmentKey::CommonJsExports("__webpack_unused_export__".to_owned()),
-              None,
-            )
-            .boxed(),
-          );
-          source.replace(
-            dep.range.start,
-            value_range.start,
-            "__webpack_unused_export__ = (",
-            None,
-          );
-          source.replace(value_range.end, dep.range.end, ")", None);
+          render_unused_export(dep, used.as_ref(), source, init_fragments);
         }
       } else {
         panic!("Define property need value range");
@@ -285,3 +360,63 @@ impl DependencyTemplate for CommonJsExportsDependencyTemplate {
     }
   }
 }
+
+/// Assigns the value of an export that is not used by its name to a placeholder variable
+fn render_unused_export(
+  dep: &CommonJsExportsDependency,
+  used: Option<&UsedName>,
+  source: &mut TemplateReplaceSource,
+  init_fragments: &mut ModuleInitFragments<'_>,
+) {
+  // Export a inlinable const from cjs is not possible for now but we compat it here
+  let placeholder_var = if dep.base.is_expression() && matches!(used, Some(UsedName::Inlined(_))) {
+    "__webpack_inlined_export__"
+  } else {
+    "__webpack_unused_export__"
+  };
+  init_fragments.push(
+    NormalInitFragment::new(
+      format!("var {placeholder_var};\n"),
+      InitFragmentStage::StageConstants,
+      0,
+      InitFragmentKey::CommonJsExports(placeholder_var.to_owned()),
+      None,
+    )
+    .boxed(),
+  );
+  if dep.base.is_expression() {
+    source.replace(dep.range.start, dep.range.end, placeholder_var, None);
+  } else {
+    let value_range = dep
+      .value_range
+      .as_ref()
+      .expect("Define property need value range");
+    source.replace(
+      dep.range.start,
+      value_range.start,
+      &format!("{placeholder_var} = ("),
+      None,
+    );
+    source.replace(value_range.end, dep.range.end, ")", None);
+  }
+}
+
+/// Declares the top-level symbol holding an export of the concatenated CommonJS module
+pub(super) fn declare_concatenated_export(
+  concatenation_scope: &mut ConcatenationScope,
+  init_fragments: &mut ModuleInitFragments<'_>,
+  name: &Atom,
+) -> String {
+  let symbol = concatenation_scope.register_commonjs_export(name);
+  init_fragments.push(
+    NormalInitFragment::new(
+      format!("var {symbol};\n"),
+      InitFragmentStage::StageConstants,
+      0,
+      InitFragmentKey::CommonJsExports(symbol.clone()),
+      None,
+    )
+    .boxed(),
+  );
+  symbol
+}
@@ -11,7 +11,7 @@ use rspack_core::{
 };
 use swc_core::atoms::Atom;
 
-use super::ExportsBase;
+use super::{ExportsBase, common_js_exports_dependency::declare_concatenated_export};
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -135,37 +135,43 @@ impl DependencyTemplate for CommonJsSelfReferenceDependencyTemplate {
       module,
       runtime,
       runtime_requirements,
+      init_fragments,
+      concatenation_scope,
       ..
     } = code_generatable_context;
+
+    // the concatenated module has no `exports` object, refer to the symbol of the export instead
+    if let Some(concatenation_scope) = concatenation_scope
+      && let Some(name) = dep.names.first()
+    {
+      let symbol = declare_concatenated_export(concatenation_scope, init_fragments, name);
+      source.replace(
+        dep.range.start,
+        dep.range.end,
+        &format!("{symbol}{}", property_access(&dep.names[1..], 0)),
+        None,
+      );
+      return;
+    }
     let module_graph = compilation.get_module_graph();
     let module = module_graph
       .module_by_identifier(&module.identifier())
       .expect("should have mgm");
 
     let used = if dep.names.is_empty() {
-      let used_name = if dep.names.is_empty() {
-        let exports_info_used =
-          module_graph.get_prefetched_exports_info_used(&module.identifier(), *runtime);
-        ExportsInfoGetter::get_used_name(
-          GetUsedNameParam::WithoutNames(&exports_info_used),
-          *runtime,
-          &dep.names,
-        )
-      } else {
-        let exports_info = module_graph.get_prefetched_exports_info(
-          &module.identifier(),
-          PrefetchExportsInfoMode::Nested(&dep.names),
-        );
-        ExportsInfoGetter::get_used_name(
-          GetUsedNameParam::WithNames(&exports_info),
-          *runtime,
-          &dep.names,
-        )
-      };
-
-      used_name.unwrap_or_else(|| UsedName::Normal(dep.names.clone()))
+      UsedName::Normal(vec![])
     } else {
-      UsedName::Normal(dep.names.clone())
+      let exports_info = module_graph.get_prefetched_exports_info(
+        &module.identifier(),
+        PrefetchExportsInfoMode::Nested(&dep.names),
+      );
+      // the export may be mangled
+      ExportsInfoGetter::get_used_name(
+        GetUsedNameParam::WithNames(&exports_info),
+        *runtime,
+        &dep.names,
+      )
+      .unwrap_or_else(|| UsedName::Normal(dep.names.clone()))
     };
 
     let exports_argument = module.get_exports_argument();
@@ -26,6 +26,7 @@ pub struct ESMExportSpecifierDependency {
   value: Atom, // id
   inline: Option<EvaluatedInlinableValue>,
   enum_value: Option<TSEnumValue>,
+  no_side_effects: bool,
 }
 
 impl ESMExportSpecifierDependency {
@@ -34,6 +35,7 @@ impl ESMExportSpecifierDependency {
     value: Atom,
     inline: Option<EvaluatedInlinableValue>,
     enum_value: Option<TSEnumValue>,
+    no_side_effects: bool,
     range: DependencyRange,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
@@ -43,6 +45,7 @@ impl ESMExportSpecifierDependency {
       value,
       inline,
       enum_value,
+      no_side_effects,
       range,
       loc,
       id: DependencyId::new(),
@@ -77,6 +80,7 @@ impl Dependency for ESMExportSpecifierDependency {
       exports: ExportsOfExportsSpec::Names(vec![ExportNameOrSpec::ExportSpec(ExportSpec {
         name: self.name.clone(),
         inlinable: self.inline.clone(),
+        no_side_effects: self.no_side_effects,
         exports: self.enum_value.as_ref().map(|enum_value| {
           ExportSpecExports::new(
             enum_value
@@ -11,8 +11,9 @@ use rspack_core::{
   GetUsedNameParam, ImportAttributes, ImportPhase, JavascriptParserOptions, ModuleDependency,
   ModuleGraph, ModuleGraphCacheArtifact, ModuleGraphConnection, ModuleReferenceOptions,
   PrefetchExportsInfoMode, ReferencedExport, ResourceIdentifier, RuntimeSpec, SharedSourceMap,
-  TemplateContext, TemplateReplaceSource, UsedByExports, UsedName,
-  create_exports_object_referenced, get_exports_type, property_access, to_normal_comment,
+  TemplateContext, TemplateReplaceSource, TerminalBinding, UsedByExports, UsedName,
+  create_exports_object_referenced, get_exports_type, get_terminal_binding, property_access,
+  to_normal_comment,
 };
 use rspack_error::Diagnostic;
 use rspack_util::json_stringify;
@@ -53,6 +54,15 @@ pub struct ESMImportSpecifierDependency {
   pub evaluated_in_operator: bool,
   loc: Option<DependencyLocation>,
   pub namespace_object_as_context: bool,
+  /// The dependency is the callee of a top-level call which is side effects free
+  /// if the imported function is annotated with `#__NO_SIDE_EFFECTS__`
+  pub no_side_effects_call: bool,
+  /// The dependency is the callee of `new` or the right side of `instanceof`, which doesn't
+  /// expose the members of the imported class
+  pub opaque_class_reference: bool,
+  /// The dependency is a member expression which is assigned, updated or deleted,
+  /// so the accessed export can't be inlined in any module
+  pub mutated: bool,
   factorize_info: FactorizeInfo,
 }
 
@@ -92,6 +102,9 @@ impl ESMImportSpecifierDependency {
       used_by_exports: None,
       evaluated_in_operator: false,
       namespace_object_as_context: false,
+      no_side_effects_call: false,
+      opaque_class_reference: false,
+      mutated: false,
       referenced_properties_in_destructuring,
       phase,
       attributes,
@@ -101,6 +114,12 @@ impl ESMImportSpecifierDependency {
     }
   }
 
+  /// The ids in the source, they may be updated in the module graph by the side effects
+  /// optimization, see [Self::get_ids]
+  pub fn source_ids(&self) -> &[Atom] {
+    &self.ids
+  }
+
   pub fn get_ids<'a>(&'a self, mg: &'a ModuleGraph) -> &'a [Atom] {
     mg.get_dep_meta_if_existing(&self.id)
       .map(|meta| meta.ids.as_slice())
@@ -133,7 +152,7 @@ impl ESMImportSpecifierDependency {
         name: v.to_vec(),
         can_mangle: true,
         // Need access the export value to trigger side effects for deferred module
-        can_inline: !self.phase.is_defer(),
+        can_inline: !self.phase.is_defer() && !self.mutated,
       })]
     } else {
       create_exports_object_referenced()
@@ -192,12 +211,29 @@ impl Dependency for ESMImportSpecifierDependency {
 
   fn get_module_evaluation_side_effects_state(
     &self,
-    _module_graph: &ModuleGraph,
+    module_graph: &ModuleGraph,
     _module_graph_cache: &ModuleGraphCacheArtifact,
     _module_chain: &mut IdentifierSet,
     _connection_state_cache: &mut IdentifierMap<ConnectionState>,
   ) -> ConnectionState {
-    ConnectionState::Active(false)
+    if !self.no_side_effects_call {
+      return ConnectionState::Active(false);
+    }
+    let Some(module) = module_graph.module_identifier_by_dependency_id(&self.id) else {
+      return ConnectionState::Active(true);
+    };
+    let [name] = self.get_ids(module_graph) else {
+      return ConnectionState::Active(true);
+    };
+    let exports_info =
+      module_graph.get_prefetched_exports_info(module, PrefetchExportsInfoMode::Default);
+    let export_info = exports_info.get_export_info_without_mut_module_graph(name);
+    // follow the reexports to find the annotated function
+    let no_side_effects = matches!(
+      get_terminal_binding(&export_info, module_graph),
+      Some(TerminalBinding::ExportInfo(export_info)) if export_info.as_data(module_graph).no_side_effects()
+    );
+    ConnectionState::Active(!no_side_effects)
   }
 
   fn resource_identifier(&self) -> Option<&str> {
@@ -639,24 +675,35 @@ impl DependencyConditionFn for ESMImportSpecifierDependencyCondition {
     connection: &ModuleGraphConnection,
     runtime: Option<&RuntimeSpec>,
     module_graph: &ModuleGraph,
-    _module_graph_cache: &ModuleGraphCacheArtifact,
+    module_graph_cache: &ModuleGraphCacheArtifact,
   ) -> ConnectionState {
     let dependency = module_graph.dependency_by_id(&connection.dependency_id);
     let dependency = dependency
       .downcast_ref::<ESMImportSpecifierDependency>()
       .expect("should be ESMImportSpecifierDependency");
+    // the call is kept regardless of the usage of the declaration when the imported
+    // function is not annotated with `#__NO_SIDE_EFFECTS__`
+    let used_by_exports = if dependency.no_side_effects_call
+      && dependency
+        .get_module_evaluation_side_effects_state(
+          module_graph,
+          module_graph_cache,
+          &mut Default::default(),
+          &mut Default::default(),
+        )
+        .is_true()
+    {
+      None
+    } else {
+      dependency.used_by_exports.as_ref()
+    };
     ConnectionState::Active(
       connection_active_inline_value_for_esm_import_specifier(
         dependency,
         connection,
         runtime,
         module_graph,
-      ) && connection_active_used_by_exports(
-        connection,
-        runtime,
-        module_graph,
-        dependency.used_by_exports.as_ref(),
-      ),
+      ) && connection_active_used_by_exports(connection, runtime, module_graph, used_by_exports),
     )
   }
 }
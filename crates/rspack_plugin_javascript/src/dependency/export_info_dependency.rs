@@ -4,8 +4,8 @@ use rspack_cacheable::{
   with::{AsPreset, AsVec},
 };
 use rspack_core::{
-  DependencyCodeGeneration, DependencyTemplate, DependencyTemplateType, ExportProvided,
-  ExportsInfoGetter, GetUsedNameParam, PrefetchExportsInfoMode, TemplateContext,
+  DependencyCodeGeneration, DependencyRange, DependencyTemplate, DependencyTemplateType,
+  ExportProvided, ExportsInfoGetter, GetUsedNameParam, PrefetchExportsInfoMode, TemplateContext,
   TemplateReplaceSource, UsageState, UsedExports, UsedName,
 };
 use swc_core::ecma::atoms::Atom;
@@ -30,6 +30,15 @@ impl ExportInfoDependency {
       property,
     }
   }
+
+  pub fn range(&self) -> DependencyRange {
+    DependencyRange::new(self.start, self.end)
+  }
+
+  /// The export name of `__webpack_exports_info__.<export_name>.used`
+  pub fn used_export_name(&self) -> Option<&[Atom]> {
+    (self.property == "used" && !self.export_name.is_empty()).then_some(self.export_name.as_slice())
+  }
 }
 
 #[cacheable_dyn]
@@ -1,4 +1,5 @@
 mod amd;
+mod class_member_dependency;
 mod commonjs;
 mod context;
 mod esm;
@@ -11,7 +12,7 @@ mod url;
 mod worker;
 
 pub use self::{
-  amd::*, commonjs::*, context::*, esm::*, export_info_dependency::*, hmr::*,
-  is_included_dependency::*, module_argument_dependency::*, pure_expression_dependency::*, url::*,
-  worker::*,
+  amd::*, class_member_dependency::*, commonjs::*, context::*, esm::*, export_info_dependency::*,
+  hmr::*, is_included_dependency::*, module_argument_dependency::*, pure_expression_dependency::*,
+  url::*, worker::*,
 };
@@ -14,6 +14,9 @@ use rspack_util::ext::DynHash;
 pub struct PureExpressionDependency {
   pub range: DependencyRange,
   used_by_exports: Option<UsedByExports>,
+  /// The call of an imported function, which is only pure when the function is annotated
+  /// with `#__NO_SIDE_EFFECTS__`
+  no_side_effects_callee: Option<DependencyId>,
   id: DependencyId,
   pub module_identifier: ModuleIdentifier,
 }
@@ -23,6 +26,7 @@ impl PureExpressionDependency {
     Self {
       range,
       used_by_exports: None,
+      no_side_effects_callee: None,
       id: DependencyId::default(),
       module_identifier,
     }
@@ -33,6 +37,20 @@ impl PureExpressionDependency {
     compilation: &Compilation,
     runtime: Option<&RuntimeSpec>,
   ) -> RuntimeCondition {
+    if let Some(callee) = &self.no_side_effects_callee {
+      let module_graph = compilation.get_module_graph();
+      let state = module_graph
+        .dependency_by_id(callee)
+        .get_module_evaluation_side_effects_state(
+          module_graph,
+          &compilation.module_graph_cache_artifact,
+          &mut Default::default(),
+          &mut Default::default(),
+        );
+      if state.is_true() {
+        return RuntimeCondition::Boolean(true);
+      }
+    }
     match self.used_by_exports {
       Some(UsedByExports::Bool(true)) => {
         unreachable!()
@@ -59,6 +77,10 @@ impl PureExpressionDependency {
   pub fn set_used_by_exports(&mut self, used_by_exports: Option<UsedByExports>) {
     self.used_by_exports = used_by_exports;
   }
+
+  pub fn set_no_side_effects_callee(&mut self, callee: Option<DependencyId>) {
+    self.no_side_effects_callee = callee;
+  }
 }
 
 #[cacheable_dyn]
@@ -0,0 +1,567 @@
+use rspack_core::{CollectedClass, CollectedClassMembersInfo};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+  atoms::Atom,
+  common::{Mark, Span, Spanned, SyntaxContext},
+  ecma::{
+    ast::{
+      BinExpr, BinaryOp, Class, ClassDecl, ClassExpr, ClassMember, DefaultDecl, ExportDefaultExpr,
+      ExportNamedSpecifier, Expr, Id, Ident, ImportDecl, Lit, MemberExpr, MemberProp, ModuleDecl,
+      ModuleExportName, ModuleItem, NewExpr, ObjectPat, ObjectPatProp, Program, PropName, Stmt,
+      Str, SuperProp, SuperPropExpr, ThisExpr, Tpl, VarDeclarator,
+    },
+    utils::{ExprCtx, ExprExt},
+    visit::{Visit, VisitWith},
+  },
+};
+
+use super::{DEFAULT_STAR_JS_WORD, JS_DEFAULT_KEYWORD, JavascriptParserPlugin};
+use crate::{dependency::ClassMemberDependency, visitors::JavascriptParser};
+
+/// Members that are invoked implicitly by the language or the host environment,
+/// they are never removed even if they are not accessed by name.
+const IMPLICITLY_USED_MEMBERS: &[&str] = &[
+  "constructor",
+  "toString",
+  "toLocaleString",
+  "valueOf",
+  "toJSON",
+  "then",
+  "next",
+  "return",
+  "throw",
+  "handleEvent",
+];
+
+/// Methods of `Object` that reveal the members of a class through reflection
+const REFLECTION_METHODS: &[&str] = &[
+  "getOwnPropertyNames",
+  "getOwnPropertyDescriptor",
+  "getOwnPropertyDescriptors",
+  "getPrototypeOf",
+];
+
+/// Collects the members of the top-level classes and the property names accessed by
+/// the module for `optimization.removeUnusedClassMembers`.
+///
+/// A class bails out if it is referenced in a way other than `new Foo()`, `x instanceof Foo`,
+/// `Foo.bar` or `class Bar extends Foo` in the same module, if its instances escape, or if the
+/// module uses reflection. An instance escapes unless it is only accessed by static keys, e.g.
+/// `const foo = new Foo(); foo.bar()`, so the instances returned by factories, passed to functions
+/// or accessed with a computed key bail out the class. Whether the exported classes are exposed
+/// by other modules is decided in `RemoveUnusedClassMembersPlugin`.
+pub struct ClassMembersParserPlugin {
+  unresolved_ctxt: SyntaxContext,
+}
+
+impl ClassMembersParserPlugin {
+  pub fn new(unresolved_mark: Mark) -> Self {
+    Self {
+      unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+    }
+  }
+}
+
+impl JavascriptParserPlugin for ClassMembersParserPlugin {
+  fn program(&self, parser: &mut JavascriptParser, program: &Program) -> Option<bool> {
+    let classes = program
+      .as_module()
+      .map(|module| collect_top_level_classes(&module.body))
+      .unwrap_or_default();
+
+    let mut instances_collector = InstancesCollector::default();
+    program.visit_with(&mut instances_collector);
+
+    let mut visitor = ClassMembersVisitor {
+      unresolved_ctxt: self.unresolved_ctxt,
+      class_ids: classes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, class)| Some((class.id.clone()?, index)))
+        .collect(),
+      bailouts: FxHashSet::default(),
+      accessed_properties: FxHashSet::default(),
+      opaque_references: FxHashSet::default(),
+      opaque_classes: FxHashMap::default(),
+      instances: instances_collector.instances,
+      escaped_instances: FxHashSet::default(),
+      current_classes: Vec::new(),
+      has_reflection: false,
+    };
+    program.visit_with(&mut visitor);
+
+    let mut bailouts = visitor.bailouts;
+    for span in &visitor.escaped_instances {
+      if let Some(index) = visitor.opaque_classes.get(span) {
+        bailouts.insert(*index);
+      }
+      visitor.opaque_references.remove(span);
+    }
+    if visitor.has_reflection {
+      bailouts.extend(0..classes.len());
+      visitor.opaque_references.clear();
+    }
+    for (index, class) in classes.iter().enumerate() {
+      if class.bailout {
+        bailouts.insert(index);
+      }
+    }
+    // the members of the super classes are exposed by the instances of the sub classes
+    let mut queue = bailouts.iter().copied().collect::<Vec<_>>();
+    while let Some(index) = queue.pop() {
+      if let Some(super_class) = classes[index].super_class
+        && bailouts.insert(super_class)
+      {
+        queue.push(super_class);
+      }
+    }
+
+    let expr_ctx = ExprCtx {
+      unresolved_ctxt: self.unresolved_ctxt,
+      in_strict: true,
+      is_unresolved_ref_safe: false,
+      remaining_depth: 4,
+    };
+    let mut collected_classes = Vec::new();
+    for (index, class) in classes.iter().enumerate() {
+      if bailouts.contains(&index) {
+        continue;
+      }
+      for member in &class.class.body {
+        if let Some((name, span)) = get_removable_member(member, expr_ctx) {
+          parser.add_dependency(Box::new(ClassMemberDependency::new(
+            span.into(),
+            class.name.clone(),
+            name,
+          )));
+        }
+      }
+      collected_classes.push(CollectedClass {
+        name: class.name.clone(),
+        exports: class.exports.clone(),
+        super_class: class
+          .super_class
+          .map(|super_class| classes[super_class].name.clone()),
+      });
+    }
+
+    parser.opaque_class_references = visitor.opaque_references;
+    parser.build_info.collected_class_members_info = Some(CollectedClassMembersInfo {
+      accessed_properties: visitor.accessed_properties,
+      classes: collected_classes,
+    });
+    None
+  }
+}
+
+struct TopLevelClass<'a> {
+  id: Option<Id>,
+  name: Atom,
+  class: &'a Class,
+  exports: Vec<Atom>,
+  super_class: Option<usize>,
+  bailout: bool,
+}
+
+fn collect_top_level_classes(body: &[ModuleItem]) -> Vec<TopLevelClass<'_>> {
+  let mut classes = Vec::new();
+  for item in body {
+    let (ident, class, exports) = match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => {
+        let Some(ClassDecl { ident, class, .. }) = decl.as_class() else {
+          continue;
+        };
+        (Some(ident), &**class, vec![])
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(decl)) => {
+        let Some(ClassDecl { ident, class, .. }) = decl.decl.as_class() else {
+          continue;
+        };
+        (Some(ident), &**class, vec![ident.sym.clone()])
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(decl)) => {
+        let DefaultDecl::Class(ClassExpr { ident, class }) = &decl.decl else {
+          continue;
+        };
+        (ident.as_ref(), &**class, vec![JS_DEFAULT_KEYWORD.clone()])
+      }
+      _ => continue,
+    };
+    classes.push(TopLevelClass {
+      id: ident.map(Ident::to_id),
+      name: ident.map_or_else(|| DEFAULT_STAR_JS_WORD.clone(), |ident| ident.sym.clone()),
+      class,
+      exports,
+      super_class: None,
+      bailout: !class.decorators.is_empty(),
+    });
+  }
+
+  let find_class = |classes: &[TopLevelClass], ident: &Ident| {
+    let id = ident.to_id();
+    classes
+      .iter()
+      .position(|class| class.id.as_ref() == Some(&id))
+  };
+  for item in body {
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named)) if named.src.is_none() => {
+        for specifier in &named.specifiers {
+          if let Some(ExportNamedSpecifier { orig, exported, .. }) = specifier.as_named()
+            && let ModuleExportName::Ident(orig) = orig
+            && let Some(index) = find_class(&classes, orig)
+          {
+            let exported = match exported {
+              Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+              Some(ModuleExportName::Str(str)) => str.value.to_atom_lossy().into_owned(),
+              None => orig.sym.clone(),
+            };
+            classes[index].exports.push(exported);
+          }
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. })) => {
+        if let Expr::Ident(ident) = &**expr
+          && let Some(index) = find_class(&classes, ident)
+        {
+          classes[index].exports.push(JS_DEFAULT_KEYWORD.clone());
+        }
+      }
+      _ => {}
+    }
+  }
+
+  for index in 0..classes.len() {
+    let Some(super_class) = &classes[index].class.super_class else {
+      continue;
+    };
+    // the members that the super classes outside the module access dynamically are unknown
+    if let Expr::Ident(ident) = &**super_class
+      && let Some(super_index) = find_class(&classes, ident)
+    {
+      classes[index].super_class = Some(super_index);
+    } else {
+      classes[index].bailout = true;
+    }
+  }
+  classes
+}
+
+/// Returns the name and the span of a member that can be removed if it is unused, the
+/// instance properties are kept since they are observable through the instances.
+fn get_removable_member(member: &ClassMember, expr_ctx: ExprCtx) -> Option<(Atom, Span)> {
+  let (key, span) = match member {
+    ClassMember::Method(method)
+      if !method.is_abstract
+        && method.function.body.is_some()
+        && method.function.decorators.is_empty() =>
+    {
+      (&method.key, method.span)
+    }
+    ClassMember::ClassProp(prop)
+      if prop.is_static
+        && !prop.declare
+        && prop.decorators.is_empty()
+        && prop
+          .value
+          .as_ref()
+          .is_none_or(|value| !value.may_have_side_effects(expr_ctx)) =>
+    {
+      (&prop.key, prop.span)
+    }
+    _ => return None,
+  };
+  let name = match key {
+    PropName::Ident(ident) => ident.sym.clone(),
+    PropName::Str(str) => str.value.to_atom_lossy().into_owned(),
+    _ => return None,
+  };
+  if IMPLICITLY_USED_MEMBERS.contains(&name.as_str()) {
+    return None;
+  }
+  Some((name, span))
+}
+
+/// Collects the variables that are initialized with `new Foo()`, the exported variables are
+/// skipped since their instances escape.
+#[derive(Default)]
+struct InstancesCollector {
+  instances: FxHashMap<Id, Vec<Span>>,
+}
+
+impl Visit for InstancesCollector {
+  fn visit_module_decl(&mut self, decl: &ModuleDecl) {
+    if let ModuleDecl::ExportDecl(export) = decl
+      && export.decl.is_var()
+    {
+      return;
+    }
+    decl.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    if let Some(ident) = declarator.name.as_ident()
+      && let Some(init) = &declarator.init
+      && let Expr::New(new_expr) = &**init
+    {
+      self
+        .instances
+        .entry(ident.to_id())
+        .or_default()
+        .push(new_expr.callee.span());
+    }
+    declarator.visit_children_with(self);
+  }
+}
+
+struct ClassMembersVisitor {
+  unresolved_ctxt: SyntaxContext,
+  class_ids: FxHashMap<Id, usize>,
+  bailouts: FxHashSet<usize>,
+  accessed_properties: FxHashSet<Atom>,
+  /// References that only construct or check the class, i.e. `new Foo()` and `x instanceof Foo`
+  opaque_references: FxHashSet<Span>,
+  /// Opaque references of the top-level classes of the module
+  opaque_classes: FxHashMap<Span, usize>,
+  /// Variables that are initialized with `new Foo()`, the values are the spans of `Foo`
+  instances: FxHashMap<Id, Vec<Span>>,
+  /// Spans of the constructors whose instances escape
+  escaped_instances: FxHashSet<Span>,
+  current_classes: Vec<Option<usize>>,
+  has_reflection: bool,
+}
+
+impl ClassMembersVisitor {
+  fn class_index(&self, ident: &Ident) -> Option<usize> {
+    if self.class_ids.is_empty() {
+      return None;
+    }
+    self.class_ids.get(&ident.to_id()).copied()
+  }
+
+  fn bailout_current_class(&mut self) {
+    if let Some(Some(index)) = self.current_classes.last() {
+      self.bailouts.insert(*index);
+    }
+  }
+
+  fn visit_opaque_reference(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Ident(ident) => {
+        self.opaque_references.insert(ident.span);
+        if let Some(index) = self.class_index(ident) {
+          self.opaque_classes.insert(ident.span, index);
+        }
+      }
+      Expr::Member(member) => {
+        self.opaque_references.insert(expr.span());
+        member.visit_with(self);
+      }
+      _ => expr.visit_with(self),
+    }
+  }
+
+  fn visit_new_expr_with_escaped(&mut self, expr: &NewExpr, escaped: bool) {
+    self.visit_opaque_reference(&expr.callee);
+    if escaped {
+      self.escaped_instances.insert(expr.callee.span());
+    }
+    expr.args.visit_with(self);
+  }
+
+  fn visit_class_with_index(&mut self, class: &Class, index: Option<usize>) {
+    class.decorators.visit_with(self);
+    if let Some(super_class) = &class.super_class {
+      // the relation is tracked by `TopLevelClass::super_class`
+      if !matches!(&**super_class, Expr::Ident(ident) if self.class_index(ident).is_some()) {
+        super_class.visit_with(self);
+      }
+    }
+    self.current_classes.push(index);
+    class.body.visit_with(self);
+    self.current_classes.pop();
+  }
+}
+
+impl Visit for ClassMembersVisitor {
+  fn visit_import_decl(&mut self, _decl: &ImportDecl) {}
+
+  fn visit_export_named_specifier(&mut self, specifier: &ExportNamedSpecifier) {
+    match &specifier.orig {
+      ModuleExportName::Ident(ident) if self.class_index(ident).is_some() => {}
+      orig => orig.visit_with(self),
+    }
+  }
+
+  fn visit_export_default_expr(&mut self, export: &ExportDefaultExpr) {
+    if !matches!(&*export.expr, Expr::Ident(ident) if self.class_index(ident).is_some()) {
+      export.expr.visit_with(self);
+    }
+  }
+
+  fn visit_class_decl(&mut self, decl: &ClassDecl) {
+    let index = self.class_index(&decl.ident);
+    self.visit_class_with_index(&decl.class, index);
+  }
+
+  fn visit_class_expr(&mut self, expr: &ClassExpr) {
+    let index = match &expr.ident {
+      Some(ident) => self.class_index(ident),
+      None => None,
+    };
+    self.visit_class_with_index(&expr.class, index);
+  }
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    // the keys of the declarations are not accesses
+    if let PropName::Computed(computed) = name {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_new_expr(&mut self, expr: &NewExpr) {
+    self.visit_new_expr_with_escaped(expr, true);
+  }
+
+  fn visit_bin_expr(&mut self, expr: &BinExpr) {
+    expr.left.visit_with(self);
+    if expr.op == BinaryOp::InstanceOf {
+      self.visit_opaque_reference(&expr.right);
+    } else {
+      expr.right.visit_with(self);
+    }
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    if let Some(ident) = declarator.name.as_ident()
+      && let Some(init) = &declarator.init
+      && let Expr::New(new_expr) = &**init
+      && self
+        .instances
+        .get(&ident.to_id())
+        .is_some_and(|spans| spans.contains(&new_expr.callee.span()))
+    {
+      // the instance escapes only if the variable is referenced
+      self.visit_new_expr_with_escaped(new_expr, false);
+      return;
+    }
+    declarator.visit_children_with(self);
+  }
+
+  fn visit_member_expr(&mut self, expr: &MemberExpr) {
+    let class_index = match &*expr.obj {
+      Expr::Ident(ident) => self.class_index(ident),
+      _ => None,
+    };
+    match &expr.prop {
+      MemberProp::Ident(prop) => {
+        self.accessed_properties.insert(prop.sym.clone());
+        if let Some(index) = class_index {
+          // `Foo.prototype` exposes all the instance members
+          if prop.sym == "prototype" {
+            self.bailouts.insert(index);
+          }
+          return;
+        }
+        if let Expr::Ident(obj) = &*expr.obj
+          && obj.ctxt == self.unresolved_ctxt
+          && obj.sym == "Object"
+          && REFLECTION_METHODS.contains(&prop.sym.as_str())
+        {
+          self.has_reflection = true;
+        }
+      }
+      MemberProp::Computed(computed) => {
+        computed.visit_with(self);
+        // `this[key]`, `foo[key]` and `new Foo()[key]` are visited as the escaping references
+        if !matches!(&*computed.expr, Expr::Lit(Lit::Str(_) | Lit::Num(_))) {
+          expr.obj.visit_with(self);
+          return;
+        }
+      }
+      MemberProp::PrivateName(_) => {
+        if class_index.is_some() {
+          return;
+        }
+      }
+    }
+    // accessing a property by a static key doesn't make the instance escape
+    match &*expr.obj {
+      Expr::This(_) => {}
+      Expr::Ident(ident) if self.instances.contains_key(&ident.to_id()) => {}
+      Expr::New(new_expr) => self.visit_new_expr_with_escaped(new_expr, false),
+      obj => obj.visit_with(self),
+    }
+  }
+
+  fn visit_super_prop_expr(&mut self, expr: &SuperPropExpr) {
+    match &expr.prop {
+      SuperProp::Ident(prop) => {
+        self.accessed_properties.insert(prop.sym.clone());
+      }
+      SuperProp::Computed(computed) => {
+        if !matches!(&*computed.expr, Expr::Lit(Lit::Str(_) | Lit::Num(_))) {
+          self.bailout_current_class();
+        }
+        computed.visit_with(self);
+      }
+    }
+  }
+
+  fn visit_object_pat(&mut self, pat: &ObjectPat) {
+    for prop in &pat.props {
+      match prop {
+        ObjectPatProp::KeyValue(key_value) => match &key_value.key {
+          PropName::Ident(ident) => {
+            self.accessed_properties.insert(ident.sym.clone());
+          }
+          PropName::Str(str) => {
+            self
+              .accessed_properties
+              .insert(str.value.to_atom_lossy().into_owned());
+          }
+          _ => {}
+        },
+        ObjectPatProp::Assign(assign) => {
+          self.accessed_properties.insert(assign.key.sym.clone());
+        }
+        ObjectPatProp::Rest(_) => {}
+      }
+    }
+    pat.visit_children_with(self);
+  }
+
+  fn visit_str(&mut self, str: &Str) {
+    // the members may be accessed by the string literals dynamically, e.g. `obj[name]()`
+    self
+      .accessed_properties
+      .insert(str.value.to_atom_lossy().into_owned());
+  }
+
+  fn visit_tpl(&mut self, tpl: &Tpl) {
+    for quasi in &tpl.quasis {
+      if let Some(cooked) = &quasi.cooked {
+        self
+          .accessed_properties
+          .insert(cooked.to_atom_lossy().into_owned());
+      }
+    }
+    tpl.exprs.visit_with(self);
+  }
+
+  fn visit_this_expr(&mut self, _expr: &ThisExpr) {
+    // the instance escapes, e.g. `return this` or `fn(this)`
+    self.bailout_current_class();
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if let Some(index) = self.class_index(ident) {
+      self.bailouts.insert(index);
+    } else if let Some(spans) = self.instances.get(&ident.to_id()) {
+      self.escaped_instances.extend(spans.iter().copied());
+    } else if ident.ctxt == self.unresolved_ctxt && (ident.sym == "Reflect" || ident.sym == "Proxy")
+    {
+      self.has_reflection = true;
+    }
+  }
+}
@@ -1,15 +1,19 @@
 use rspack_core::{BuildMetaDefaultObject, BuildMetaExportsType, DependencyRange, RuntimeGlobals};
 use rspack_util::SpanExt;
+use rustc_hash::FxHashSet;
 use swc_core::{
   atoms::Atom,
-  common::{Span, Spanned},
-  ecma::ast::{
-    AssignExpr, CallExpr, Expr, ExprOrSpread, Ident, Lit, MemberExpr, ObjectLit, Prop, PropName,
-    PropOrSpread, UnaryExpr, UnaryOp,
+  common::{BytePos, Mark, Span, Spanned, SyntaxContext},
+  ecma::{
+    ast::{
+      AssignExpr, AssignOp, CallExpr, Expr, ExprOrSpread, Ident, KeyValueProp, Lit, MemberExpr,
+      MethodProp, ObjectLit, Prop, PropName, PropOrSpread, ThisExpr, UnaryExpr, UnaryOp,
+    },
+    visit::{Visit, VisitWith},
   },
 };
 
-use super::JavascriptParserPlugin;
+use super::{JavascriptParserPlugin, side_effects_parser_plugin::is_pure_expression};
 use crate::{
   dependency::{
     CommonJsExportRequireDependency, CommonJsExportsDependency, CommonJsSelfReferenceDependency,
@@ -111,6 +115,13 @@ impl JavascriptParser<'_> {
     self.build_meta.exports_type = BuildMetaExportsType::Dynamic;
   }
 
+  /// Records why this CommonJS module can't be concatenated, only the first reason is kept
+  pub(crate) fn bailout_commonjs_concatenation(&mut self, reason: &str) {
+    if !self.is_esm && self.build_info.module_concatenation_bailout.is_none() {
+      self.build_info.module_concatenation_bailout = Some(reason.to_string());
+    }
+  }
+
   fn check_namespace(&mut self, top_level: bool, value_expr: Option<&Expr>) {
     if matches!(self.parser_exports_state, Some(false)) || self.parser_exports_state.is_none() {
       return;
@@ -126,6 +137,16 @@ impl JavascriptParser<'_> {
   }
 }
 
+fn get_exports_base_expression(base: ExportsBase) -> &'static str {
+  if base.is_exports() {
+    "exports"
+  } else if base.is_module_exports() {
+    "module.exports"
+  } else {
+    "this"
+  }
+}
+
 fn parse_require_call<'a>(
   parser: &mut JavascriptParser,
   mut expr: &'a Expr,
@@ -162,15 +183,137 @@ fn parse_require_call<'a>(
   None
 }
 
+#[derive(Default)]
+struct ThisVisitor {
+  has_this: bool,
+}
+
+impl Visit for ThisVisitor {
+  fn visit_this_expr(&mut self, _: &ThisExpr) {
+    self.has_this = true;
+  }
+}
+
+/// Returns the names of the properties if the object literal only has static keys, and its
+/// values don't refer to the object itself by `this`
+fn get_object_literal_export_names(obj: &ObjectLit) -> Option<Vec<Atom>> {
+  let mut names = Vec::with_capacity(obj.props.len());
+  let mut visited = FxHashSet::default();
+  for prop in &obj.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      return None;
+    };
+    let name = match &**prop {
+      Prop::Shorthand(ident) => ident.sym.clone(),
+      Prop::KeyValue(KeyValueProp { key, .. }) | Prop::Method(MethodProp { key, .. }) => {
+        match key {
+          PropName::Ident(ident) => ident.sym.clone(),
+          PropName::Str(str) => str.value.to_atom_lossy().into_owned(),
+          _ => return None,
+        }
+      }
+      _ => return None,
+    };
+    // `__proto__: x` sets the prototype instead of defining a property
+    if name == "__proto__" || !visited.insert(name.clone()) {
+      return None;
+    }
+    names.push(name);
+  }
+  let mut visitor = ThisVisitor::default();
+  obj.visit_with(&mut visitor);
+  (!visitor.has_this).then_some(names)
+}
+
+fn is_anonymous_function_definition(expr: &Expr) -> bool {
+  match expr {
+    Expr::Fn(fn_expr) => fn_expr.ident.is_none(),
+    Expr::Class(class_expr) => class_expr.ident.is_none(),
+    Expr::Arrow(_) => true,
+    Expr::Paren(paren) => is_anonymous_function_definition(&paren.expr),
+    _ => false,
+  }
+}
+
+// module.exports = { a, b: 1, c() {} };
+fn handle_assign_object_literal(
+  parser: &mut JavascriptParser,
+  assign_expr: &AssignExpr,
+  obj: &ObjectLit,
+  names: Vec<Atom>,
+  unresolved_ctxt: SyntaxContext,
+) -> Option<bool> {
+  parser.enable();
+  parser.add_dependency(Box::new(CommonJsExportsDependency::new(
+    assign_expr.left.span().into(),
+    None,
+    ExportsBase::ModuleExports,
+    vec![],
+  )));
+  for (index, (prop, name)) in obj.props.iter().zip(names).enumerate() {
+    let PropOrSpread::Prop(prop) = prop else {
+      unreachable!("should only have properties");
+    };
+    let value = match &**prop {
+      Prop::KeyValue(key_value) => Some(&*key_value.value),
+      _ => None,
+    };
+    // the key can be replaced by the mangled name, unless the name of the property is observable
+    // by the function name inferred from it
+    let key_range = match &**prop {
+      Prop::Shorthand(ident) => Some(Span::new(ident.span.lo, ident.span.lo)),
+      Prop::KeyValue(key_value) if !is_anonymous_function_definition(&key_value.value) => {
+        Some(key_value.key.span())
+      }
+      _ => None,
+    };
+    if name == "__esModule" {
+      parser.check_namespace(true, value);
+    }
+    let dependency_idx = parser.next_dependency_idx();
+    let presentational_dependency_idx = parser.next_presentational_dependency_idx();
+    let block_idx = parser.next_block_idx();
+    parser.walk_property(prop);
+    // the property can't be removed if it contains other dependencies, or its value may have
+    // side effects
+    let removable = dependency_idx == parser.next_dependency_idx()
+      && presentational_dependency_idx == parser.next_presentational_dependency_idx()
+      && block_idx == parser.next_block_idx()
+      && value
+        .is_none_or(|value| is_pure_expression(parser, value, unresolved_ctxt, parser.comments));
+    // remove the property along with the following comma
+    let end = obj
+      .props
+      .get(index + 1)
+      .map_or(obj.span.hi - BytePos(1), |next| next.span().lo);
+    parser.add_dependency(Box::new(
+      CommonJsExportsDependency::new_module_exports_property(
+        Span::new(prop.span().lo, end).into(),
+        removable,
+        key_range.map(Into::into),
+        name,
+      ),
+    ));
+  }
+  Some(true)
+}
+
 fn handle_assign_export(
   parser: &mut JavascriptParser,
   assign_expr: &AssignExpr,
   remaining: &[Atom],
   base: ExportsBase,
+  unresolved_ctxt: SyntaxContext,
 ) -> Option<bool> {
   if parser.is_esm {
     return None;
   }
+  if remaining.is_empty() {
+    parser.bailout_commonjs_concatenation(&format!(
+      "an assignment to `{}`",
+      get_exports_base_expression(base)
+    ));
+  }
   if (remaining.is_empty() || remaining.first().is_some_and(|i| i != "__esModule"))
     && let Some((arg, ids)) = parse_require_call(parser, &assign_expr.right)
     && arg.is_string()
@@ -186,6 +329,7 @@ fn handle_assign_export(
     // exports.aaa = require('xx');
     // module.exports.aaa = require('xx');
     // this.aaa = require('xx');
+    parser.bailout_commonjs_concatenation("re-exports of `require()`");
     let range: DependencyRange = assign_expr.span.into();
     parser.add_dependency(Box::new(CommonJsExportRequireDependency::new(
       arg.string().to_string(),
@@ -200,6 +344,15 @@ fn handle_assign_export(
   }
 
   if remaining.is_empty() {
+    if matches!(base, ExportsBase::ModuleExports)
+      && matches!(assign_expr.op, AssignOp::Assign)
+      && parser.statement_path.len() == 1
+      && parser.is_statement_level_expression(assign_expr.span())
+      && let Some(obj) = assign_expr.right.as_object()
+      && let Some(names) = get_object_literal_export_names(obj)
+    {
+      return handle_assign_object_literal(parser, assign_expr, obj, names, unresolved_ctxt);
+    }
     return None;
   }
 
@@ -243,12 +396,16 @@ fn handle_access_export(
   }
   if remaining.is_empty() {
     parser.bailout();
+    parser.bailout_commonjs_concatenation(&format!(
+      "the `{}` object",
+      get_exports_base_expression(base)
+    ));
   }
   parser.add_dependency(Box::new(CommonJsSelfReferenceDependency::new(
     expr_span.into(),
     base,
     remaining.to_vec(),
-    true,
+    call_args.is_some(),
   )));
   if let Some(call_args) = call_args {
     parser.walk_expr_or_spread(call_args);
@@ -258,11 +415,15 @@ fn handle_access_export(
 
 pub struct CommonJsExportsParserPlugin {
   skip_in_esm: bool,
+  unresolved_ctxt: SyntaxContext,
 }
 
 impl CommonJsExportsParserPlugin {
-  pub fn new(skip_in_esm: bool) -> Self {
-    Self { skip_in_esm }
+  pub fn new(skip_in_esm: bool, unresolved_mark: Mark) -> Self {
+    Self {
+      skip_in_esm,
+      unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+    }
   }
 
   fn should_skip_handler(&self, parser: &JavascriptParser) -> bool {
@@ -284,7 +445,13 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
 
     if for_name == "exports" {
       // exports.x = y;
-      return handle_assign_export(parser, assign_expr, remaining, ExportsBase::Exports);
+      return handle_assign_export(
+        parser,
+        assign_expr,
+        remaining,
+        ExportsBase::Exports,
+        self.unresolved_ctxt,
+      );
     }
     if for_name == "module" && matches!(remaining.first(), Some(first) if first == "exports") {
       // module.exports.x = y;
@@ -293,11 +460,18 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
         assign_expr,
         &remaining[1..],
         ExportsBase::ModuleExports,
+        self.unresolved_ctxt,
       );
     }
     if for_name == "this" && parser.is_top_level_scope() {
       // this.x = y
-      return handle_assign_export(parser, assign_expr, remaining, ExportsBase::This);
+      return handle_assign_export(
+        parser,
+        assign_expr,
+        remaining,
+        ExportsBase::This,
+        self.unresolved_ctxt,
+      );
     }
     None
   }
@@ -352,6 +526,11 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
           get_value_of_property_description(arg2),
         );
       }
+      // only the value of `__esModule` can be read from the descriptor when concatenated,
+      // other properties may be defined with getters
+      if &property != "__esModule" || get_value_of_property_description(arg2).is_none() {
+        parser.bailout_commonjs_concatenation("`Object.defineProperty()` on the exports object");
+      }
       parser.add_dependency(Box::new(CommonJsExportsDependency::new(
         call_expr.span.into(),
         Some(arg2.span().into()),
@@ -383,6 +562,7 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
         RuntimeGlobals::NODE_MODULE_DECORATOR
       };
       parser.bailout();
+      parser.bailout_commonjs_concatenation("the `module` object");
       parser.add_dependency(Box::new(ModuleDecoratorDependency::new(
         decorator,
         !parser.is_esm,
@@ -10,7 +10,9 @@ use super::{
   DEFAULT_STAR_JS_WORD, InnerGraphMapUsage, InnerGraphPlugin, JS_DEFAULT_KEYWORD,
   JavascriptParserPlugin,
   esm_import_dependency_parser_plugin::{ESM_SPECIFIER_TAG, ESMSpecifierData},
-  inline_const::{INLINABLE_CONST_TAG, InlinableConstData},
+  inline_const::{
+    INLINABLE_CONST_OBJECT_TAG, INLINABLE_CONST_TAG, InlinableConstData, InlinableConstObjectData,
+  },
 };
 use crate::{
   dependency::{
@@ -132,8 +134,15 @@ impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
         .build_info
         .collected_typescript_info
         .as_ref()
-        .and_then(|info| info.exported_enums.get(local_id).cloned());
+        .and_then(|info| info.exported_enums.get(local_id).cloned())
+        .or_else(|| {
+          parser
+            .get_tag_data(local_id, INLINABLE_CONST_OBJECT_TAG)
+            .map(InlinableConstObjectData::downcast)
+            .map(|data| data.value)
+        });
       let variable = parser.get_tag_data(local_id, NESTED_IDENTIFIER_TAG);
+      let no_side_effects = parser.no_side_effects_functions.contains_key(local_id);
 
       Box::new(ESMExportSpecifierDependency::new(
         export_name.clone(),
@@ -145,6 +154,7 @@ impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
         },
         inlinable,
         enum_value,
+        no_side_effects,
         statement.span().into(),
         Some(parser.source_rope().clone()),
       ))
@@ -1,5 +1,5 @@
 use rspack_core::{
-  ConstDependency, DependencyType, ExportPresenceMode, ImportAttributes, ImportPhase,
+  ConstDependency, Dependency, DependencyType, ExportPresenceMode, ImportAttributes, ImportPhase,
 };
 use swc_core::{
   atoms::Atom,
@@ -200,7 +200,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       .destructuring_assignment_properties
       .get(&ident.span())
       .cloned();
-    let dep = ESMImportSpecifierDependency::new(
+    let mut dep = ESMImportSpecifierDependency::new(
       settings.source,
       settings.name,
       settings.source_order,
@@ -216,6 +216,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       settings.attributes,
       Some(parser.source_rope().clone()),
     );
+    dep.opaque_class_reference = parser.opaque_class_references.contains(&ident.span);
     let dep_idx = parser.next_dependency_idx();
     parser.add_dependency(Box::new(dep));
 
@@ -260,7 +261,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
     let mut ids = settings.ids;
     ids.extend(non_optional_members.iter().cloned());
     let direct_import = members.is_empty();
-    let dep = ESMImportSpecifierDependency::new(
+    let mut dep = ESMImportSpecifierDependency::new(
       settings.source,
       settings.name,
       settings.source_order,
@@ -278,6 +279,13 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       settings.attributes,
       Some(parser.source_rope().clone()),
     );
+    dep.no_side_effects_call = direct_import && parser.no_side_effects_callees.contains(&span);
+    if dep.no_side_effects_call {
+      parser
+        .inner_graph
+        .no_side_effects_callee_dependencies
+        .insert(span, *dep.id());
+    }
     let dep_idx = parser.next_dependency_idx();
     parser.add_dependency(Box::new(dep));
 
@@ -323,7 +331,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       .destructuring_assignment_properties
       .get(&member_expr.span())
       .cloned();
-    let dep = ESMImportSpecifierDependency::new(
+    let mut dep = ESMImportSpecifierDependency::new(
       settings.source,
       settings.name,
       settings.source_order,
@@ -339,6 +347,10 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       settings.attributes,
       Some(parser.source_rope().clone()),
     );
+    dep.opaque_class_reference = parser.opaque_class_references.contains(&member_expr.span());
+    dep.mutated = parser
+      .mutated_member_expressions
+      .contains(&member_expr.span());
     let dep_idx = parser.next_dependency_idx();
     parser.add_dependency(Box::new(dep));
 
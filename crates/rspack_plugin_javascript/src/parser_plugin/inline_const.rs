@@ -1,8 +1,19 @@
-use rspack_core::EvaluatedInlinableValue;
+use rspack_core::{EvaluatedInlinableValue, TSEnumValue};
 use rspack_util::ryu_js;
-use swc_core::ecma::ast::{ModuleDecl, ModuleItem, Program, VarDeclarator};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+  atoms::Atom,
+  common::{Mark, SyntaxContext},
+  ecma::{
+    ast::{
+      Decl, ExportNamedSpecifier, Expr, Ident, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit,
+      Program, Prop, PropName, PropOrSpread, Stmt, VarDeclKind, VarDeclarator,
+    },
+    visit::{Visit, VisitWith},
+  },
+};
 
-use super::JavascriptParserPlugin;
+use super::{JavascriptParserPlugin, side_effects_parser_plugin::is_object_freeze_callee};
 use crate::{
   utils::eval::{
     BasicEvaluatedExpression, evaluate_to_boolean, evaluate_to_null, evaluate_to_number,
@@ -15,14 +26,31 @@ use crate::{
 };
 
 pub const INLINABLE_CONST_TAG: &str = "inlinable const";
+pub const INLINABLE_CONST_OBJECT_TAG: &str = "inlinable const object";
 
 #[derive(Debug, Clone)]
 pub struct InlinableConstData {
   pub value: EvaluatedInlinableValue,
 }
 
-#[derive(Default)]
-pub struct InlineConstPlugin;
+/// The properties of a const object literal, they are exported in the same way as
+/// the members of a typescript enum, so the property accesses can be inlined
+#[derive(Debug, Clone)]
+pub struct InlinableConstObjectData {
+  pub value: TSEnumValue,
+}
+
+pub struct InlineConstPlugin {
+  unresolved_ctxt: SyntaxContext,
+}
+
+impl InlineConstPlugin {
+  pub fn new(unresolved_mark: Mark) -> Self {
+    Self {
+      unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+    }
+  }
+}
 
 impl JavascriptParserPlugin for InlineConstPlugin {
   fn program(&self, parser: &mut JavascriptParser, program: &Program) -> Option<bool> {
@@ -43,6 +71,9 @@ impl JavascriptParserPlugin for InlineConstPlugin {
           ModuleItem::Stmt(_) => continue,
         }
       }
+      if parser.has_inlinable_const_decls {
+        parser.referenced_const_objects = collect_referenced_const_objects(&module.body);
+      }
     }
 
     None
@@ -94,12 +125,149 @@ impl JavascriptParserPlugin for InlineConstPlugin {
           Some(InlinableConstData { value: inlinable }),
           VariableInfoFlags::NORMAL,
         );
+      } else if let Some(object) =
+        get_inlinable_object(parser, init, &name.id.sym, self.unresolved_ctxt)
+        && let Some(value) = to_inlinable_object_value(parser, object)
+      {
+        parser.tag_variable_with_flags(
+          name.id.sym.clone(),
+          INLINABLE_CONST_OBJECT_TAG,
+          Some(InlinableConstObjectData { value }),
+          VariableInfoFlags::NORMAL,
+        );
       }
     }
     None
   }
 }
 
+/// Get the object literal of `const obj = { ... }` or `const obj = Object.freeze({ ... })`.
+///
+/// The properties of a non-frozen object may be modified, so it is only inlinable when
+/// it is not referenced in its own module. The modifications in other modules are found by
+/// the `ESMImportSpecifierDependency`s of the mutated member expressions, which disable
+/// inlining of the modified properties, and the object referenced as a whole value in other
+/// modules disables inlining of all its properties when flagging the usage.
+fn get_inlinable_object<'a>(
+  parser: &JavascriptParser,
+  init: &'a Expr,
+  name: &Atom,
+  unresolved_ctxt: SyntaxContext,
+) -> Option<&'a ObjectLit> {
+  match init {
+    Expr::Object(object) if !parser.referenced_const_objects.contains(name) => Some(object),
+    Expr::Call(call) => {
+      let [arg] = call.args.as_slice() else {
+        return None;
+      };
+      if arg.spread.is_none() && is_object_freeze_callee(&call.callee, unresolved_ctxt) {
+        arg.expr.as_object()
+      } else {
+        None
+      }
+    }
+    _ => None,
+  }
+}
+
+fn to_inlinable_object_value(
+  parser: &mut JavascriptParser,
+  object: &ObjectLit,
+) -> Option<TSEnumValue> {
+  let mut value = FxHashMap::default();
+  for prop in &object.props {
+    // spread properties make the object has unknown properties
+    let PropOrSpread::Prop(prop) = prop else {
+      return None;
+    };
+    let (key, inlinable) = match &**prop {
+      Prop::KeyValue(kv) => {
+        let key = match &kv.key {
+          PropName::Ident(ident) => ident.sym.clone(),
+          PropName::Str(str) => str.value.to_atom_lossy().into_owned(),
+          _ => return None,
+        };
+        let evaluated = parser.evaluate_expression(&kv.value);
+        (key, to_evaluated_inlinable_value(&evaluated))
+      }
+      Prop::Shorthand(ident) => {
+        let expr = Expr::Ident(ident.clone());
+        let evaluated = parser.evaluate_expression(&expr);
+        (ident.sym.clone(), to_evaluated_inlinable_value(&evaluated))
+      }
+      _ => return None,
+    };
+    value.insert(key, inlinable);
+  }
+  Some(TSEnumValue::new(value))
+}
+
+/// Collect the top-level const objects that are referenced in the module, the export
+/// specifiers and the declarations are not counted as references.
+fn collect_referenced_const_objects(body: &[ModuleItem]) -> FxHashSet<Atom> {
+  let mut const_objects = FxHashSet::default();
+  for item in body {
+    let var = match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => var,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+        let Decl::Var(var) = &export_decl.decl else {
+          continue;
+        };
+        var
+      }
+      _ => continue,
+    };
+    if var.kind != VarDeclKind::Const {
+      continue;
+    }
+    for decl in &var.decls {
+      if let Some(name) = decl.name.as_ident()
+        && decl.init.as_ref().is_some_and(|init| init.is_object())
+      {
+        const_objects.insert(name.id.sym.clone());
+      }
+    }
+  }
+  if const_objects.is_empty() {
+    return const_objects;
+  }
+
+  let mut visitor = ConstObjectReferenceVisitor {
+    const_objects: &const_objects,
+    referenced: Default::default(),
+  };
+  body.visit_with(&mut visitor);
+  visitor.referenced
+}
+
+struct ConstObjectReferenceVisitor<'a> {
+  const_objects: &'a FxHashSet<Atom>,
+  referenced: FxHashSet<Atom>,
+}
+
+impl Visit for ConstObjectReferenceVisitor<'_> {
+  fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
+    // the references of shadowed declarations in nested scopes are counted as well,
+    // that's fine since it only makes the object not inlinable
+    if decl.name.as_ident().is_none() {
+      decl.name.visit_with(self);
+    }
+    decl.init.visit_with(self);
+  }
+
+  fn visit_export_named_specifier(&mut self, specifier: &ExportNamedSpecifier) {
+    if !matches!(specifier.orig, ModuleExportName::Ident(_)) {
+      specifier.orig.visit_with(self);
+    }
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if self.const_objects.contains(&ident.sym) {
+      self.referenced.insert(ident.sym.clone());
+    }
+  }
+}
+
 fn to_evaluated_inlinable_value(
   evaluated: &BasicEvaluatedExpression,
 ) -> Option<EvaluatedInlinableValue> {
@@ -17,7 +17,8 @@ use crate::{
   dependency::PureExpressionDependency,
   parser_plugin::{DEFAULT_STAR_JS_WORD, JavascriptParserPlugin},
   side_effects_parser_plugin::{
-    is_pure_class, is_pure_class_member, is_pure_expression, is_pure_function,
+    imported_no_side_effects_callee, is_pure_class, is_pure_class_member, is_pure_expression,
+    is_pure_function,
   },
   visitors::{
     ExportedVariableInfo, JavascriptParser, Statement, TagInfoData, VariableDeclaration,
@@ -276,6 +277,19 @@ impl InnerGraphPlugin {
           if !matches!(used_by_exports, UsedByExports::Bool(true)) {
             let mut dep = PureExpressionDependency::new(range, *parser.module_identifier);
             dep.set_used_by_exports(Some(used_by_exports));
+            dep.set_no_side_effects_callee(
+              parser
+                .inner_graph
+                .no_side_effects_calls
+                .get(&range)
+                .and_then(|callee| {
+                  parser
+                    .inner_graph
+                    .no_side_effects_callee_dependencies
+                    .get(callee)
+                })
+                .copied(),
+            );
             parser.add_dependency(Box::new(dep));
           }
         }
@@ -322,6 +336,21 @@ impl InnerGraphPlugin {
     // When inner graph is disabled, we skip adding PureExpressionDependency (same as None)
   }
 
+  /// Calls of the imported functions annotated with `#__NO_SIDE_EFFECTS__` are pure, which is
+  /// checked by the `PureExpressionDependency` once the exports of the imported module are flagged.
+  fn is_imported_no_side_effects_call(&self, parser: &mut JavascriptParser, expr: &Expr) -> bool {
+    let Some(callee) =
+      imported_no_side_effects_callee(parser, expr, self.unresolved_context, parser.comments)
+    else {
+      return false;
+    };
+    parser
+      .inner_graph
+      .no_side_effects_calls
+      .insert(expr.span().into(), callee);
+    true
+  }
+
   pub fn tag_top_level_symbol(
     parser: &mut crate::visitors::JavascriptParser,
     name: &Atom,
@@ -476,7 +505,8 @@ impl JavascriptParserPlugin for InnerGraphPlugin {
     // https://github.com/estree/estree/blob/master/es2015.md#exportdefaultdeclaration
     // but SWC using ExportDefaultExpr to represent `export default 1`
     if let ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) = export_decl
-      && is_pure_expression(parser, expr, self.unresolved_context, parser.comments)
+      && (is_pure_expression(parser, expr, self.unresolved_context, parser.comments)
+        || self.is_imported_no_side_effects_call(parser, expr))
     {
       let export_part = &**expr;
       let variable = Self::tag_top_level_symbol(parser, &DEFAULT_STAR_JS_WORD);
@@ -526,7 +556,9 @@ impl JavascriptParserPlugin for InnerGraphPlugin {
           .inner_graph
           .class_with_top_level_symbol
           .insert(init.span(), v);
-      } else if is_pure_expression(parser, init, self.unresolved_context, parser.comments) {
+      } else if is_pure_expression(parser, init, self.unresolved_context, parser.comments)
+        || self.is_imported_no_side_effects_call(parser, init)
+      {
         let v = Self::tag_top_level_symbol(parser, name);
         parser
           .inner_graph
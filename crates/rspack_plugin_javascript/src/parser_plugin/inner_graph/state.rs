@@ -1,6 +1,6 @@
 use std::collections::hash_map::Entry;
 
-use rspack_core::DependencyRange;
+use rspack_core::{DependencyId, DependencyRange};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::common::Span;
 
@@ -32,6 +32,10 @@ pub struct InnerGraphState {
   pub(crate) class_with_top_level_symbol: HashMap<Span, TopLevelSymbol>,
   pub(crate) decl_with_top_level_symbol: HashMap<Span, TopLevelSymbol>,
   pub(crate) pure_declarators: HashSet<Span>,
+  /// The pure parts calling an imported function, map to the span of the callee
+  pub(crate) no_side_effects_calls: HashMap<DependencyRange, Span>,
+  /// The `ESMImportSpecifierDependency` created for the callee of `no_side_effects_calls`
+  pub(crate) no_side_effects_callee_dependencies: HashMap<Span, DependencyId>,
 }
 
 impl InnerGraphState {
@@ -1,6 +1,7 @@
 mod amd;
 mod api_plugin;
 mod check_var_decl;
+mod class_members_plugin;
 mod common_js_exports_parse_plugin;
 mod common_js_imports_parse_plugin;
 mod common_js_plugin;
@@ -41,6 +42,7 @@ pub(crate) use self::{
   },
   api_plugin::APIPlugin,
   check_var_decl::CheckVarDeclaratorIdent,
+  class_members_plugin::ClassMembersParserPlugin,
   common_js_exports_parse_plugin::CommonJsExportsParserPlugin,
   common_js_imports_parse_plugin::CommonJsImportsParserPlugin,
   common_js_plugin::CommonJsPlugin,
@@ -3,17 +3,19 @@ use std::sync::LazyLock;
 use rspack_core::SideEffectsBailoutItemWithSpan;
 use swc_core::{
   common::{
-    Mark, Spanned, SyntaxContext,
+    BytePos, Mark, Span, Spanned, SyntaxContext,
     comments::{CommentKind, Comments},
   },
   ecma::{
     ast::{
-      Class, ClassMember, Decl, Expr, Function, ModuleDecl, Pat, PropName, VarDecl, VarDeclOrExpr,
+      Callee, Class, ClassMember, Decl, Expr, Function, ModuleDecl, ModuleItem, Pat, Program,
+      PropName, Stmt, VarDecl, VarDeclOrExpr,
     },
     utils::{ExprCtx, ExprExt},
   },
 };
 
+use super::esm_import_dependency_parser_plugin::ESM_SPECIFIER_TAG;
 use crate::{
   ClassExt, JavascriptParserPlugin,
   visitors::{JavascriptParser, Statement, VariableDeclaration},
@@ -22,6 +24,10 @@ use crate::{
 static PURE_COMMENTS: LazyLock<regex::Regex> =
   LazyLock::new(|| regex::Regex::new("^\\s*(#|@)__PURE__\\s*$").expect("Should create the regex"));
 
+static NO_SIDE_EFFECTS_COMMENTS: LazyLock<regex::Regex> = LazyLock::new(|| {
+  regex::Regex::new("^\\s*(#|@)__NO_SIDE_EFFECTS__\\s*$").expect("Should create the regex")
+});
+
 pub struct SideEffectsParserPlugin {
   unresolve_ctxt: SyntaxContext,
 }
@@ -35,10 +41,64 @@ impl SideEffectsParserPlugin {
 }
 
 impl JavascriptParserPlugin for SideEffectsParserPlugin {
+  fn program(&self, parser: &mut JavascriptParser, ast: &Program) -> Option<bool> {
+    // collect the annotated functions before walking, function declarations are hoisted
+    // so the calls may appear before the declarations
+    let comments = parser.comments;
+    let mut functions = vec![];
+    let mut collect = |stmt_lo, decl: &Decl| match decl {
+      Decl::Fn(f) => {
+        if has_no_side_effects_comment(comments, stmt_lo)
+          || has_no_side_effects_comment(comments, f.function.span.lo)
+        {
+          functions.push((f.ident.sym.clone(), f.ident.ctxt));
+        }
+      }
+      Decl::Var(var) => {
+        let annotated = has_no_side_effects_comment(comments, stmt_lo)
+          || has_no_side_effects_comment(comments, var.span.lo);
+        for decl in &var.decls {
+          let (Some(ident), Some(init)) = (decl.name.as_ident(), &decl.init) else {
+            continue;
+          };
+          if matches!(&**init, Expr::Arrow(_) | Expr::Fn(_))
+            && ((annotated && var.decls.len() == 1)
+              || has_no_side_effects_comment(comments, init.span().lo))
+          {
+            functions.push((ident.sym.clone(), ident.ctxt));
+          }
+        }
+      }
+      _ => {}
+    };
+    match ast {
+      Program::Module(module) => {
+        for item in &module.body {
+          match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+              collect(export_decl.span.lo, &export_decl.decl)
+            }
+            ModuleItem::Stmt(Stmt::Decl(decl)) => collect(decl.span().lo, decl),
+            _ => {}
+          }
+        }
+      }
+      Program::Script(script) => {
+        for stmt in &script.body {
+          if let Stmt::Decl(decl) = stmt {
+            collect(decl.span().lo, decl);
+          }
+        }
+      }
+    }
+    parser.no_side_effects_functions.extend(functions);
+    None
+  }
+
   fn module_declaration(&self, parser: &mut JavascriptParser, decl: &ModuleDecl) -> Option<bool> {
     match decl {
       ModuleDecl::ExportDefaultExpr(expr) => {
-        if !is_pure_expression(parser, &expr.expr, self.unresolve_ctxt, parser.comments) {
+        if !self.is_pure_or_imported_no_side_effects_call(parser, &expr.expr) {
           parser.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
             expr.span,
             String::from("ExportDefaultExpr"),
@@ -46,7 +106,12 @@ impl JavascriptParserPlugin for SideEffectsParserPlugin {
         }
       }
       ModuleDecl::ExportDecl(decl) => {
-        if !is_pure_decl(parser, &decl.decl, self.unresolve_ctxt, parser.comments) {
+        let pure = if let Decl::Var(var) = &decl.decl {
+          self.is_pure_top_level_var_decl(parser, var)
+        } else {
+          is_pure_decl(parser, &decl.decl, self.unresolve_ctxt, parser.comments)
+        };
+        if !pure {
           parser.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
             decl.decl.span(),
             String::from("Decl"),
@@ -66,6 +131,43 @@ impl JavascriptParserPlugin for SideEffectsParserPlugin {
   }
 }
 
+fn has_no_side_effects_comment(comments: Option<&dyn Comments>, pos: BytePos) -> bool {
+  comments
+    .and_then(|comments| comments.get_leading(pos))
+    .is_some_and(|comment_list| {
+      comment_list.iter().any(|comment| {
+        comment.kind == CommentKind::Block && NO_SIDE_EFFECTS_COMMENTS.is_match(&comment.text)
+      })
+    })
+}
+
+fn is_no_side_effects_callee(parser: &JavascriptParser, callee: &Callee) -> bool {
+  if let Callee::Expr(callee) = callee
+    && let Expr::Ident(ident) = &**callee
+  {
+    parser
+      .no_side_effects_functions
+      .get(&ident.sym)
+      .is_some_and(|ctxt| *ctxt == ident.ctxt)
+  } else {
+    false
+  }
+}
+
+/// `Object.freeze(...)` has no side effects other than freezing its argument, which is only
+/// observable through the returned value.
+pub(crate) fn is_object_freeze_callee(callee: &Callee, unresolved_ctxt: SyntaxContext) -> bool {
+  if let Callee::Expr(callee) = callee
+    && let Expr::Member(member) = &**callee
+    && let Expr::Ident(obj) = &*member.obj
+    && let Some(prop) = member.prop.as_ident()
+  {
+    obj.sym == "Object" && obj.ctxt == unresolved_ctxt && prop.sym == "freeze"
+  } else {
+    false
+  }
+}
+
 fn is_pure_call_expr(
   parser: &mut JavascriptParser,
   expr: &Expr,
@@ -86,7 +188,10 @@ fn is_pure_call_expr(
       None
     })
     .unwrap_or(false);
-  if !pure_flag {
+  if !pure_flag
+    && !is_no_side_effects_callee(parser, callee)
+    && !is_object_freeze_callee(callee, unresolved_ctxt)
+  {
     !expr.may_have_side_effects(ExprCtx {
       unresolved_ctxt,
       in_strict: false,
@@ -104,7 +209,57 @@ fn is_pure_call_expr(
   }
 }
 
+/// Whether the imported function is annotated with `#__NO_SIDE_EFFECTS__` is unknown until
+/// the exports of the imported module are flagged, so the callee is recorded and the
+/// `ESMImportSpecifierDependency` created for it decides the side effects of the call.
+///
+/// Returns the span of the recorded callee.
+pub(crate) fn imported_no_side_effects_callee(
+  parser: &mut JavascriptParser,
+  expr: &Expr,
+  unresolved_ctxt: SyntaxContext,
+  comments: Option<&dyn Comments>,
+) -> Option<Span> {
+  let Expr::Call(call_expr) = expr else {
+    return None;
+  };
+  let Callee::Expr(callee) = &call_expr.callee else {
+    return None;
+  };
+  let Expr::Ident(ident) = &**callee else {
+    return None;
+  };
+  parser.get_tag_data(&ident.sym, ESM_SPECIFIER_TAG)?;
+  let pure_args = call_expr.args.iter().all(|arg| {
+    arg.spread.is_none() && is_pure_expression(parser, &arg.expr, unresolved_ctxt, comments)
+  });
+  if !pure_args {
+    return None;
+  }
+  parser.no_side_effects_callees.insert(ident.span);
+  Some(ident.span)
+}
+
 impl SideEffectsParserPlugin {
+  fn is_pure_or_imported_no_side_effects_call(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &Expr,
+  ) -> bool {
+    is_pure_expression(parser, expr, self.unresolve_ctxt, parser.comments)
+      || imported_no_side_effects_callee(parser, expr, self.unresolve_ctxt, parser.comments)
+        .is_some()
+  }
+
+  fn is_pure_top_level_var_decl(&self, parser: &mut JavascriptParser, var: &VarDecl) -> bool {
+    var.decls.iter().all(|decl| {
+      decl
+        .init
+        .as_ref()
+        .is_none_or(|init| self.is_pure_or_imported_no_side_effects_call(parser, init))
+    })
+  }
+
   fn analyze_stmt_side_effects(&self, stmt: &Statement, parser: &mut JavascriptParser) {
     if parser.side_effects_item.is_some() {
       return;
@@ -191,12 +346,7 @@ impl SideEffectsParserPlugin {
         }
       }
       Statement::Expr(expr_stmt) => {
-        if !is_pure_expression(
-          parser,
-          &expr_stmt.expr,
-          self.unresolve_ctxt,
-          parser.comments,
-        ) {
+        if !self.is_pure_or_imported_no_side_effects_call(parser, &expr_stmt.expr) {
           parser.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
             expr_stmt.span(),
             String::from("Statement"),
@@ -231,7 +381,7 @@ impl SideEffectsParserPlugin {
       }
       Statement::Var(var_stmt) => match var_stmt {
         VariableDeclaration::VarDecl(var_decl) => {
-          if !is_pure_var_decl(parser, var_decl, self.unresolve_ctxt, parser.comments) {
+          if !self.is_pure_top_level_var_decl(parser, var_decl) {
             parser.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
               var_stmt.span(),
               String::from("Statement"),
@@ -412,6 +412,7 @@ struct ParsedExportSpec<'a> {
   priority: Option<u8>,
   hidden: bool,
   inlinable: Option<&'a EvaluatedInlinableValue>,
+  no_side_effects: bool,
 }
 
 impl<'a> ParsedExportSpec<'a> {
@@ -430,6 +431,7 @@ impl<'a> ParsedExportSpec<'a> {
         priority: global_export_info.priority,
         hidden: false,
         inlinable: None,
+        no_side_effects: false,
       },
       ExportNameOrSpec::ExportSpec(spec) => Self {
         name: &spec.name,
@@ -443,6 +445,7 @@ impl<'a> ParsedExportSpec<'a> {
         priority: spec.priority.or(global_export_info.priority),
         hidden: spec.hidden.unwrap_or(false),
         inlinable: spec.inlinable.as_ref(),
+        no_side_effects: spec.no_side_effects,
       },
     }
   }
@@ -472,11 +475,18 @@ pub fn merge_exports_without_nested(
       priority,
       hidden,
       inlinable,
+      no_side_effects,
       ..
     } = ParsedExportSpec::new(export_name_or_spec, &global_export_info);
 
     let export_info = exports_info.ensure_owned_export_info(name);
-    changed |= set_export_base_info(export_info, can_mangle, terminal_binding, inlinable);
+    changed |= set_export_base_info(
+      export_info,
+      can_mangle,
+      terminal_binding,
+      inlinable,
+      no_side_effects,
+    );
 
     changed |= set_export_target(
       export_info,
@@ -524,6 +534,7 @@ pub fn merge_exports(
       priority,
       hidden,
       inlinable,
+      no_side_effects,
     } = ParsedExportSpec::new(export_name_or_spec, &global_export_info);
 
     let export_info = exports_info.as_data_mut(mg).ensure_export_info(name);
@@ -532,6 +543,7 @@ pub fn merge_exports(
       can_mangle,
       terminal_binding,
       inlinable,
+      no_side_effects,
     );
 
     if let Some(exports) = exports {
@@ -578,6 +590,7 @@ fn set_export_base_info(
   can_mangle: Option<bool>,
   terminal_binding: bool,
   inlinable: Option<&EvaluatedInlinableValue>,
+  no_side_effects: bool,
 ) -> bool {
   let mut changed = false;
   if let Some(provided) = export_info.provided()
@@ -602,6 +615,11 @@ fn set_export_base_info(
     changed = true;
   }
 
+  if no_side_effects && !export_info.no_side_effects() {
+    export_info.set_no_side_effects(true);
+    changed = true;
+  }
+
   if terminal_binding && !export_info.terminal_binding() {
     export_info.set_terminal_binding(true);
     changed = true;
@@ -367,6 +367,15 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
           let flag = mgm_exports_info
             .as_data_mut(module_graph)
             .set_used_in_unknown_way(runtime.as_ref());
+          let nested_exports_info = mgm_exports_info
+            .as_data(module_graph)
+            .exports()
+            .values()
+            .filter_map(|export_info| export_info.exports_info())
+            .collect::<Vec<_>>();
+          for nested_exports_info in nested_exports_info {
+            disable_nested_inlining(module_graph, nested_exports_info);
+          }
 
           if flag {
             queue.push((
@@ -425,11 +434,15 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
               continue;
             }
 
+            let nested_exports_info = export_info.exports_info();
             let changed_flag = export_info.set_used_conditionally(
               Box::new(|v| v != &UsageState::Used),
               UsageState::Used,
               runtime.as_ref(),
             );
+            if let Some(nested_exports_info) = nested_exports_info {
+              disable_nested_inlining(module_graph, nested_exports_info);
+            }
             if changed_flag {
               let current_module = if current_exports_info == mgm_exports_info {
                 Some(module_id)
@@ -675,6 +688,19 @@ fn get_dependency_referenced_exports(
   }
 }
 
+/// The properties of an export referenced as a whole value may be modified through the
+/// reference, e.g. `Object.assign(obj, ...)` or `const alias = obj; alias.a = 1`, so they
+/// can't be inlined.
+fn disable_nested_inlining(module_graph: &mut ModuleGraph, exports_info: ExportsInfo) {
+  for export_info in exports_info
+    .as_data_mut(module_graph)
+    .exports_mut()
+    .values_mut()
+  {
+    export_info.set_can_inline_use(Some(CanInlineUse::No));
+  }
+}
+
 fn process_referenced_module_without_nested(
   module_id: ModuleIdentifier,
   is_exports_type_unset: bool,
@@ -18,22 +18,23 @@ use rustc_hash::FxHashMap;
 use crate::{
   JsPlugin, JsPluginInner,
   dependency::{
-    AMDRequireContextDependencyTemplate, CommonJsExportRequireDependencyTemplate,
-    CommonJsExportsDependencyTemplate, CommonJsFullRequireDependencyTemplate,
-    CommonJsRequireContextDependencyTemplate, CommonJsRequireDependencyTemplate,
-    CommonJsSelfReferenceDependencyTemplate, CreateScriptUrlDependencyTemplate,
-    ESMAcceptDependencyTemplate, ESMCompatibilityDependencyTemplate,
-    ESMExportExpressionDependencyTemplate, ESMExportHeaderDependencyTemplate,
-    ESMExportImportedSpecifierDependencyTemplate, ESMExportSpecifierDependencyTemplate,
-    ESMImportSideEffectDependencyTemplate, ESMImportSpecifierDependencyTemplate,
-    ExportInfoDependencyTemplate, ExternalModuleDependencyTemplate,
-    ImportContextDependencyTemplate, ImportDependencyTemplate, ImportEagerDependencyTemplate,
-    ImportMetaContextDependencyTemplate, ImportMetaHotAcceptDependencyTemplate,
-    ImportMetaHotDeclineDependencyTemplate, ImportMetaResolveDependencyTemplate,
-    ImportMetaResolveHeaderDependencyTemplate, IsIncludedDependencyTemplate,
-    ModuleArgumentDependencyTemplate, ModuleDecoratorDependencyTemplate,
-    ModuleHotAcceptDependencyTemplate, ModuleHotDeclineDependencyTemplate,
-    ProvideDependencyTemplate, PureExpressionDependencyTemplate, RequireContextDependencyTemplate,
+    AMDRequireContextDependencyTemplate, ClassMemberDependencyTemplate,
+    CommonJsExportRequireDependencyTemplate, CommonJsExportsDependencyTemplate,
+    CommonJsFullRequireDependencyTemplate, CommonJsRequireContextDependencyTemplate,
+    CommonJsRequireDependencyTemplate, CommonJsSelfReferenceDependencyTemplate,
+    CreateScriptUrlDependencyTemplate, ESMAcceptDependencyTemplate,
+    ESMCompatibilityDependencyTemplate, ESMExportExpressionDependencyTemplate,
+    ESMExportHeaderDependencyTemplate, ESMExportImportedSpecifierDependencyTemplate,
+    ESMExportSpecifierDependencyTemplate, ESMImportSideEffectDependencyTemplate,
+    ESMImportSpecifierDependencyTemplate, ExportInfoDependencyTemplate,
+    ExternalModuleDependencyTemplate, ImportContextDependencyTemplate, ImportDependencyTemplate,
+    ImportEagerDependencyTemplate, ImportMetaContextDependencyTemplate,
+    ImportMetaHotAcceptDependencyTemplate, ImportMetaHotDeclineDependencyTemplate,
+    ImportMetaResolveDependencyTemplate, ImportMetaResolveHeaderDependencyTemplate,
+    IsIncludedDependencyTemplate, ModuleArgumentDependencyTemplate,
+    ModuleDecoratorDependencyTemplate, ModuleHotAcceptDependencyTemplate,
+    ModuleHotDeclineDependencyTemplate, ProvideDependencyTemplate,
+    PureExpressionDependencyTemplate, RequireContextDependencyTemplate,
     RequireEnsureDependencyTemplate, RequireHeaderDependencyTemplate,
     RequireResolveContextDependencyTemplate, RequireResolveDependencyTemplate,
     RequireResolveHeaderDependencyTemplate, URLContextDependencyTemplate, URLDependencyTemplate,
@@ -382,6 +383,10 @@ async fn compilation(
     PureExpressionDependencyTemplate::template_type(),
     Arc::new(PureExpressionDependencyTemplate::default()),
   );
+  compilation.set_dependency_template(
+    ClassMemberDependencyTemplate::template_type(),
+    Arc::new(ClassMemberDependencyTemplate::default()),
+  );
   // core plugins
   compilation.set_dependency_template(
     CachedConstDependencyTemplate::template_type(),
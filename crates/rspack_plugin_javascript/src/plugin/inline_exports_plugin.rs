@@ -1,18 +1,25 @@
+use std::hash::Hasher;
+
 use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
-  Compilation, CompilationOptimizeDependencies, Dependency, DependencyId, ExportMode,
-  ExportProvided, ExportsInfo, ExportsInfoGetter, GetUsedNameParam, ModuleGraph,
-  ModuleGraphConnection, ModuleIdentifier, Plugin, PrefetchExportsInfoMode, RuntimeSpec,
-  SideEffectsOptimizeArtifact, UsageState, UsedName, UsedNameItem,
-  build_module_graph::BuildModuleGraphArtifact, incremental::IncrementalPasses,
+  Compilation, CompilationOptimizeDependencies, Dependency, DependencyId, ExportInfo, ExportMode,
+  ExportProvided, ExportsInfo, ExportsInfoGetter, GetUsedNameParam, InlineExportsArtifact,
+  InlineExportsEntry, Logger, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, Plugin,
+  PrefetchExportsInfoMode, RuntimeSpec, SideEffectsOptimizeArtifact, UsageState, UsedName,
+  UsedNameItem,
+  build_module_graph::BuildModuleGraphArtifact,
+  incremental::{self, IncrementalPasses},
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::atom::Atom;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
-use crate::dependency::{ESMExportImportedSpecifierDependency, ESMImportSpecifierDependency};
+use crate::{
+  dependency::{ESMExportImportedSpecifierDependency, ESMImportSpecifierDependency},
+  utils::exports_used_names::{collect_used_names, get_export_info_by_path, hash_exports_info},
+};
 
 fn inline_enabled(dependency_id: &DependencyId, mg: &ModuleGraph) -> bool {
   let module = mg
@@ -112,14 +119,64 @@ async fn optimize_dependencies(
   let mg = build_module_graph_artifact.get_module_graph_mut();
   let modules = mg.modules();
 
-  let mut visited: FxHashSet<ExportsInfo> = FxHashSet::default();
+  let affected_modules = compilation
+    .incremental
+    .mutations_read(IncrementalPasses::INLINE_EXPORTS)
+    .map(|mutations| {
+      let modules = mutations.get_affected_modules_with_module_graph(mg);
+      tracing::debug!(target: incremental::TRACING_TARGET, passes = %IncrementalPasses::INLINE_EXPORTS, %mutations, ?modules);
+      modules
+    });
+  let save_inlined = compilation
+    .incremental
+    .passes_enabled(IncrementalPasses::INLINE_EXPORTS);
+  let previous_artifact = std::mem::take(&mut *compilation.inline_exports_artifact.borrow_mut());
 
-  let mut q = modules
-    .keys()
-    .filter_map(|mid| {
+  let modules_exports_info = modules
+    .par_iter()
+    .filter_map(|(mid, _)| {
       let mgm = mg.module_graph_module_by_identifier(mid)?;
-      Some(mgm.exports)
+      let fingerprint = (save_inlined || affected_modules.is_some()).then(|| {
+        let mut hasher = FxHasher::default();
+        hash_exports_info(mg, &mgm.exports, &mut hasher);
+        hasher.finish()
+      });
+      Some((*mid, mgm.exports, fingerprint))
     })
+    .collect::<Vec<_>>();
+
+  // whether an export can be inlined only depends on the exports info of its own module, so the
+  // inlined exports can be reused if the module is not affected and the fingerprint is unchanged
+  let mut inline_exports_artifact = InlineExportsArtifact::default();
+  let mut reused_inlined = vec![];
+  let mut modules_to_inline = vec![];
+  for (mid, exports_info, fingerprint) in modules_exports_info {
+    if let Some(affected_modules) = &affected_modules
+      && !affected_modules.contains(&mid)
+      && let Some(entry) = previous_artifact.get(&mid)
+      && fingerprint == Some(entry.fingerprint)
+      && let Some(inlined) = entry
+        .inlined
+        .iter()
+        .map(|path| get_export_info_by_path(mg, &exports_info, path))
+        .collect::<Option<Vec<_>>>()
+    {
+      reused_inlined.extend(inlined);
+      inline_exports_artifact.insert(mid, entry.clone());
+    } else {
+      modules_to_inline.push((mid, exports_info, fingerprint));
+    }
+  }
+  let reused_modules = inline_exports_artifact.len();
+  for export_info in reused_inlined {
+    set_inlined_used_name(mg, &export_info);
+  }
+
+  let mut visited: FxHashSet<ExportsInfo> = FxHashSet::default();
+
+  let mut q = modules_to_inline
+    .iter()
+    .map(|(_, exports_info, _)| *exports_info)
     .collect_vec();
 
   while !q.is_empty() {
@@ -168,13 +225,7 @@ async fn optimize_dependencies(
           .into_iter()
           .filter_map(|(export_info, nested_exports_info, do_inline)| {
             if do_inline {
-              let data = export_info.as_data_mut(mg);
-              data.set_used_name(UsedNameItem::Inlined(
-                data
-                  .can_inline_provide()
-                  .expect("should have provided inline value")
-                  .clone(),
-              ));
+              set_inlined_used_name(mg, &export_info);
             }
             nested_exports_info
           })
@@ -183,9 +234,53 @@ async fn optimize_dependencies(
     }
   }
 
+  if save_inlined {
+    inline_exports_artifact.extend(
+      modules_to_inline
+        .par_iter()
+        .filter_map(|(mid, exports_info, fingerprint)| {
+          let inlined = collect_used_names(mg, exports_info, |used_name| match used_name {
+            UsedNameItem::Inlined(_) => Some(()),
+            UsedNameItem::Str(_) => None,
+          })
+          .into_iter()
+          .map(|(path, _)| path)
+          .collect();
+          Some((
+            *mid,
+            InlineExportsEntry {
+              fingerprint: (*fingerprint)?,
+              inlined,
+            },
+          ))
+        })
+        .collect::<Vec<_>>(),
+    );
+  }
+
+  if affected_modules.is_some() {
+    let logger = compilation.get_logger("rspack.incremental.inlineExports");
+    logger.log(format!(
+      "{} modules reuse their inlined exports, {} modules are inlined again",
+      reused_modules,
+      modules_to_inline.len(),
+    ));
+  }
+  *compilation.inline_exports_artifact.borrow_mut() = inline_exports_artifact;
+
   Ok(None)
 }
 
+fn set_inlined_used_name(mg: &mut ModuleGraph, export_info: &ExportInfo) {
+  let data = export_info.as_data_mut(mg);
+  data.set_used_name(UsedNameItem::Inlined(
+    data
+      .can_inline_provide()
+      .expect("should have provided inline value")
+      .clone(),
+  ));
+}
+
 impl Plugin for InlineExportsPlugin {
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
     ctx
@@ -1,22 +1,29 @@
-use std::sync::LazyLock;
+use std::{
+  hash::{Hash, Hasher},
+  sync::LazyLock,
+};
 
 use itertools::Itertools;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
   BuildMetaExportsType, Compilation, CompilationOptimizeCodeGeneration, ExportInfo, ExportProvided,
-  ExportsInfo, ExportsInfoGetter, ModuleGraph, Plugin, PrefetchExportsInfoMode,
-  PrefetchedExportsInfoWrapper, UsageState, UsedNameItem,
-  build_module_graph::BuildModuleGraphArtifact, incremental::IncrementalPasses,
+  ExportsInfo, ExportsInfoGetter, Logger, MangleExportsArtifact, MangleExportsEntry, ModuleGraph,
+  Plugin, PrefetchExportsInfoMode, PrefetchedExportsInfoWrapper, UsageState, UsedNameItem,
+  build_module_graph::BuildModuleGraphArtifact,
+  incremental::{self, IncrementalPasses},
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_ids::id_helpers::assign_deterministic_ids;
 use rspack_util::atom::Atom;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
-use crate::utils::mangle_exports::{
-  NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS, NUMBER_OF_IDENTIFIER_START_CHARS, number_to_identifier,
+use crate::utils::{
+  exports_used_names::{collect_used_names, get_export_info_by_path, hash_exports_info},
+  mangle_exports::{
+    NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS, NUMBER_OF_IDENTIFIER_START_CHARS, number_to_identifier,
+  },
 };
 
 fn can_mangle(exports_info: &PrefetchedExportsInfoWrapper<'_>) -> bool {
@@ -78,18 +85,70 @@ async fn optimize_code_generation(
   let mg = build_module_graph_artifact.get_module_graph_mut();
   let modules = mg.modules();
 
-  let mut exports_info_cache = FxHashMap::default();
+  let affected_modules = compilation
+    .incremental
+    .mutations_read(IncrementalPasses::MANGLE_EXPORTS)
+    .map(|mutations| {
+      let modules = mutations.get_affected_modules_with_module_graph(mg);
+      tracing::debug!(target: incremental::TRACING_TARGET, passes = %IncrementalPasses::MANGLE_EXPORTS, %mutations, ?modules);
+      modules
+    });
+  let save_used_names = compilation
+    .incremental
+    .passes_enabled(IncrementalPasses::MANGLE_EXPORTS);
+  let previous_artifact = std::mem::take(&mut *compilation.mangle_exports_artifact.borrow_mut());
 
-  let mut q = modules
-    .iter()
+  let modules_exports_info = modules
+    .par_iter()
     .filter_map(|(mid, module)| {
       let mgm = mg.module_graph_module_by_identifier(mid)?;
       let is_namespace = matches!(
         module.build_meta().exports_type,
         BuildMetaExportsType::Namespace
       );
-      Some((mgm.exports, is_namespace))
+      let fingerprint = (save_used_names || affected_modules.is_some()).then(|| {
+        let mut hasher = FxHasher::default();
+        is_namespace.hash(&mut hasher);
+        hash_exports_info(mg, &mgm.exports, &mut hasher);
+        hasher.finish()
+      });
+      Some((*mid, mgm.exports, is_namespace, fingerprint))
     })
+    .collect::<Vec<_>>();
+
+  // the mangled names of a module only depend on its own exports info, so they can be reused
+  // if the module is not affected and the fingerprint of its exports info is unchanged
+  let mut mangle_exports_artifact = MangleExportsArtifact::default();
+  let mut reused_used_names = vec![];
+  let mut modules_to_mangle = vec![];
+  for (mid, exports_info, is_namespace, fingerprint) in modules_exports_info {
+    if let Some(affected_modules) = &affected_modules
+      && !affected_modules.contains(&mid)
+      && let Some(entry) = previous_artifact.get(&mid)
+      && fingerprint == Some(entry.fingerprint)
+      && let Some(used_names) = entry
+        .used_names
+        .iter()
+        .map(|(path, name)| {
+          get_export_info_by_path(mg, &exports_info, path)
+            .map(|export_info| (export_info, UsedNameItem::Str(name.clone())))
+        })
+        .collect::<Option<Vec<_>>>()
+    {
+      reused_used_names.extend(used_names);
+      mangle_exports_artifact.insert(mid, entry.clone());
+    } else {
+      modules_to_mangle.push((mid, exports_info, is_namespace, fingerprint));
+    }
+  }
+  let reused_modules = mangle_exports_artifact.len();
+  mg.batch_set_export_info_used_name(reused_used_names);
+
+  let mut exports_info_cache = FxHashMap::default();
+
+  let mut q = modules_to_mangle
+    .iter()
+    .map(|(_, exports_info, is_namespace, _)| (*exports_info, *is_namespace))
     .collect_vec();
 
   while !q.is_empty() {
@@ -179,12 +238,9 @@ async fn optimize_code_generation(
     }
   }
 
-  let mut queue = modules
-    .into_iter()
-    .filter_map(|(mid, _)| {
-      let mgm = mg.module_graph_module_by_identifier(&mid)?;
-      Some(mgm.exports)
-    })
+  let mut queue = modules_to_mangle
+    .iter()
+    .map(|(_, exports_info, _, _)| *exports_info)
     .collect_vec();
 
   while !queue.is_empty() {
@@ -205,6 +261,37 @@ async fn optimize_code_generation(
     mg.batch_set_export_info_used_name(used_name_tasks);
   }
 
+  if save_used_names {
+    mangle_exports_artifact.extend(
+      modules_to_mangle
+        .par_iter()
+        .filter_map(|(mid, exports_info, _, fingerprint)| {
+          let used_names = collect_used_names(mg, exports_info, |used_name| match used_name {
+            UsedNameItem::Str(name) => Some(name.clone()),
+            UsedNameItem::Inlined(_) => None,
+          });
+          Some((
+            *mid,
+            MangleExportsEntry {
+              fingerprint: (*fingerprint)?,
+              used_names,
+            },
+          ))
+        })
+        .collect::<Vec<_>>(),
+    );
+  }
+
+  if affected_modules.is_some() {
+    let logger = compilation.get_logger("rspack.incremental.mangleExports");
+    logger.log(format!(
+      "{} modules reuse their mangled names, {} modules are mangled again",
+      reused_modules,
+      modules_to_mangle.len(),
+    ));
+  }
+  *compilation.mangle_exports_artifact.borrow_mut() = mangle_exports_artifact;
+
   Ok(())
 }
 
@@ -1,24 +1,34 @@
 #![allow(clippy::only_used_in_recursion)]
-use std::{borrow::Cow, collections::VecDeque, rc::Rc, sync::Arc};
+use std::{
+  borrow::Cow,
+  collections::VecDeque,
+  hash::{Hash, Hasher},
+  rc::Rc,
+  sync::Arc,
+};
 
 use rayon::prelude::*;
 use rspack_collections::{IdentifierDashMap, IdentifierIndexSet, IdentifierMap, IdentifierSet};
 use rspack_core::{
-  BoxDependency, Compilation, CompilationOptimizeChunkModules, DependencyId, DependencyType,
-  ExportProvided, ExtendedReferencedExport, GetTargetResult, ImportedByDeferModulesArtifact,
-  LibIdentOptions, Logger, Module, ModuleExt, ModuleGraph, ModuleGraphCacheArtifact,
+  BoxDependency, BuildMetaDefaultObject, BuildMetaExportsType, Compilation,
+  CompilationOptimizeChunkModules, DependencyId, DependencyType, ExportProvided, ExportsType,
+  ExtendedReferencedExport, GetTargetResult, ImportedByDeferModulesArtifact, LibIdentOptions,
+  Logger, Module, ModuleConcatenationArtifact, ModuleConcatenationBailout,
+  ModuleConcatenationEntry, ModuleExt, ModuleGraph, ModuleGraphCacheArtifact,
   ModuleGraphConnection, ModuleGraphModule, ModuleIdentifier, Plugin, PrefetchExportsInfoMode,
   ProvidedExports, RuntimeCondition, RuntimeSpec, SourceType,
   concatenated_module::{
     ConcatenatedInnerModule, ConcatenatedModule, RootModuleContext, is_esm_dep_like,
   },
   filter_runtime, get_cached_readable_identifier, get_target,
-  incremental::IncrementalPasses,
+  incremental::{self, IncrementalPasses},
 };
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::itoa;
-use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
+
+use crate::dependency::ESMImportSpecifierDependency;
 
 fn format_bailout_reason(msg: &str) -> String {
   format!("ModuleConcatenation bailout: {msg}")
@@ -30,6 +40,24 @@ enum Warning {
   Problem(String),
 }
 
+impl From<Warning> for ModuleConcatenationBailout {
+  fn from(value: Warning) -> Self {
+    match value {
+      Warning::Id(id) => Self::Module(id),
+      Warning::Problem(problem) => Self::Problem(problem),
+    }
+  }
+}
+
+impl From<ModuleConcatenationBailout> for Warning {
+  fn from(value: ModuleConcatenationBailout) -> Self {
+    match value {
+      ModuleConcatenationBailout::Module(id) => Self::Id(id),
+      ModuleConcatenationBailout::Problem(problem) => Self::Problem(problem),
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConcatConfiguration {
   pub root_module: ModuleIdentifier,
@@ -88,6 +116,32 @@ impl ConcatConfiguration {
       modules.pop();
     }
   }
+
+  fn from_entry(root_module: ModuleIdentifier, entry: ModuleConcatenationEntry) -> Self {
+    ConcatConfiguration {
+      root_module,
+      runtime: entry.runtime,
+      modules: entry.modules,
+      warnings: entry
+        .warnings
+        .into_iter()
+        .map(|(module, bailout)| (module, bailout.into()))
+        .collect(),
+    }
+  }
+
+  fn to_entry(&self, fingerprints: IdentifierMap<u64>) -> ModuleConcatenationEntry {
+    ModuleConcatenationEntry {
+      runtime: self.runtime.clone(),
+      modules: self.modules.clone(),
+      warnings: self
+        .warnings
+        .iter()
+        .map(|(module, warning)| (*module, warning.clone().into()))
+        .collect(),
+      fingerprints,
+    }
+  }
 }
 
 #[plugin]
@@ -126,6 +180,13 @@ impl<T> RuntimeIdentifierCache<T> {
       self.no_runtime_map.get(module)
     }
   }
+
+  fn modules(&self) -> impl Iterator<Item = &ModuleIdentifier> {
+    self
+      .no_runtime_map
+      .keys()
+      .chain(self.runtime_map.values().flat_map(|map| map.keys()))
+  }
 }
 
 impl ModuleConcatenationPlugin {
@@ -225,6 +286,78 @@ impl ModuleConcatenationPlugin {
     set
   }
 
+  /// Hash everything of a module that is read when calculating concat configurations,
+  /// so a configuration is reusable if the fingerprints of all visited modules are unchanged
+  fn get_module_fingerprint(
+    compilation: &Compilation,
+    module_id: &ModuleIdentifier,
+    relevant_modules: &IdentifierSet,
+    possible_modules: &IdentifierSet,
+    module_cache: &HashMap<ModuleIdentifier, NoRuntimeModuleCache>,
+  ) -> u64 {
+    let module_graph = compilation.get_module_graph();
+    let mut hasher = FxHasher::default();
+    relevant_modules.contains(module_id).hash(&mut hasher);
+    possible_modules.contains(module_id).hash(&mut hasher);
+
+    let exports_info =
+      module_graph.get_prefetched_exports_info(module_id, PrefetchExportsInfoMode::Full);
+    exports_info.update_hash(&mut hasher, None);
+    // orphan modules are not added to the chunk graph
+    if compilation
+      .chunk_graph
+      .get_number_of_module_chunks(*module_id)
+      > 0
+    {
+      let mut chunks = compilation
+        .chunk_graph
+        .get_module_chunks(*module_id)
+        .iter()
+        .map(|chunk| chunk.as_u32())
+        .collect::<Vec<_>>();
+      chunks.sort_unstable();
+      chunks.hash(&mut hasher);
+
+      let mut runtimes = compilation
+        .chunk_graph
+        .get_module_runtimes_iter(*module_id, &compilation.chunk_by_ukey)
+        .flat_map(|runtime| runtime.iter().copied())
+        .collect::<Vec<_>>();
+      runtimes.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+      runtimes.dedup();
+      // the used exports are checked for each runtime separately
+      for runtime in runtimes {
+        exports_info.update_hash(&mut hasher, Some(&RuntimeSpec::from_iter([runtime])));
+      }
+    }
+
+    if let Some(cached) = module_cache.get(module_id) {
+      cached.provided_names.hash(&mut hasher);
+      for (con, (has_imported_names, active)) in &cached.connections {
+        con.dependency_id.hash(&mut hasher);
+        con.module_identifier().hash(&mut hasher);
+        has_imported_names.hash(&mut hasher);
+        active.hash(&mut hasher);
+      }
+      let mut incomings = cached
+        .incomings
+        .iter()
+        .flat_map(|(origin_module, connections)| {
+          connections.iter().map(|con| {
+            (
+              con.dependency_id,
+              *origin_module,
+              cached.active_incomings.get(&con.dependency_id).copied(),
+            )
+          })
+        })
+        .collect::<Vec<_>>();
+      incomings.sort_unstable_by_key(|(dependency_id, ..)| *dependency_id);
+      incomings.hash(&mut hasher);
+    }
+    hasher.finish()
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn try_to_add(
     compilation: &Compilation,
@@ -467,11 +600,31 @@ impl ModuleConcatenationPlugin {
         return Some(problem);
       }
 
+      let module = module_graph
+        .module_by_identifier(module_id)
+        .expect("should have module");
+      let is_commonjs = compilation
+        .options
+        .optimization
+        .concatenate_commonjs_modules
+        && is_commonjs_module(module.as_ref());
       let mut non_esm_connections = HashMap::default();
       for (origin_module, connections) in incoming_connections_from_modules.iter() {
         let has_non_esm_connections = connections.iter().any(|connection| {
           let dep = module_graph.dependency_by_id(&connection.dependency_id);
-          !is_esm_dep_like(dep)
+          if is_commonjs {
+            // `exports.foo` of the CommonJS module itself refers to the concatenated export
+            **origin_module != *module_id
+              && !is_commonjs_export_reference(
+                dep,
+                module.as_ref(),
+                origin_module,
+                module_graph,
+                module_graph_cache,
+              )
+          } else {
+            !is_esm_dep_like(dep)
+          }
         });
 
         if has_non_esm_connections {
@@ -506,11 +659,19 @@ impl ModuleConcatenationPlugin {
             })
             .collect();
 
-          format!(
-            "Module {} is referenced from these modules with unsupported syntax: {}",
-            module_readable_identifier,
-            names.join(", ")
-          )
+          if is_commonjs {
+            format!(
+              "Module {} is a CommonJS module which is only concatenated when imported by the names of its exports, but is referenced from these modules with unsupported syntax: {}",
+              module_readable_identifier,
+              names.join(", ")
+            )
+          } else {
+            format!(
+              "Module {} is referenced from these modules with unsupported syntax: {}",
+              module_readable_identifier,
+              names.join(", ")
+            )
+          }
         };
         let problem = Warning::Problem(problem);
         statistics.incorrect_module_dependency += 1;
@@ -835,6 +996,8 @@ impl ModuleConcatenationPlugin {
 
   async fn optimize_chunk_modules_impl(&self, compilation: &mut Compilation) -> Result<()> {
     let logger = compilation.get_logger("rspack.ModuleConcatenationPlugin");
+    let mut previous_configurations =
+      std::mem::take(&mut compilation.module_concatenation_artifact);
 
     if compilation.options.experiments.defer_import {
       let mut imported_by_defer_modules_artifact = ImportedByDeferModulesArtifact::default();
@@ -879,12 +1042,24 @@ impl ModuleConcatenationPlugin {
           .module_by_identifier(&module_id)
           .expect("should have module");
 
-        if let Some(reason) =
+        // CommonJS modules can only be concatenated into the ES modules importing them
+        let is_commonjs = compilation
+          .options
+          .optimization
+          .concatenate_commonjs_modules
+          && is_commonjs_module(m.as_ref());
+        let reason = if is_commonjs {
+          get_commonjs_concatenation_bailout_reason(m.as_ref())
+        } else {
           m.get_concatenation_bailout_reason(module_graph, &compilation.chunk_graph)
-        {
+        };
+        if let Some(reason) = reason {
           bailout_reason.push(reason);
           return (false, false, module_id, bailout_reason);
         }
+        if is_commonjs {
+          can_be_root = false;
+        }
 
         if ModuleGraph::is_async(&compilation.async_modules_artifact.borrow(), &module_id) {
           bailout_reason.push("Module is async".into());
@@ -1040,6 +1215,23 @@ impl ModuleConcatenationPlugin {
     let mut stats_size_sum = 0;
     let mut stats_empty_configurations = 0;
 
+    let affected_modules = compilation
+      .incremental
+      .mutations_read(IncrementalPasses::MODULE_CONCATENATION)
+      .map(|mutations| {
+        let modules =
+          mutations.get_affected_modules_with_module_graph(compilation.get_module_graph());
+        tracing::debug!(target: incremental::TRACING_TARGET, passes = %IncrementalPasses::MODULE_CONCATENATION, %mutations, ?modules);
+        modules
+      });
+    let save_configurations = compilation
+      .incremental
+      .passes_enabled(IncrementalPasses::MODULE_CONCATENATION);
+    let relevant_modules_set = relevant_modules.iter().copied().collect::<IdentifierSet>();
+    let mut fingerprints: IdentifierMap<u64> = IdentifierMap::default();
+    let mut module_concatenation_artifact = ModuleConcatenationArtifact::default();
+    let mut stats_reused_configurations = 0;
+
     let start = logger.time("find modules to concatenate");
     let mut concat_configurations: Vec<ConcatConfiguration> = Vec::new();
     let mut used_as_inner: IdentifierSet = IdentifierSet::default();
@@ -1155,61 +1347,134 @@ impl ModuleConcatenationPlugin {
         RuntimeCondition::Spec(spec) => Some(spec),
       };
 
-      let mut current_configuration =
-        ConcatConfiguration::new(*current_root, active_runtime.clone());
+      let reusable_entry = if let Some(affected_modules) = &affected_modules
+        && let Some(entry) = previous_configurations.remove(current_root)
+        && entry.runtime == active_runtime
+        && entry.fingerprints.iter().all(|(module_id, fingerprint)| {
+          !affected_modules.contains(module_id)
+            && module_graph.module_by_identifier(module_id).is_some()
+            && fingerprints.entry(*module_id).or_insert_with(|| {
+              Self::get_module_fingerprint(
+                compilation,
+                module_id,
+                &relevant_modules_set,
+                &possible_inners,
+                &modules_without_runtime_cache,
+              )
+            }) == fingerprint
+        }) {
+        Some(entry)
+      } else {
+        None
+      };
 
-      let mut failure_cache = IdentifierMap::default();
-      let mut success_cache = RuntimeIdentifierCache::default();
-      let mut candidates_visited = HashSet::default();
-      let mut candidates = VecDeque::new();
+      let current_configuration = if let Some(entry) = reusable_entry {
+        stats_reused_configurations += 1;
+        if save_configurations {
+          module_concatenation_artifact.insert(*current_root, entry.clone());
+        }
+        ConcatConfiguration::from_entry(*current_root, entry)
+      } else {
+        let mut current_configuration =
+          ConcatConfiguration::new(*current_root, active_runtime.clone());
 
-      let imports = Self::get_imports(
-        module_graph,
-        module_graph_cache,
-        *current_root,
-        active_runtime.as_ref(),
-        &mut imports_cache,
-        &modules_without_runtime_cache,
-      );
-      for import in imports {
-        candidates.push_back(import);
-      }
+        let mut failure_cache = IdentifierMap::default();
+        let mut success_cache = RuntimeIdentifierCache::default();
+        let mut candidates_visited = HashSet::default();
+        let mut candidates = VecDeque::new();
 
-      let mut import_candidates = IdentifierSet::default();
-      while let Some(imp) = candidates.pop_front() {
-        if candidates_visited.contains(&imp) {
-          continue;
-        } else {
-          candidates_visited.insert(imp);
-        }
-        import_candidates.clear();
-        match Self::try_to_add(
-          compilation,
-          &mut current_configuration,
-          &imp,
-          Some(runtime),
+        let imports = Self::get_imports(
+          module_graph,
+          module_graph_cache,
+          *current_root,
           active_runtime.as_ref(),
-          &possible_inners,
-          &mut import_candidates,
-          &mut failure_cache,
-          &mut success_cache,
-          true,
-          &mut statistics,
           &mut imports_cache,
           &modules_without_runtime_cache,
-        ) {
-          Some(problem) => {
-            failure_cache.insert(imp, problem.clone());
-            current_configuration.add_warning(imp, problem);
+        );
+        for import in imports {
+          candidates.push_back(import);
+        }
+
+        let mut import_candidates = IdentifierSet::default();
+        while let Some(imp) = candidates.pop_front() {
+          if candidates_visited.contains(&imp) {
+            continue;
+          } else {
+            candidates_visited.insert(imp);
           }
-          _ => {
-            import_candidates.iter().for_each(|c: &ModuleIdentifier| {
-              candidates.push_back(*c);
-            });
+          import_candidates.clear();
+          match Self::try_to_add(
+            compilation,
+            &mut current_configuration,
+            &imp,
+            Some(runtime),
+            active_runtime.as_ref(),
+            &possible_inners,
+            &mut import_candidates,
+            &mut failure_cache,
+            &mut success_cache,
+            true,
+            &mut statistics,
+            &mut imports_cache,
+            &modules_without_runtime_cache,
+          ) {
+            Some(problem) => {
+              failure_cache.insert(imp, problem.clone());
+              current_configuration.add_warning(imp, problem);
+            }
+            _ => {
+              import_candidates.iter().for_each(|c: &ModuleIdentifier| {
+                candidates.push_back(*c);
+              });
+            }
           }
         }
-      }
-      stats_candidates += candidates.len();
+        stats_candidates += candidates.len();
+
+        if save_configurations {
+          let visited_modules = current_configuration
+            .get_modules()
+            .iter()
+            .chain(failure_cache.keys())
+            .chain(success_cache.modules())
+            .chain(candidates_visited.iter())
+            .copied()
+            .collect::<IdentifierSet>();
+          let mut dependencies = visited_modules.clone();
+          for module_id in &visited_modules {
+            if let Some(cached) = modules_without_runtime_cache.get(module_id) {
+              dependencies.extend(cached.incomings.keys().flatten().copied());
+              dependencies.extend(
+                cached
+                  .connections
+                  .iter()
+                  .map(|(con, _)| *con.module_identifier()),
+              );
+            }
+          }
+          let module_fingerprints = dependencies
+            .into_iter()
+            .map(|module_id| {
+              let fingerprint = *fingerprints.entry(module_id).or_insert_with(|| {
+                Self::get_module_fingerprint(
+                  compilation,
+                  &module_id,
+                  &relevant_modules_set,
+                  &possible_inners,
+                  &modules_without_runtime_cache,
+                )
+              });
+              (module_id, fingerprint)
+            })
+            .collect();
+          module_concatenation_artifact.insert(
+            *current_root,
+            current_configuration.to_entry(module_fingerprints),
+          );
+        }
+        current_configuration
+      };
+
       if !current_configuration.is_empty() {
         let modules = current_configuration.get_modules();
         stats_size_sum += modules.len();
@@ -1235,6 +1500,16 @@ impl ModuleConcatenationPlugin {
 
     rayon::spawn(move || drop(modules_without_runtime_cache));
 
+    if affected_modules.is_some() {
+      let logger = compilation.get_logger("rspack.incremental.moduleConcatenation");
+      logger.log(format!(
+        "{} concat configurations are reused, {} are recalculated",
+        stats_reused_configurations,
+        module_concatenation_artifact.len() - stats_reused_configurations,
+      ));
+    }
+    compilation.module_concatenation_artifact = module_concatenation_artifact;
+
     if !concat_configurations.is_empty() {
       let mut concat_len_buffer = itoa::Buffer::new();
       let concat_len_str = concat_len_buffer.format(concat_configurations.len());
@@ -1731,3 +2006,77 @@ fn is_connection_active_in_runtime(
 
   connection.is_active(mg, runtime, mg_cache)
 }
+
+/// Whether the module is parsed as a CommonJS module, which can be concatenated as an inner
+/// module with `optimization.concatenateCommonjsModules`
+fn is_commonjs_module(module: &dyn Module) -> bool {
+  module.as_normal_module().is_some()
+    && module.module_type().is_js_like()
+    && !module.build_meta().esm
+}
+
+fn get_commonjs_concatenation_bailout_reason(module: &dyn Module) -> Option<Cow<'static, str>> {
+  let build_meta = module.build_meta();
+  if let Some(bailout) = module.build_info().module_concatenation_bailout.as_deref() {
+    return Some(format!("Module uses {bailout}").into());
+  }
+  // the code of the concatenated modules is in strict mode
+  if !module.build_info().strict {
+    return Some("Module is not in strict mode".into());
+  }
+  let has_static_exports = match build_meta.exports_type {
+    BuildMetaExportsType::Flagged => true,
+    BuildMetaExportsType::Default => {
+      matches!(build_meta.default_object, BuildMetaDefaultObject::Redirect)
+    }
+    _ => false,
+  };
+  if !has_static_exports {
+    return Some(
+      "Module is not an ECMAScript module or a CommonJS module with static exports".into(),
+    );
+  }
+  None
+}
+
+/// Whether the dependency refers to a provided export of the CommonJS module by its name,
+/// the module can't be concatenated if its `exports` object is referenced as a whole
+fn is_commonjs_export_reference(
+  dep: &BoxDependency,
+  module: &dyn Module,
+  origin_module: &ModuleIdentifier,
+  mg: &ModuleGraph,
+  mg_cache: &ModuleGraphCacheArtifact,
+) -> bool {
+  match dep.dependency_type() {
+    DependencyType::EsmImport => true,
+    DependencyType::EsmImportSpecifier => {
+      let Some(dep) = dep.downcast_ref::<ESMImportSpecifierDependency>() else {
+        return false;
+      };
+      let strict_esm_module = mg
+        .module_by_identifier(origin_module)
+        .is_some_and(|origin_module| origin_module.build_meta().strict_esm_module);
+      let ids = dep.get_ids(mg);
+      let ids = match module.get_exports_type(mg, mg_cache, strict_esm_module) {
+        ExportsType::Namespace => ids,
+        // `default` refers to the `exports` object
+        ExportsType::DefaultWithNamed if ids.first().is_some_and(|id| id == "default") => &ids[1..],
+        ExportsType::DefaultWithNamed => ids,
+        _ => return false,
+      };
+      let Some(name) = ids.first() else {
+        return false;
+      };
+      let exports_info = mg.get_prefetched_exports_info(
+        &module.identifier(),
+        PrefetchExportsInfoMode::Nested(std::slice::from_ref(name)),
+      );
+      matches!(
+        exports_info.get_read_only_export_info(name).provided(),
+        Some(ExportProvided::Provided)
+      )
+    }
+    _ => false,
+  }
+}
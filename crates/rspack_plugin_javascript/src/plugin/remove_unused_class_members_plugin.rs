@@ -0,0 +1,197 @@
+use rspack_collections::{IdentifierMap, IdentifierSet};
+use rspack_core::{
+  ClassMembersArtifact, Compilation, CompilationOptimizeDependencies, DependencyType, Logger,
+  Module, ModuleGraph, ModuleIdentifier, ModuleType, Plugin, PrefetchExportsInfoMode,
+  SideEffectsOptimizeArtifact, UsageState, build_module_graph::BuildModuleGraphArtifact,
+  incremental::IncrementalPasses,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::atom::Atom;
+use rustc_hash::FxHashSet;
+
+use crate::dependency::{ClassMemberDependency, ESMImportSpecifierDependency};
+
+/// Removes the unused members of the top-level classes, a member is unused if its name is
+/// not accessed by any module and the class is not exposed dynamically.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct RemoveUnusedClassMembersPlugin;
+
+#[plugin_hook(CompilationOptimizeDependencies for RemoveUnusedClassMembersPlugin, stage = 100)]
+async fn optimize_dependencies(
+  &self,
+  compilation: &Compilation,
+  _side_effect_optimize_artifact: &mut SideEffectsOptimizeArtifact,
+  build_module_graph_artifact: &mut BuildModuleGraphArtifact,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Option<bool>> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULES_HASHES,
+    "RemoveUnusedClassMembersPlugin (optimization.removeUnusedClassMembers = true)",
+    "it requires collecting the accessed properties of all the modules, which is a global effect",
+  ) {
+    diagnostics.extend(diagnostic);
+  }
+
+  let logger = compilation.get_logger("rspack.RemoveUnusedClassMembersPlugin");
+  let start = logger.time("collect accessed properties");
+  let mg = build_module_graph_artifact.get_module_graph();
+  let mut accessed_properties: FxHashSet<Atom> = FxHashSet::default();
+  let mut class_modules = vec![];
+  for (module_identifier, module) in mg.modules() {
+    let Some(info) = &module.build_info().collected_class_members_info else {
+      // the externals and the modules that are not parsed may access any member of the
+      // instances they receive
+      if is_unparsed_module(module.as_ref()) {
+        logger.time_end(start);
+        logger.log(format!("bail out since {module_identifier} is not parsed"));
+        *compilation.class_members_artifact.borrow_mut() = ClassMembersArtifact::default();
+        return Ok(None);
+      }
+      continue;
+    };
+    accessed_properties.extend(info.accessed_properties.iter().cloned());
+    if !info.classes.is_empty() {
+      class_modules.push(module_identifier);
+    }
+  }
+  logger.time_end(start);
+
+  let start = logger.time("find unused class members");
+  let mut artifact = ClassMembersArtifact::default();
+  let exposed_exports = collect_exposed_exports(compilation, mg, &class_modules);
+  for module_identifier in class_modules {
+    let module = mg
+      .module_by_identifier(&module_identifier)
+      .expect("should have module");
+    let info = module
+      .build_info()
+      .collected_class_members_info
+      .as_ref()
+      .expect("should have collected class members info");
+    let exports_info =
+      mg.get_prefetched_exports_info(&module_identifier, PrefetchExportsInfoMode::Default);
+    let exposed = exposed_exports.get(&module_identifier);
+
+    let mut bailout_classes: FxHashSet<&Atom> = FxHashSet::default();
+    for class in &info.classes {
+      let is_exposed = class.exports.iter().any(|export| {
+        exposed.is_some_and(|exposed| exposed.as_ref().is_none_or(|exposed| exposed.contains(export)))
+          // the export is used by the library or the runtime, e.g. `module.exports` of an entry
+          || matches!(
+            exports_info.get_used(std::slice::from_ref(export), None),
+            UsageState::NoInfo | UsageState::Unknown
+          )
+      });
+      if is_exposed {
+        bailout_classes.insert(&class.name);
+      }
+    }
+    // the members of the super classes are exposed by the instances of the sub classes
+    let mut queue = bailout_classes.iter().copied().collect::<Vec<_>>();
+    while let Some(name) = queue.pop() {
+      if let Some(super_class) = info
+        .classes
+        .iter()
+        .find(|class| &class.name == name)
+        .and_then(|class| class.super_class.as_ref())
+        && bailout_classes.insert(super_class)
+      {
+        queue.push(super_class);
+      }
+    }
+
+    for dependency_id in module.get_dependencies() {
+      let Some(dependency) = mg
+        .dependency_by_id(dependency_id)
+        .downcast_ref::<ClassMemberDependency>()
+      else {
+        continue;
+      };
+      if !bailout_classes.contains(dependency.class_name())
+        && !accessed_properties.contains(dependency.member_name())
+      {
+        artifact.insert(*dependency_id);
+      }
+    }
+  }
+  logger.time_end(start);
+
+  *compilation.class_members_artifact.borrow_mut() = artifact;
+  Ok(None)
+}
+
+fn is_unparsed_module(module: &dyn Module) -> bool {
+  match module.module_type() {
+    ModuleType::JsDynamic | ModuleType::Remote | ModuleType::ConsumeShared => true,
+    // the modules matching `module.noParse`
+    module_type => module_type.is_js_like() && module.as_normal_module().is_some(),
+  }
+}
+
+/// Collects the exports of the modules that are exposed to other modules, `None` means
+/// all the exports of the module are exposed.
+fn collect_exposed_exports(
+  compilation: &Compilation,
+  mg: &ModuleGraph,
+  class_modules: &[ModuleIdentifier],
+) -> IdentifierMap<Option<FxHashSet<Atom>>> {
+  let mut exposed_exports: IdentifierMap<Option<FxHashSet<Atom>>> = IdentifierMap::default();
+  let mut exposed_modules = IdentifierSet::default();
+  for module_identifier in class_modules {
+    for connection in mg.get_incoming_connections(module_identifier) {
+      let dependency = mg.dependency_by_id(&connection.dependency_id);
+      match dependency.dependency_type() {
+        DependencyType::Entry | DependencyType::EsmImport => {}
+        DependencyType::EsmImportSpecifier => {
+          let dependency = dependency
+            .downcast_ref::<ESMImportSpecifierDependency>()
+            .expect("should be ESMImportSpecifierDependency");
+          match dependency.get_ids(mg) {
+            // `import * as ns from './foo'; fn(ns)`
+            [] => {
+              exposed_modules.insert(*module_identifier);
+            }
+            // `new Foo()` or `x instanceof Foo`
+            [_] if dependency.opaque_class_reference => {}
+            // `Foo.bar`
+            [_, property, ..] if property != "prototype" => {}
+            [export, ..] => {
+              if let Some(exports) = exposed_exports
+                .entry(*module_identifier)
+                .or_insert_with(|| Some(FxHashSet::default()))
+              {
+                exports.insert(export.clone());
+              }
+            }
+          }
+        }
+        // the re-exported classes may be used in any way by the modules importing the
+        // re-exporting module, unless the re-export is not used at all
+        DependencyType::EsmExportImportedSpecifier => {
+          if connection.is_active(mg, None, &compilation.module_graph_cache_artifact) {
+            exposed_modules.insert(*module_identifier);
+          }
+        }
+        _ => {
+          exposed_modules.insert(*module_identifier);
+        }
+      }
+    }
+  }
+  for module_identifier in exposed_modules {
+    exposed_exports.insert(module_identifier, None);
+  }
+  exposed_exports
+}
+
+impl Plugin for RemoveUnusedClassMembersPlugin {
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .optimize_dependencies
+      .tap(optimize_dependencies::new(self));
+    Ok(())
+  }
+}
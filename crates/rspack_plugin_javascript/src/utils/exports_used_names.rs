@@ -0,0 +1,77 @@
+use std::hash::{Hash, Hasher};
+
+use rspack_core::{ExportInfo, ExportsInfo, ModuleGraph, UsedNameItem};
+use rspack_util::atom::Atom;
+
+/// Hash the states that decide the used names of the exports, including the nested
+/// exports infos owned by the exports info
+pub fn hash_exports_info(mg: &ModuleGraph, exports_info: &ExportsInfo, hasher: &mut impl Hasher) {
+  let data = exports_info.as_data(mg);
+  data.other_exports_info().get_used(None).hash(hasher);
+  for (name, export_info) in data.exports() {
+    name.hash(hasher);
+    export_info.provided().hash(hasher);
+    export_info.can_mangle().hash(hasher);
+    export_info.can_inline().hash(hasher);
+    export_info.can_inline_provide().hash(hasher);
+    export_info.has_used_name().hash(hasher);
+    export_info.get_used(None).hash(hasher);
+    let nested_exports_info = export_info
+      .exports_info()
+      .filter(|_| export_info.exports_info_owned());
+    nested_exports_info.is_some().hash(hasher);
+    if let Some(nested_exports_info) = nested_exports_info {
+      hash_exports_info(mg, &nested_exports_info, hasher);
+    }
+  }
+}
+
+/// Find the export info by the path of the (nested) export
+pub fn get_export_info_by_path(
+  mg: &ModuleGraph,
+  exports_info: &ExportsInfo,
+  path: &[Atom],
+) -> Option<ExportInfo> {
+  let (name, nested_path) = path.split_first()?;
+  let export_info = exports_info.as_data(mg).named_exports(name)?;
+  if nested_path.is_empty() {
+    return Some(export_info.id());
+  }
+  if !export_info.exports_info_owned() {
+    return None;
+  }
+  get_export_info_by_path(mg, &export_info.exports_info()?, nested_path)
+}
+
+/// Collect the used names of the exports, including the nested exports owned by the exports info,
+/// keyed by the path of the export
+pub fn collect_used_names<T>(
+  mg: &ModuleGraph,
+  exports_info: &ExportsInfo,
+  f: impl Fn(&UsedNameItem) -> Option<T> + Copy,
+) -> Vec<(Vec<Atom>, T)> {
+  fn collect<T>(
+    mg: &ModuleGraph,
+    exports_info: &ExportsInfo,
+    f: impl Fn(&UsedNameItem) -> Option<T> + Copy,
+    path: &mut Vec<Atom>,
+    res: &mut Vec<(Vec<Atom>, T)>,
+  ) {
+    for (name, export_info) in exports_info.as_data(mg).exports() {
+      path.push(name.clone());
+      if let Some(item) = export_info.used_name().and_then(f) {
+        res.push((path.clone(), item));
+      }
+      if export_info.exports_info_owned()
+        && let Some(nested_exports_info) = export_info.exports_info()
+      {
+        collect(mg, &nested_exports_info, f, path, res);
+      }
+      path.pop();
+    }
+  }
+
+  let mut res = vec![];
+  collect(mg, exports_info, f, &mut vec![], &mut res);
+  res
+}
@@ -1,4 +1,5 @@
 pub mod eval;
+pub mod exports_used_names;
 pub mod mangle_exports;
 pub mod object_properties;
 
@@ -0,0 +1,187 @@
+use rspack_core::{
+  BranchCondition, BranchTestValue, DependenciesBlock, Dependency, DependencyRange,
+  EvaluatedInlinableValue,
+};
+use swc_core::{
+  common::Spanned,
+  ecma::ast::{BinExpr, BinaryOp, Expr, Lit, UnaryOp},
+};
+
+use super::JavascriptParser;
+use crate::dependency::{ESMImportSpecifierDependency, ExportInfoDependency};
+
+impl JavascriptParser<'_> {
+  /// Walks the test of an `if` statement or a conditional expression, returns the conditions
+  /// of the consequent and the alternate, which are only collected with `optimization.inlineExports`
+  pub(super) fn walk_branch_test(
+    &mut self,
+    test: &Expr,
+  ) -> (Vec<BranchCondition>, Vec<BranchCondition>) {
+    if !self.build_info.inline_exports {
+      self.walk_expression(test);
+      return (vec![], vec![]);
+    }
+    let dependency_idx = self.next_dependency_idx();
+    let presentational_dependency_idx = self.next_presentational_dependency_idx();
+    self.walk_expression(test);
+    let mut cons_conditions = vec![];
+    self.collect_branch_conditions(
+      test,
+      true,
+      dependency_idx,
+      presentational_dependency_idx,
+      &mut cons_conditions,
+    );
+    let mut alt_conditions = vec![];
+    self.collect_branch_conditions(
+      test,
+      false,
+      dependency_idx,
+      presentational_dependency_idx,
+      &mut alt_conditions,
+    );
+    (cons_conditions, alt_conditions)
+  }
+
+  /// Collects the conditions that must hold for the `test` to be evaluated to `expected`, the
+  /// values in the conditions are the imported constants and the `__webpack_exports_info__`
+  /// usages walked in the `test`, which are determined after the module graph is built.
+  ///
+  /// Only the conditions that are all required are collected, e.g. `a && b` being true
+  /// requires both `a` and `b` to be truthy, but nothing is required for `a || b` being true.
+  fn collect_branch_conditions(
+    &self,
+    test: &Expr,
+    expected: bool,
+    dependency_idx: usize,
+    presentational_dependency_idx: usize,
+    conditions: &mut Vec<BranchCondition>,
+  ) {
+    match test {
+      Expr::Paren(paren) => self.collect_branch_conditions(
+        &paren.expr,
+        expected,
+        dependency_idx,
+        presentational_dependency_idx,
+        conditions,
+      ),
+      Expr::Unary(unary) if unary.op == UnaryOp::Bang => self.collect_branch_conditions(
+        &unary.arg,
+        !expected,
+        dependency_idx,
+        presentational_dependency_idx,
+        conditions,
+      ),
+      Expr::Bin(BinExpr {
+        op, left, right, ..
+      }) if (*op == BinaryOp::LogicalAnd && expected)
+        || (*op == BinaryOp::LogicalOr && !expected) =>
+      {
+        for expr in [left, right] {
+          self.collect_branch_conditions(
+            expr,
+            expected,
+            dependency_idx,
+            presentational_dependency_idx,
+            conditions,
+          );
+        }
+      }
+      Expr::Bin(BinExpr {
+        op, left, right, ..
+      }) if matches!(op, BinaryOp::EqEqEq | BinaryOp::NotEqEq) => {
+        let (value, literal) = match (get_literal_value(left), get_literal_value(right)) {
+          (None, Some(literal)) => (left, literal),
+          (Some(literal), None) => (right, literal),
+          _ => return,
+        };
+        if let Some(value) =
+          self.get_branch_test_value(value, dependency_idx, presentational_dependency_idx)
+        {
+          conditions.push(BranchCondition {
+            value,
+            compared_with: Some(literal),
+            expected: expected == (*op == BinaryOp::EqEqEq),
+          });
+        }
+      }
+      _ => {
+        if let Some(value) =
+          self.get_branch_test_value(test, dependency_idx, presentational_dependency_idx)
+        {
+          conditions.push(BranchCondition {
+            value,
+            compared_with: None,
+            expected,
+          });
+        }
+      }
+    }
+  }
+
+  fn get_branch_test_value(
+    &self,
+    expr: &Expr,
+    dependency_idx: usize,
+    presentational_dependency_idx: usize,
+  ) -> Option<BranchTestValue> {
+    let range = DependencyRange::from(expr.span());
+    if let Some(dependency) = self.dependencies[dependency_idx..]
+      .iter()
+      .filter_map(|dependency| dependency.downcast_ref::<ESMImportSpecifierDependency>())
+      .find(|dependency| dependency.range() == Some(range))
+    {
+      return (!dependency.source_ids().is_empty()).then(|| BranchTestValue::Import {
+        dependency: *dependency.id(),
+        ids: dependency.source_ids().to_vec(),
+      });
+    }
+    self.presentational_dependencies[presentational_dependency_idx..]
+      .iter()
+      .filter_map(|dependency| dependency.as_any().downcast_ref::<ExportInfoDependency>())
+      .find(|dependency| dependency.range() == range)
+      .and_then(|dependency| dependency.used_export_name())
+      .map(|export_name| BranchTestValue::ExportUsed(export_name.to_vec()))
+  }
+
+  /// Walks a branch of an `if` statement or a conditional expression, the dependencies in the
+  /// branch are inactive if any of the `conditions` turns out to be false
+  pub(super) fn walk_branch(&mut self, conditions: &[BranchCondition], f: impl FnOnce(&mut Self)) {
+    let dependency_idx = self.next_dependency_idx();
+    let block_idx = self.next_block_idx();
+    f(self);
+    if conditions.is_empty() {
+      return;
+    }
+    let dependency_ids = self.dependencies[dependency_idx..]
+      .iter()
+      .map(|dependency| *dependency.id())
+      .chain(
+        self.blocks[block_idx..]
+          .iter()
+          .flat_map(|block| block.get_dependencies().iter().copied()),
+      )
+      .collect::<Vec<_>>();
+    for dependency_id in dependency_ids {
+      self
+        .build_info
+        .branch_conditions
+        .entry(dependency_id)
+        .or_default()
+        .extend(conditions.iter().cloned());
+    }
+  }
+}
+
+fn get_literal_value(expr: &Expr) -> Option<EvaluatedInlinableValue> {
+  match expr {
+    Expr::Paren(paren) => get_literal_value(&paren.expr),
+    Expr::Lit(Lit::Null(_)) => Some(EvaluatedInlinableValue::new_null()),
+    Expr::Lit(Lit::Bool(bool)) => Some(EvaluatedInlinableValue::new_boolean(bool.value)),
+    Expr::Lit(Lit::Num(num)) => Some(EvaluatedInlinableValue::new_number(num.value)),
+    Expr::Lit(Lit::Str(str)) => Some(EvaluatedInlinableValue::new_string(
+      str.value.to_atom_lossy().into_owned(),
+    )),
+    _ => None,
+  }
+}
@@ -1,4 +1,5 @@
 pub mod ast;
+mod branch_condition;
 mod call_hooks_name;
 pub mod estree;
 mod walk;
@@ -32,7 +33,7 @@ use rspack_util::{SpanExt, fx_hash::FxIndexSet};
 use rustc_hash::{FxHashMap, FxHashSet};
 use swc_core::{
   atoms::Atom,
-  common::{BytePos, Mark, Span, Spanned, comments::Comments},
+  common::{BytePos, Mark, Span, Spanned, SyntaxContext, comments::Comments},
   ecma::{
     ast::{
       ArrayPat, AssignPat, AssignTargetPat, CallExpr, Decl, Expr, Ident, Lit, MemberExpr,
@@ -363,7 +364,22 @@ pub struct JavascriptParser<'parser> {
   pub(crate) last_esm_import_order: i32,
   pub(crate) inner_graph: InnerGraphState,
   pub(crate) has_inlinable_const_decls: bool,
+  /// Top-level const objects referenced in the module, which are not inlinable since
+  /// their properties may be modified
+  pub(crate) referenced_const_objects: FxHashSet<Atom>,
   pub(crate) side_effects_item: Option<SideEffectsBailoutItemWithSpan>,
+  /// Top-level functions annotated with `#__NO_SIDE_EFFECTS__`, and the syntax contexts of
+  /// their declarations to tell them from the shadowing bindings
+  pub(crate) no_side_effects_functions: FxHashMap<Atom, SyntaxContext>,
+  /// Callees of top-level calls to imported functions, these calls are side effects free
+  /// only if the imported functions are annotated with `#__NO_SIDE_EFFECTS__`
+  pub(crate) no_side_effects_callees: FxHashSet<Span>,
+  /// References that only construct or check a class, i.e. `new Foo()` and `x instanceof Foo`,
+  /// they don't expose the members of the class
+  pub(crate) opaque_class_references: FxHashSet<Span>,
+  /// Member expressions that are assigned, updated or deleted, the properties they access
+  /// can't be inlined
+  pub(crate) mutated_member_expressions: FxHashSet<Span>,
 }
 
 impl<'parser> JavascriptParser<'parser> {
@@ -448,6 +464,7 @@ impl<'parser> JavascriptParser<'parser> {
       if commonjs_exports != JavascriptParserCommonjsExportsOption::Disable {
         plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin::new(
           commonjs_exports == JavascriptParserCommonjsExportsOption::SkipInEsm,
+          unresolved_mark,
         )));
       }
     }
@@ -481,7 +498,14 @@ impl<'parser> JavascriptParser<'parser> {
 
     if compiler_options.optimization.inline_exports {
       build_info.inline_exports = true;
-      plugins.push(Box::new(parser_plugin::InlineConstPlugin));
+      plugins.push(Box::new(parser_plugin::InlineConstPlugin::new(
+        unresolved_mark,
+      )));
+    }
+    if compiler_options.optimization.remove_unused_class_members {
+      plugins.push(Box::new(parser_plugin::ClassMembersParserPlugin::new(
+        unresolved_mark,
+      )));
     }
     if compiler_options.optimization.inner_graph {
       plugins.push(Box::new(parser_plugin::InnerGraphPlugin::new(
@@ -538,7 +562,12 @@ impl<'parser> JavascriptParser<'parser> {
       parse_meta,
       local_modules: Default::default(),
       has_inlinable_const_decls: true,
+      referenced_const_objects: Default::default(),
       side_effects_item: None,
+      no_side_effects_functions: Default::default(),
+      no_side_effects_callees: Default::default(),
+      opaque_class_references: Default::default(),
+      mutated_member_expressions: Default::default(),
       runtime_template,
     }
   }
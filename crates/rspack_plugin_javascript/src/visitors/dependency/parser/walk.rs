@@ -261,6 +261,9 @@ impl JavascriptParser<'_> {
   }
 
   fn walk_return_statement(&mut self, stmt: &ReturnStmt) {
+    if self.is_top_level_scope() {
+      self.bailout_commonjs_concatenation("a top-level `return`");
+    }
     if let Some(arg) = &stmt.arg {
       self.walk_expression(arg);
     }
@@ -283,10 +286,12 @@ impl JavascriptParser<'_> {
         self.walk_nested_statement(alt);
       }
     } else {
-      self.walk_expression(&stmt.test);
-      self.walk_nested_statement(&stmt.cons);
+      let (cons_conditions, alt_conditions) = self.walk_branch_test(&stmt.test);
+      self.walk_branch(&cons_conditions, |this| {
+        this.walk_nested_statement(&stmt.cons)
+      });
       if let Some(alt) = &stmt.alt {
-        self.walk_nested_statement(alt);
+        self.walk_branch(&alt_conditions, |this| this.walk_nested_statement(alt));
       }
     }
   }
@@ -461,6 +466,9 @@ impl JavascriptParser<'_> {
   }
 
   fn walk_update_expression(&mut self, expr: &UpdateExpr) {
+    if let Some(member) = expr.arg.as_member() {
+      self.mutated_member_expressions.insert(member.span());
+    }
     self.walk_expression(&expr.arg)
   }
 
@@ -483,6 +491,11 @@ impl JavascriptParser<'_> {
         return;
       }
     };
+    if expr.op == UnaryOp::Delete
+      && let Some(member) = expr.arg.as_member()
+    {
+      self.mutated_member_expressions.insert(member.span());
+    }
     // TODO: expr.arg belongs chain_expression
     self.walk_expression(&expr.arg)
   }
@@ -690,7 +703,7 @@ impl JavascriptParser<'_> {
     self.top_level_scope = was_top_level;
   }
 
-  fn walk_property(&mut self, prop: &Prop) {
+  pub(crate) fn walk_property(&mut self, prop: &Prop) {
     match prop {
       Prop::Shorthand(ident) => {
         self.in_short_hand = true;
@@ -775,9 +788,9 @@ impl JavascriptParser<'_> {
         self.walk_expression(&expr.alt);
       }
     } else {
-      self.walk_expression(&expr.test);
-      self.walk_expression(&expr.cons);
-      self.walk_expression(&expr.alt);
+      let (cons_conditions, alt_conditions) = self.walk_branch_test(&expr.test);
+      self.walk_branch(&cons_conditions, |this| this.walk_expression(&expr.cons));
+      self.walk_branch(&alt_conditions, |this| this.walk_expression(&expr.alt));
     }
   }
 
@@ -1308,6 +1321,7 @@ impl JavascriptParser<'_> {
       );
       self.walk_assign_target_pattern(pat);
     } else if let Some(SimpleAssignTarget::Member(member)) = expr.left.as_simple() {
+      self.mutated_member_expressions.insert(member.span());
       if let Some(MemberExpressionInfo::Expression(expr_name)) =
         self.get_member_expression_info(ExprRef::Member(member), AllowedMemberTypes::Expression)
         && expr_name
@@ -4,4 +4,16 @@ use rspack_error::Result;
 #[async_trait::async_trait]
 pub trait Backend: std::fmt::Debug + Send + Sync {
   async fn current_active_modules(&mut self) -> Result<IdentifierSet>;
+
+  /// Whether the modules stay active until they are returned by [`Backend::expired_modules`].
+  /// Otherwise the modules returned by [`Backend::current_active_modules`] replace the active
+  /// modules of the last compilation.
+  fn expires_modules(&self) -> bool {
+    false
+  }
+
+  /// The modules that are no longer in use since the last call, which are built as inactive again.
+  async fn expired_modules(&mut self) -> Result<IdentifierSet> {
+    Ok(Default::default())
+  }
 }
@@ -0,0 +1,516 @@
+//! A lazy compilation backend implemented in Rust, which serves the requests of the lazy
+//! compilation clients with a small HTTP server.
+//!
+//! The clients report the modules in use in two ways:
+//! - `POST {prefix}` with the module identifiers separated by newlines in the body, which is what the
+//!   built-in `lazy-compilation-web.js` and `lazy-compilation-node.js` clients send.
+//! - `GET {prefix}{keys}` with the module identifiers encoded by `encodeURIComponent` and joined by
+//!   `@`, the response is an event stream that is kept open while the modules are in use.
+//!
+//! A module is deactivated once it has no open event stream and hasn't been requested for
+//! [`HttpBackendOptions::idle_timeout`]. The modules requested by `POST` never expire, as the clients
+//! only report the modules when they are activated or disposed, not while they are in use.
+
+use std::{
+  fmt,
+  net::{Ipv4Addr, SocketAddr},
+  sync::{Arc, Mutex},
+  time::{Duration, Instant},
+};
+
+use rspack_collections::{IdentifierMap, IdentifierSet};
+use rspack_core::ModuleIdentifier;
+use rspack_error::{Result, error};
+use tokio::{
+  io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  task::{JoinHandle, JoinSet},
+};
+
+use crate::backend::Backend;
+
+/// The default path prefix of the lazy compilation requests.
+pub const LAZY_COMPILATION_PREFIX: &str = "/lazy-compilation-using-";
+
+const MAX_HEADER_SIZE: usize = 16 * 1024;
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+const MAX_EXPIRE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Options for [`HttpBackend::listen`].
+#[derive(Debug, Clone)]
+pub struct HttpBackendOptions {
+  /// The address to listen on, a random port on localhost is used by default.
+  pub addr: SocketAddr,
+
+  /// The path prefix of the requests.
+  pub prefix: String,
+
+  /// How long a module stays active after its last event stream ends, modules never expire if
+  /// `None`. It doesn't apply to the modules requested by `POST`, which never expire.
+  pub idle_timeout: Option<Duration>,
+}
+
+impl Default for HttpBackendOptions {
+  fn default() -> Self {
+    Self {
+      addr: SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+      prefix: LAZY_COMPILATION_PREFIX.to_string(),
+      idle_timeout: None,
+    }
+  }
+}
+
+type InvalidateFn = Box<dyn Fn() + Send + Sync>;
+
+#[derive(Debug)]
+struct ModuleState {
+  connections: usize,
+  last_active: Instant,
+  /// Requested by `POST`, the module is kept active as there is no connection to track
+  persistent: bool,
+}
+
+#[derive(Debug, Default)]
+struct State {
+  modules: IdentifierMap<ModuleState>,
+  /// Modules activated since the last [`Backend::current_active_modules`]
+  activated: IdentifierSet,
+  /// Modules expired since the last [`Backend::expired_modules`]
+  expired: IdentifierSet,
+}
+
+struct Shared {
+  prefix: String,
+  idle_timeout: Option<Duration>,
+  state: Mutex<State>,
+  on_invalidate: Mutex<Option<InvalidateFn>>,
+}
+
+impl Shared {
+  fn invalidate(&self) {
+    if let Some(on_invalidate) = &*self.on_invalidate.lock().expect("should lock") {
+      on_invalidate();
+    }
+  }
+
+  /// Marks the modules as active, one connection is kept for each module if `connect` is true,
+  /// otherwise the modules never expire.
+  fn activate(&self, modules: &[ModuleIdentifier], connect: bool) {
+    let now = Instant::now();
+    let mut new_modules = vec![];
+    {
+      let mut state = self.state.lock().expect("should lock");
+      for module in modules {
+        let module_state = state.modules.entry(*module).or_insert_with(|| {
+          new_modules.push(*module);
+          ModuleState {
+            connections: 0,
+            last_active: now,
+            persistent: false,
+          }
+        });
+        module_state.last_active = now;
+        if connect {
+          module_state.connections += 1;
+        } else {
+          module_state.persistent = true;
+        }
+      }
+      for module in &new_modules {
+        // the module is still built as active if the expiration is not taken yet
+        if !state.expired.remove(module) {
+          state.activated.insert(*module);
+        }
+      }
+    }
+    if !new_modules.is_empty() {
+      self.invalidate();
+    }
+  }
+
+  fn disconnect(&self, modules: &[ModuleIdentifier]) {
+    let now = Instant::now();
+    let mut state = self.state.lock().expect("should lock");
+    for module in modules {
+      if let Some(module_state) = state.modules.get_mut(module) {
+        module_state.connections = module_state.connections.saturating_sub(1);
+        module_state.last_active = now;
+      }
+    }
+  }
+
+  fn expire(&self, idle_timeout: Duration) {
+    let expired = {
+      let mut state = self.state.lock().expect("should lock");
+      let expired = state
+        .modules
+        .iter()
+        .filter(|(_, module_state)| {
+          !module_state.persistent
+            && module_state.connections == 0
+            && module_state.last_active.elapsed() >= idle_timeout
+        })
+        .map(|(module, _)| *module)
+        .collect::<Vec<_>>();
+      for module in &expired {
+        state.modules.remove(module);
+        // the module is never built as active if it's not taken yet
+        if !state.activated.remove(module) {
+          state.expired.insert(*module);
+        }
+      }
+      !expired.is_empty()
+    };
+    if expired {
+      self.invalidate();
+    }
+  }
+}
+
+struct Tasks(Vec<JoinHandle<()>>);
+
+impl Drop for Tasks {
+  fn drop(&mut self) {
+    for task in &self.0 {
+      task.abort();
+    }
+  }
+}
+
+/// A lazy compilation backend that listens for the requests of the clients on a local HTTP server.
+///
+/// The server is shut down once all the clones of the backend are dropped.
+///
+/// ```no_run
+/// use rspack_plugin_lazy_compilation::{HttpBackend, HttpBackendOptions};
+///
+/// # async fn run() -> rspack_error::Result<()> {
+/// let backend = HttpBackend::listen(HttpBackendOptions::default()).await?;
+/// // the request of the client module passed to `LazyCompilationPlugin::new`
+/// let client = backend.client_request("/path/to/lazy-compilation-web.js");
+/// // rebuild when modules are activated or expired, e.g. with the watching of the compiler
+/// backend.on_invalidate(|| println!("lazy compilation modules changed"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HttpBackend {
+  url: String,
+  shared: Arc<Shared>,
+  _tasks: Arc<Tasks>,
+}
+
+impl fmt::Debug for HttpBackend {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("HttpBackend")
+      .field("url", &self.url)
+      .finish()
+  }
+}
+
+impl HttpBackend {
+  /// Starts the HTTP server on the current tokio runtime.
+  pub async fn listen(options: HttpBackendOptions) -> Result<Self> {
+    let listener = TcpListener::bind(options.addr).await.map_err(|e| {
+      error!(
+        "Failed to listen on {} for lazy compilation: {e}",
+        options.addr
+      )
+    })?;
+    let addr = listener
+      .local_addr()
+      .map_err(|e| error!("Failed to get the address of lazy compilation server: {e}"))?;
+
+    let shared = Arc::new(Shared {
+      prefix: options.prefix,
+      idle_timeout: options.idle_timeout,
+      state: Default::default(),
+      on_invalidate: Default::default(),
+    });
+
+    let mut tasks = vec![tokio::spawn(accept(listener, shared.clone()))];
+    if let Some(idle_timeout) = options.idle_timeout {
+      tasks.push(tokio::spawn(expire(shared.clone(), idle_timeout)));
+    }
+
+    Ok(Self {
+      url: format!("http://{addr}{}", shared.prefix),
+      shared,
+      _tasks: Arc::new(Tasks(tasks)),
+    })
+  }
+
+  /// The url the clients should send requests to.
+  pub fn url(&self) -> &str {
+    &self.url
+  }
+
+  /// The request of the client module with the url of this backend, which is passed to
+  /// `LazyCompilationPlugin::new` as the client.
+  pub fn client_request(&self, client: &str) -> String {
+    format!("{client}?{}", urlencoding::encode(&self.url))
+  }
+
+  /// Sets the callback to be called when modules are activated or expired, which should trigger a
+  /// rebuild of the compiler.
+  pub fn on_invalidate(&self, on_invalidate: impl Fn() + Send + Sync + 'static) {
+    *self.shared.on_invalidate.lock().expect("should lock") = Some(Box::new(on_invalidate));
+  }
+}
+
+#[async_trait::async_trait]
+impl Backend for HttpBackend {
+  fn expires_modules(&self) -> bool {
+    true
+  }
+
+  async fn current_active_modules(&mut self) -> Result<IdentifierSet> {
+    Ok(std::mem::take(
+      &mut self.shared.state.lock().expect("should lock").activated,
+    ))
+  }
+
+  async fn expired_modules(&mut self) -> Result<IdentifierSet> {
+    if self.shared.idle_timeout.is_none() {
+      return Ok(Default::default());
+    }
+    Ok(std::mem::take(
+      &mut self.shared.state.lock().expect("should lock").expired,
+    ))
+  }
+}
+
+async fn accept(listener: TcpListener, shared: Arc<Shared>) {
+  // the connections are aborted with the accepting task when the backend is dropped
+  let mut connections = JoinSet::new();
+  loop {
+    tokio::select! {
+      accepted = listener.accept() => {
+        let stream = match accepted {
+          Ok((stream, _)) => stream,
+          Err(e) => {
+            tracing::debug!("lazy compilation server failed to accept: {e}");
+            // e.g. too many open files, retrying immediately would only spin
+            tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+            continue;
+          }
+        };
+        let shared = shared.clone();
+        connections.spawn(async move {
+          if let Err(e) = handle_connection(stream, &shared).await {
+            tracing::debug!("lazy compilation request failed: {e}");
+          }
+        });
+      }
+      Some(_) = connections.join_next(), if !connections.is_empty() => {}
+    }
+  }
+}
+
+async fn expire(shared: Arc<Shared>, idle_timeout: Duration) {
+  let mut interval = tokio::time::interval(
+    (idle_timeout / 2).clamp(Duration::from_millis(10), MAX_EXPIRE_CHECK_INTERVAL),
+  );
+  loop {
+    interval.tick().await;
+    shared.expire(idle_timeout);
+  }
+}
+
+const CORS_HEADERS: &str = "Access-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: GET, POST, OPTIONS\r\nAccess-Control-Allow-Headers: Content-Type\r\n";
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+  let response = format!(
+    "HTTP/1.1 {status}\r\n{CORS_HEADERS}Content-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+    body.len()
+  );
+  stream.write_all(response.as_bytes()).await?;
+  stream.shutdown().await
+}
+
+async fn handle_connection(mut stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+  let Some(request) = Request::read(&mut stream).await? else {
+    return respond(&mut stream, "400 Bad Request", "Bad Request").await;
+  };
+  let path = request.path.split('?').next().unwrap_or_default();
+  if request.method == "OPTIONS" {
+    return respond(&mut stream, "204 No Content", "").await;
+  }
+  let Some(keys) = path.strip_prefix(shared.prefix.as_str()) else {
+    return respond(&mut stream, "404 Not Found", "Not Found").await;
+  };
+
+  match request.method.as_str() {
+    "POST" => {
+      let modules = String::from_utf8_lossy(&request.body)
+        .split('\n')
+        .filter(|key| !key.is_empty())
+        .map(ModuleIdentifier::from)
+        .collect::<Vec<_>>();
+      shared.activate(&modules, false);
+      respond(&mut stream, "200 OK", "\n").await
+    }
+    "GET" => {
+      let modules = keys
+        .split('@')
+        .filter(|key| !key.is_empty())
+        .map(|key| {
+          urlencoding::decode(key)
+            .map(|key| ModuleIdentifier::from(key.as_ref()))
+            .unwrap_or_else(|_| ModuleIdentifier::from(key))
+        })
+        .collect::<Vec<_>>();
+      let response = format!(
+        "HTTP/1.1 200 OK\r\n{CORS_HEADERS}Content-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n"
+      );
+      stream.write_all(response.as_bytes()).await?;
+      shared.activate(&modules, true);
+      let result = keep_alive(&mut stream).await;
+      shared.disconnect(&modules);
+      result
+    }
+    _ => respond(&mut stream, "405 Method Not Allowed", "Method Not Allowed").await,
+  }
+}
+
+/// Keeps the event stream open until the client disconnects.
+async fn keep_alive(stream: &mut TcpStream) -> std::io::Result<()> {
+  let (mut reader, mut writer) = stream.split();
+  let mut buf = [0u8; 1024];
+  loop {
+    tokio::select! {
+      read = reader.read(&mut buf) => {
+        if read? == 0 {
+          return Ok(());
+        }
+      }
+      _ = tokio::time::sleep(KEEP_ALIVE_INTERVAL) => {
+        writer.write_all(b": keep-alive\n\n").await?;
+      }
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Request {
+  method: String,
+  path: String,
+  body: Vec<u8>,
+}
+
+impl Request {
+  /// Reads a HTTP/1.1 request, returns `None` if the request is malformed.
+  async fn read(stream: &mut (impl AsyncRead + Unpin)) -> std::io::Result<Option<Self>> {
+    let mut buf = Vec::new();
+    let header_end = loop {
+      if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+        break pos;
+      }
+      if buf.len() > MAX_HEADER_SIZE {
+        return Ok(None);
+      }
+      let mut chunk = [0u8; 1024];
+      let read = stream.read(&mut chunk).await?;
+      if read == 0 {
+        return Ok(None);
+      }
+      buf.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut rest = buf.split_off(header_end + 4);
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+      return Ok(None);
+    };
+
+    let mut content_length = None;
+    let mut chunked = false;
+    for line in lines {
+      let Some((name, value)) = line.split_once(':') else {
+        continue;
+      };
+      let value = value.trim();
+      if name.eq_ignore_ascii_case("content-length") {
+        content_length = value.parse::<usize>().ok();
+      } else if name.eq_ignore_ascii_case("transfer-encoding") {
+        chunked = value.eq_ignore_ascii_case("chunked");
+      }
+    }
+
+    let body = if chunked {
+      match read_chunked_body(stream, rest).await? {
+        Some(body) => body,
+        None => return Ok(None),
+      }
+    } else {
+      let content_length = content_length.unwrap_or_default();
+      if content_length > MAX_BODY_SIZE {
+        return Ok(None);
+      }
+      while rest.len() < content_length {
+        let mut chunk = [0u8; 1024];
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+          return Ok(None);
+        }
+        rest.extend_from_slice(&chunk[..read]);
+      }
+      rest.truncate(content_length);
+      rest
+    };
+
+    Ok(Some(Self {
+      method: method.to_string(),
+      path: path.to_string(),
+      body,
+    }))
+  }
+}
+
+/// Decodes a body with `Transfer-Encoding: chunked`, which is used by the node client.
+async fn read_chunked_body(
+  stream: &mut (impl AsyncRead + Unpin),
+  mut buf: Vec<u8>,
+) -> std::io::Result<Option<Vec<u8>>> {
+  let mut body = Vec::new();
+  let mut pos = 0;
+  loop {
+    let Some(line_end) = buf[pos..].windows(2).position(|w| w == b"\r\n") else {
+      if buf.len() - pos > MAX_HEADER_SIZE || !fill(stream, &mut buf).await? {
+        return Ok(None);
+      }
+      continue;
+    };
+    let size_line = String::from_utf8_lossy(&buf[pos..pos + line_end]);
+    let size = size_line.split(';').next().unwrap_or_default().trim();
+    let Ok(size) = usize::from_str_radix(size, 16) else {
+      return Ok(None);
+    };
+    if body.len() + size > MAX_BODY_SIZE {
+      return Ok(None);
+    }
+    let data_start = pos + line_end + 2;
+    // the chunk data is followed by CRLF
+    while buf.len() < data_start + size + 2 {
+      if !fill(stream, &mut buf).await? {
+        return Ok(None);
+      }
+    }
+    if size == 0 {
+      return Ok(Some(body));
+    }
+    body.extend_from_slice(&buf[data_start..data_start + size]);
+    pos = data_start + size + 2;
+  }
+}
+
+async fn fill(stream: &mut (impl AsyncRead + Unpin), buf: &mut Vec<u8>) -> std::io::Result<bool> {
+  let mut chunk = [0u8; 1024];
+  let read = stream.read(&mut chunk).await?;
+  buf.extend_from_slice(&chunk[..read]);
+  Ok(read != 0)
+}
@@ -1,9 +1,11 @@
 mod backend;
 mod dependency;
 mod factory;
+mod http_backend;
 mod module;
 mod plugin;
 mod utils;
 
 pub use backend::Backend;
+pub use http_backend::{HttpBackend, HttpBackendOptions, LAZY_COMPILATION_PREFIX};
 pub use plugin::{LazyCompilationPlugin, LazyCompilationTest, LazyCompilationTestCheck};
@@ -228,22 +228,36 @@ async fn normal_module_factory_module(
 
 #[plugin_hook(CompilerMake for LazyCompilationPlugin<T: Backend, F: LazyCompilationTestCheck>)]
 async fn compiler_make(&self, compilation: &mut Compilation) -> Result<()> {
-  let active_modules = self.backend.lock().await.current_active_modules().await?;
+  let (active_modules, expired_modules, expires_modules) = {
+    let mut backend = self.backend.lock().await;
+    (
+      backend.current_active_modules().await?,
+      backend.expired_modules().await?,
+      backend.expires_modules(),
+    )
+  };
   let module_graph = compilation
     .build_module_graph_artifact
     .get_module_graph_mut();
-  for module_id in &active_modules {
-    let Some(active_module) = module_graph.module_by_identifier_mut(module_id) else {
+  for module_id in active_modules.iter().chain(expired_modules.iter()) {
+    let Some(module) = module_graph.module_by_identifier_mut(module_id) else {
       continue;
     };
-    let Some(active_module) = active_module.downcast_mut::<LazyCompilationProxyModule>() else {
+    let Some(module) = module.downcast_mut::<LazyCompilationProxyModule>() else {
       continue;
     };
 
-    active_module.invalid();
+    module.invalid();
   }
 
-  *self.active_modules.write().await = active_modules.into_iter().collect();
+  let mut current_active_modules = self.active_modules.write().await;
+  if expires_modules {
+    // the modules stay active until they are expired by the backend
+    current_active_modules.retain(|module| !expired_modules.contains(module));
+    current_active_modules.extend(active_modules);
+  } else {
+    *current_active_modules = active_modules;
+  }
 
   Ok(())
 }
@@ -0,0 +1,150 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{
+  sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  },
+  time::Duration,
+};
+
+use rspack_collections::Identifier;
+use rspack_plugin_lazy_compilation::{Backend, HttpBackend, HttpBackendOptions};
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpStream,
+};
+
+const MODULE: &str = "lazy-compilation-proxy|/src/a.js";
+
+async fn connect(backend: &HttpBackend) -> (TcpStream, String) {
+  let url = backend.url().strip_prefix("http://").unwrap();
+  let (host, path) = url.split_at(url.find('/').unwrap());
+  (TcpStream::connect(host).await.unwrap(), path.to_string())
+}
+
+async fn read_head(stream: &mut TcpStream) -> String {
+  let mut buf = vec![];
+  while !buf.ends_with(b"\r\n\r\n") {
+    let mut byte = [0u8; 1];
+    assert_eq!(stream.read(&mut byte).await.unwrap(), 1);
+    buf.push(byte[0]);
+  }
+  String::from_utf8(buf).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn activate_by_post() {
+  let mut backend = HttpBackend::listen(HttpBackendOptions::default())
+    .await
+    .unwrap();
+  let invalidated = Arc::new(AtomicUsize::new(0));
+  let counter = invalidated.clone();
+  backend.on_invalidate(move || {
+    counter.fetch_add(1, Ordering::SeqCst);
+  });
+
+  // the node client sends the body in chunks
+  let (mut stream, path) = connect(&backend).await;
+  let body = format!("{:x}\r\n{MODULE}\n\r\n0\r\n\r\n", MODULE.len() + 1);
+  stream
+    .write_all(
+      format!("POST {path} HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n{body}").as_bytes(),
+    )
+    .await
+    .unwrap();
+  assert!(read_head(&mut stream).await.starts_with("HTTP/1.1 200 OK"));
+
+  assert_eq!(invalidated.load(Ordering::SeqCst), 1);
+  let active_modules = backend.current_active_modules().await.unwrap();
+  assert!(active_modules.contains(&Identifier::from(MODULE)));
+  // the modules are only reported once
+  assert!(backend.current_active_modules().await.unwrap().is_empty());
+  // the modules never expire by default
+  assert!(backend.expired_modules().await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn expire_after_disconnect() {
+  let mut backend = HttpBackend::listen(HttpBackendOptions {
+    idle_timeout: Some(Duration::from_millis(50)),
+    ..Default::default()
+  })
+  .await
+  .unwrap();
+
+  let (mut stream, path) = connect(&backend).await;
+  let key = urlencoding::encode(MODULE);
+  stream
+    .write_all(format!("GET {path}{key} HTTP/1.1\r\nAccept: text/event-stream\r\n\r\n").as_bytes())
+    .await
+    .unwrap();
+  let head = read_head(&mut stream).await;
+  assert!(head.contains("Content-Type: text/event-stream"));
+
+  let active_modules = backend.current_active_modules().await.unwrap();
+  assert!(active_modules.contains(&Identifier::from(MODULE)));
+
+  // the module stays active while the event stream is open
+  tokio::time::sleep(Duration::from_millis(200)).await;
+  assert!(backend.expired_modules().await.unwrap().is_empty());
+
+  drop(stream);
+  tokio::time::sleep(Duration::from_millis(200)).await;
+  let expired_modules = backend.expired_modules().await.unwrap();
+  assert!(expired_modules.contains(&Identifier::from(MODULE)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn never_expire_by_post() {
+  let mut backend = HttpBackend::listen(HttpBackendOptions {
+    idle_timeout: Some(Duration::from_millis(50)),
+    ..Default::default()
+  })
+  .await
+  .unwrap();
+
+  let (mut stream, path) = connect(&backend).await;
+  stream
+    .write_all(
+      format!(
+        "POST {path} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{MODULE}",
+        MODULE.len()
+      )
+      .as_bytes(),
+    )
+    .await
+    .unwrap();
+  assert!(read_head(&mut stream).await.starts_with("HTTP/1.1 200 OK"));
+  drop(stream);
+
+  let active_modules = backend.current_active_modules().await.unwrap();
+  assert!(active_modules.contains(&Identifier::from(MODULE)));
+
+  // the clients don't report the modules in use again, so they are kept active
+  tokio::time::sleep(Duration::from_millis(200)).await;
+  assert!(backend.expired_modules().await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn close_connections_on_drop() {
+  let backend = HttpBackend::listen(HttpBackendOptions::default())
+    .await
+    .unwrap();
+
+  let (mut stream, path) = connect(&backend).await;
+  let key = urlencoding::encode(MODULE);
+  stream
+    .write_all(format!("GET {path}{key} HTTP/1.1\r\nAccept: text/event-stream\r\n\r\n").as_bytes())
+    .await
+    .unwrap();
+  read_head(&mut stream).await;
+
+  drop(backend);
+  let mut buf = [0u8; 64];
+  let read = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf))
+    .await
+    .unwrap();
+  // the event stream is closed instead of being kept alive
+  assert!(matches!(read, Ok(0) | Err(_)));
+}
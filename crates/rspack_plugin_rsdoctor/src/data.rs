@@ -164,6 +164,8 @@ pub struct RsdoctorModuleGraph {
   pub modules: Vec<RsdoctorModule>,
   pub dependencies: Vec<RsdoctorDependency>,
   pub chunk_modules: Vec<RsdoctorChunkModules>,
+  /// The statements that keep the modules used only for their side effects
+  pub side_effects: Vec<RsdoctorSideEffect>,
 }
 
 #[derive(Debug, Default)]
@@ -4,7 +4,7 @@ use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
 use rspack_collections::{Identifier, IdentifierMap};
 use rspack_core::{
   BoxModule, ChunkGraph, Compilation, Context, DependencyId, DependencyType, Module, ModuleGraph,
-  ModuleIdsArtifact,
+  ModuleIdsArtifact, RealDependencyLocation,
   rspack_sources::{MapOptions, ObjectPool},
 };
 use rspack_paths::Utf8PathBuf;
@@ -13,7 +13,8 @@ use thread_local::ThreadLocal;
 
 use crate::{
   ChunkUkey, ModuleKind, ModuleUkey, RsdoctorDependency, RsdoctorModule, RsdoctorModuleId,
-  RsdoctorModuleOriginalSource,
+  RsdoctorModuleOriginalSource, RsdoctorSideEffect, RsdoctorSourcePosition, RsdoctorSourceRange,
+  RsdoctorStatement,
 };
 
 pub fn collect_modules(
@@ -222,6 +223,56 @@ pub fn collect_module_dependencies(
     .collect::<HashMap<_, _>>()
 }
 
+/// Collects the statements that make the modules included, for the modules that are used only
+/// for their side effects
+pub fn collect_module_side_effects(
+  modules: &IdentifierMap<&BoxModule>,
+  module_ukeys: &HashMap<Identifier, ModuleUkey>,
+  module_graph: &ModuleGraph,
+) -> Vec<RsdoctorSideEffect> {
+  let side_effect_ukey_counter = Arc::new(AtomicI32::new(0));
+
+  modules
+    .par_iter()
+    .filter_map(|(module_id, module)| {
+      let side_effects_bailout = module.build_info().side_effects_bailout.as_ref()?;
+      let module_ukey = *module_ukeys.get(module_id)?;
+      if !module_graph.is_used_only_for_side_effects(module_id, None) {
+        return None;
+      }
+
+      let side_effect_ukey =
+        side_effect_ukey_counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+      Some(RsdoctorSideEffect {
+        ukey: side_effect_ukey,
+        name: side_effects_bailout.statement.clone(),
+        module: module_ukey,
+        identifier: RsdoctorStatement {
+          module: module_ukey,
+          source_position: Some(to_source_range(&side_effects_bailout.loc)),
+          transformed_position: to_source_range(&side_effects_bailout.loc),
+        },
+        ..Default::default()
+      })
+    })
+    .collect::<Vec<_>>()
+}
+
+fn to_source_range(loc: &RealDependencyLocation) -> RsdoctorSourceRange {
+  RsdoctorSourceRange {
+    start: RsdoctorSourcePosition {
+      line: Some(loc.start.line as i32),
+      column: Some(loc.start.column as i32),
+      index: None,
+    },
+    end: loc.end.map(|end| RsdoctorSourcePosition {
+      line: Some(end.line as i32),
+      column: Some(end.column as i32),
+      index: None,
+    }),
+  }
+}
+
 pub fn collect_module_ids(
   modules: &IdentifierMap<&BoxModule>,
   module_ukeys: &HashMap<Identifier, ModuleUkey>,
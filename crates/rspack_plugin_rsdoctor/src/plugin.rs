@@ -31,7 +31,7 @@ use crate::{
   },
   module_graph::{
     collect_concatenated_modules, collect_module_dependencies, collect_module_ids,
-    collect_module_original_sources, collect_modules,
+    collect_module_original_sources, collect_module_side_effects, collect_modules,
   },
 };
 
@@ -359,6 +359,9 @@ async fn optimize_chunk_modules(&self, compilation: &mut Compilation) -> Result<
   let chunk_modules =
     collect_chunk_modules(chunk_by_ukey, &module_ukey_map, chunk_graph, module_graph);
 
+  // 7. collect the side effects of the modules used only for side effects
+  let side_effects = collect_module_side_effects(&modules, &module_ukey_map, module_graph);
+
   tokio::spawn(async move {
     match hooks
       .borrow()
@@ -367,6 +370,7 @@ async fn optimize_chunk_modules(&self, compilation: &mut Compilation) -> Result<
         modules: rsd_modules.into_values().collect::<Vec<_>>(),
         dependencies: rsd_dependencies.into_values().collect::<Vec<_>>(),
         chunk_modules,
+        side_effects,
       })
       .await
     {
@@ -4,14 +4,25 @@ mod max_size;
 mod min_size;
 mod module_group;
 
-use std::{borrow::Cow, cmp::Ordering, fmt::Debug};
+use std::{
+  borrow::Cow,
+  cmp::Ordering,
+  fmt::Debug,
+  hash::{Hash, Hasher},
+};
 
 use itertools::Itertools;
+use rayon::prelude::*;
 use rspack_collections::{DatabaseItem, IdentifierMap, UkeyMap, UkeySet};
-use rspack_core::{ChunkUkey, Compilation, CompilationOptimizeChunks, Logger, Plugin};
+use rspack_core::{
+  ChunkUkey, Compilation, CompilationOptimizeChunks, Logger, Plugin, SplitChunksArtifact,
+  SplitChunksModuleEntry, get_runtime_key,
+  incremental::{self, IncrementalPasses},
+};
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::{fx_hash::FxIndexMap, tracing_preset::TRACING_BENCH_TARGET};
+use rustc_hash::FxHasher;
 use tracing::instrument;
 
 use crate::{
@@ -48,6 +59,7 @@ impl SplitChunksPlugin {
   #[instrument(name = "Compilation:SplitChunks",target=TRACING_BENCH_TARGET, skip_all)]
   async fn inner_impl(&self, compilation: &mut Compilation) -> Result<()> {
     let logger = compilation.get_logger(self.name());
+    let mut previous_artifact = std::mem::take(&mut compilation.split_chunks_artifact);
     let start = logger.time("prepare module data");
 
     let mut all_modules = compilation
@@ -140,21 +152,94 @@ impl SplitChunksPlugin {
 
     logger.time_end(start);
 
+    let fingerprint = Self::get_chunks_fingerprint(compilation, &chunk_index_map, &combinator);
+    let affected_modules = if let Some(mutations) = compilation
+      .incremental
+      .mutations_read(IncrementalPasses::SPLIT_CHUNKS)
+    {
+      let modules =
+        mutations.get_affected_modules_with_module_graph(compilation.get_module_graph());
+      tracing::debug!(target: incremental::TRACING_TARGET, passes = %IncrementalPasses::SPLIT_CHUNKS, %mutations, ?modules);
+      // all modules need to be matched again if any chunk changed
+      (previous_artifact.fingerprint == fingerprint).then_some(modules)
+    } else {
+      None
+    };
+    let save_matches = compilation
+      .incremental
+      .passes_enabled(IncrementalPasses::SPLIT_CHUNKS);
+    let mut split_chunks_artifact = SplitChunksArtifact {
+      fingerprint,
+      matches: vec![],
+    };
+    let mut stats_reused_modules = 0;
+    let mut stats_matched_modules = 0;
+
     let start = logger.time("process cache groups");
     let priority_len = priority_cache_groups.len();
     for (index, (_, cache_groups)) in priority_cache_groups.into_iter().enumerate() {
-      let mut module_group_map = self
-        .prepare_module_group_map(
+      let mut previous_matches = previous_artifact
+        .matches
+        .get_mut(index)
+        .map(std::mem::take)
+        .unwrap_or_default();
+      let fingerprints = all_modules
+        .par_iter()
+        .map(|module| {
+          (
+            *module,
+            combinator.get_module_fingerprint(
+              module,
+              &module_chunks,
+              &removed_module_chunks,
+              &chunk_index_map,
+            ),
+          )
+        })
+        .collect::<IdentifierMap<_>>();
+
+      let mut module_matches = IdentifierMap::default();
+      let mut modules_to_match = vec![];
+      for module in &all_modules {
+        if let Some(affected_modules) = &affected_modules
+          && !affected_modules.contains(module)
+          && let Some(entry) = previous_matches.remove(module)
+          && entry.fingerprint == fingerprints[module]
+        {
+          module_matches.insert(*module, entry);
+        } else {
+          modules_to_match.push(*module);
+        }
+      }
+      stats_reused_modules += module_matches.len();
+      stats_matched_modules += modules_to_match.len();
+
+      for (module, matches) in self
+        .match_modules(
           &combinator,
-          &all_modules,
-          cache_groups,
+          &modules_to_match,
+          &cache_groups,
           &removed_module_chunks,
           compilation,
           &module_chunks,
           &chunk_index_map,
         )
-        .await?;
+        .await?
+      {
+        module_matches.insert(
+          module,
+          SplitChunksModuleEntry {
+            fingerprint: fingerprints[&module],
+            matches,
+          },
+        );
+      }
+
+      let mut module_group_map = self.create_module_group_map(&all_modules, &module_matches);
       tracing::trace!("prepared module_group_map {:#?}", module_group_map);
+      if save_matches {
+        split_chunks_artifact.matches.push(module_matches);
+      }
 
       self.ensure_min_size_fit(&mut module_group_map, &module_sizes);
 
@@ -267,6 +352,14 @@ impl SplitChunksPlugin {
     }
     logger.time_end(start);
 
+    if affected_modules.is_some() {
+      let logger = compilation.get_logger("rspack.incremental.splitChunks");
+      logger.log(format!(
+        "{stats_reused_modules} module matches are reused, {stats_matched_modules} modules are matched again"
+      ));
+    }
+    compilation.split_chunks_artifact = split_chunks_artifact;
+
     let start = logger.time("ensure max size fit");
     self
       .ensure_max_size_fit(compilation, &max_size_setting_map)
@@ -279,6 +372,33 @@ impl SplitChunksPlugin {
   }
 }
 
+impl SplitChunksPlugin {
+  /// Hash all chunks that modules can be split from, the module groups are calculated from scratch
+  /// when any chunk is added, removed or renamed
+  fn get_chunks_fingerprint(
+    compilation: &Compilation,
+    chunk_index_map: &UkeyMap<ChunkUkey, u64>,
+    combinator: &module_group::Combinator,
+  ) -> u64 {
+    let mut hasher = FxHasher::default();
+    for (chunk_ukey, index) in chunk_index_map
+      .iter()
+      .sorted_unstable_by_key(|(_, index)| **index)
+    {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      index.hash(&mut hasher);
+      chunk_ukey.as_u32().hash(&mut hasher);
+      chunk.name().hash(&mut hasher);
+      get_runtime_key(chunk.runtime()).hash(&mut hasher);
+      chunk
+        .can_be_initial(&compilation.chunk_group_by_ukey)
+        .hash(&mut hasher);
+    }
+    combinator.hash(&mut hasher);
+    hasher.finish()
+  }
+}
+
 impl Debug for SplitChunksPlugin {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("SplitChunksPlugin").finish()
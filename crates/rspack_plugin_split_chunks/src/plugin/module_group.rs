@@ -3,13 +3,13 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use dashmap::DashMap;
 use futures::future::join_all;
 use rayon::prelude::*;
 use rspack_collections::{IdentifierMap, UkeyIndexMap, UkeyMap, UkeySet};
 use rspack_core::{
   ChunkByUkey, ChunkUkey, Compilation, Module, ModuleGraph, ModuleIdentifier,
-  PrefetchExportsInfoMode, RuntimeKeyMap, UsageKey, get_runtime_key,
+  PrefetchExportsInfoMode, RuntimeKeyMap, SplitChunksModuleEntry, SplitChunksModuleMatch, UsageKey,
+  get_runtime_key,
 };
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_util::tracing_preset::TRACING_BENCH_TARGET;
@@ -67,6 +67,41 @@ pub(crate) struct Combinator {
 }
 
 impl Combinator {
+  /// Hash the chunk sets in graph, which decide the combinations of every module
+  pub(crate) fn hash(&self, hasher: &mut impl Hasher) {
+    for combinations in [&self.combinations, &self.used_exports_combinations] {
+      let mut keys = combinations.keys().copied().collect::<Vec<_>>();
+      keys.sort_unstable();
+      keys.hash(hasher);
+    }
+  }
+
+  /// Get the fingerprint of the chunks of a module, the module groups of the module
+  /// are unchanged if the fingerprint and the module itself are unchanged
+  pub(crate) fn get_module_fingerprint(
+    &self,
+    module: &ModuleIdentifier,
+    module_chunks: &ModuleChunks,
+    removed_module_chunks: &IdentifierMap<UkeySet<ChunkUkey>>,
+    chunk_index_map: &UkeyMap<ChunkUkey, u64>,
+  ) -> u64 {
+    let mut hasher = FxHasher::default();
+    let chunks = module_chunks
+      .get(module)
+      .expect("should have module chunks");
+    get_key(chunks.iter().copied(), chunk_index_map).hash(&mut hasher);
+    removed_module_chunks
+      .get(module)
+      .map(|chunks| get_key(chunks.iter().copied(), chunk_index_map))
+      .hash(&mut hasher);
+    if let Some(chunks_keys) = self.grouped_by_exports.get(module) {
+      let mut chunks_keys = chunks_keys.clone();
+      chunks_keys.sort_unstable();
+      chunks_keys.hash(&mut hasher);
+    }
+    hasher.finish()
+  }
+
   fn group_chunks_by_exports(
     module_identifier: &ModuleIdentifier,
     module_chunks: impl Iterator<Item = ChunkUkey>,
@@ -286,31 +321,62 @@ impl SplitChunksPlugin {
     (best_entry_key, best_module_group)
   }
 
+  /// Create the module groups from the module groups that each module is matched into
+  pub(crate) fn create_module_group_map(
+    &self,
+    all_modules: &[ModuleIdentifier],
+    module_matches: &IdentifierMap<SplitChunksModuleEntry>,
+  ) -> ModuleGroupMap {
+    let mut module_group_map = ModuleGroupMap::default();
+    for module in all_modules {
+      let Some(entry) = module_matches.get(module) else {
+        continue;
+      };
+      for matched in &entry.matches {
+        let module_group = module_group_map
+          .entry(matched.key.clone())
+          .or_insert_with(|| {
+            ModuleGroup::new(
+              matched.chunk_name.clone(),
+              matched.cache_group_index,
+              &self.cache_groups[matched.cache_group_index],
+            )
+          });
+        module_group.add_module(*module);
+        module_group.chunks.extend(matched.chunks.iter().copied());
+      }
+    }
+    // Sort the module_group_map by key to ensure deterministic iteration order
+    module_group_map.sort_unstable_keys();
+    module_group_map
+  }
+
+  /// Match modules with the cache groups and get the module groups that each module is matched into
   #[allow(clippy::too_many_arguments)]
-  #[instrument(name = "Compilation:SplitChunks:prepare_module_group_map",target=TRACING_BENCH_TARGET, skip_all)]
-  pub(crate) async fn prepare_module_group_map(
+  #[instrument(name = "Compilation:SplitChunks:match_modules",target=TRACING_BENCH_TARGET, skip_all)]
+  pub(crate) async fn match_modules(
     &self,
     combinator: &Combinator,
-    all_modules: &[ModuleIdentifier],
-    cache_groups: Vec<IndexedCacheGroup<'_>>,
+    modules: &[ModuleIdentifier],
+    cache_groups: &[IndexedCacheGroup<'_>],
     removed_module_chunks: &IdentifierMap<UkeySet<ChunkUkey>>,
     compilation: &Compilation,
     module_chunks: &ModuleChunks,
     chunk_index_map: &UkeyMap<ChunkUkey, u64>,
-  ) -> Result<ModuleGroupMap> {
+  ) -> Result<Vec<(ModuleIdentifier, Vec<SplitChunksModuleMatch>)>> {
     let module_graph = compilation.get_module_graph();
-    let module_group_map: DashMap<String, ModuleGroup> = DashMap::default();
-    let module_group_results = rspack_futures::scope::<_, Result<_>>(|token| {
-      all_modules.iter().for_each(|mid| {
-        let s = unsafe { token.used((&cache_groups, mid, &module_graph, compilation, &module_group_map, &combinator, module_chunks, removed_module_chunks, chunk_index_map)) };
-        s.spawn(|(cache_groups, mid, module_graph, compilation, module_group_map, combinator, module_chunks, removed_module_chunks, chunk_index_map)| async move {
+    let module_match_results = rspack_futures::scope::<_, Result<_>>(|token| {
+      modules.iter().for_each(|mid| {
+        let s = unsafe { token.used((cache_groups, mid, &module_graph, compilation, &combinator, module_chunks, removed_module_chunks, chunk_index_map)) };
+        s.spawn(|(cache_groups, mid, module_graph, compilation, combinator, module_chunks, removed_module_chunks, chunk_index_map)| async move {
+          let mut matches = vec![];
           let belong_to_chunks = module_chunks.get(mid).expect("should have module chunks");
           if belong_to_chunks.is_empty() {
-            return Ok(());
+            return Ok((*mid, matches));
           }
 
           if let Some(removed_chunks) = removed_module_chunks.get(mid) && belong_to_chunks.iter().all(|c| removed_chunks.contains(c)) {
-            return Ok(());
+            return Ok((*mid, matches));
           }
           let module = module_graph.module_by_identifier(mid).expect("should have module").as_ref();
           let mut filtered = vec![];
@@ -426,20 +492,19 @@ impl SplitChunksPlugin {
               if selected_chunks.iter().any(|c| removed_module_chunks.get(mid).is_some_and(|chunks| chunks.contains(c))) {
                 continue;
               }
-              merge_matched_item_into_module_group_map(
+              matches.push(get_module_match(
                 MatchedItem {
                   module,
                   cache_group,
                   cache_group_index: *cache_group_index,
                   selected_chunks,
                 },
-                module_group_map,
                 compilation,
                 chunk_index_map,
-              ).await?;
+              ).await?);
             }
           }
-          Ok(())
+          Ok((*mid, matches))
         });
       })
     })
@@ -447,14 +512,7 @@ impl SplitChunksPlugin {
     .into_iter().map(|r| r.to_rspack_result())
     .collect::<Result<Vec<_>>>()?;
 
-    for result in module_group_results {
-      result?;
-    }
-
-    // Sort the module_group_map by key to ensure deterministic iteration order
-    let mut result: Vec<_> = module_group_map.into_iter().collect();
-    result.sort_by(|a, b| a.0.cmp(&b.0));
-    Ok(result.into_iter().collect())
+    module_match_results.into_iter().collect()
   }
 
   // #[tracing::instrument(skip_all)]
@@ -544,12 +602,11 @@ impl SplitChunksPlugin {
   }
 }
 
-async fn merge_matched_item_into_module_group_map(
+async fn get_module_match(
   matched_item: MatchedItem<'_>,
-  module_group_map: &DashMap<String, ModuleGroup>,
   compilation: &Compilation,
   chunk_index_map: &UkeyMap<ChunkUkey, u64>,
-) -> Result<()> {
+) -> Result<SplitChunksModuleMatch> {
   let MatchedItem {
     module,
     cache_group_index,
@@ -585,13 +642,10 @@ async fn merge_matched_item_into_module_group_map(
     )
   };
 
-  let mut module_group = {
-    module_group_map
-      .entry(key)
-      .or_insert_with(|| ModuleGroup::new(chunk_name, cache_group_index, cache_group))
-  };
-  module_group.add_module(module.identifier());
-  module_group.chunks.extend(selected_chunks.iter().copied());
-
-  Ok(())
+  Ok(SplitChunksModuleMatch {
+    key,
+    chunk_name,
+    cache_group_index,
+    chunks: selected_chunks,
+  })
 }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 mod error;
 use error::FsResultToStorageFsResult;
 pub use error::{BatchFSError, BatchFSResult, FSError, FSOperation, FSResult};
-use rspack_fs::{FileMetadata, IntermediateFileSystem, ReadStream, WriteStream};
+use rspack_fs::{FileMetadata, IntermediateFileSystem, MemoryReadStream, ReadStream, WriteStream};
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::FxHashSet as HashSet;
 
@@ -71,6 +71,13 @@ pub struct Reader {
 }
 
 impl Reader {
+  /// Create a reader of the contents in memory, such as a decompressed file
+  pub fn from_contents(path: &Utf8Path, contents: Vec<u8>) -> Self {
+    Self {
+      path: path.to_path_buf(),
+      stream: Box::new(MemoryReadStream::new(contents)),
+    }
+  }
   pub async fn read_line(&mut self) -> FSResult<String> {
     self
       .stream
@@ -11,4 +11,60 @@ pub struct RootOptions {
   pub root: Utf8PathBuf,
   pub expire: u64,
   pub clean: bool,
+  /// The max total size of all versions in root,
+  /// the least recently saved versions will be removed when it is exceeded,
+  /// and then the oldest packs of the current version.
+  pub max_size: Option<u64>,
+}
+
+/// The compression algorithm of pack files
+///
+/// The algorithm is recorded in the header of each pack file,
+/// so packs written with different compression can be read by any storage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackCompression {
+  #[default]
+  None,
+  Lz4,
+  Zstd,
+}
+
+impl PackCompression {
+  const LZ4_HEADER: &'static str = "#lz4";
+  const ZSTD_HEADER: &'static str = "#zstd";
+
+  /// Get the first line of compressed pack files
+  pub fn header(&self) -> Option<&'static str> {
+    match self {
+      Self::None => None,
+      Self::Lz4 => Some(Self::LZ4_HEADER),
+      Self::Zstd => Some(Self::ZSTD_HEADER),
+    }
+  }
+
+  /// Get the compression from the first line of pack files,
+  /// the first line of uncompressed pack files is the key lengths which never starts with `#`.
+  pub fn from_header(line: &str) -> Self {
+    match line {
+      Self::LZ4_HEADER => Self::Lz4,
+      Self::ZSTD_HEADER => Self::Zstd,
+      _ => Self::None,
+    }
+  }
+
+  pub fn compress(&self, data: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    match self {
+      Self::None => Ok(data),
+      Self::Lz4 => Ok(lz4_flex::compress_prepend_size(&data)),
+      Self::Zstd => zstd::bulk::compress(&data, zstd::DEFAULT_COMPRESSION_LEVEL),
+    }
+  }
+
+  pub fn decompress(&self, data: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    match self {
+      Self::None => Ok(data),
+      Self::Lz4 => lz4_flex::decompress_size_prepended(&data).map_err(std::io::Error::other),
+      Self::Zstd => zstd::stream::decode_all(data.as_slice()),
+    }
+  }
 }
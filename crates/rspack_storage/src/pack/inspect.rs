@@ -0,0 +1,244 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use rspack_paths::Utf8PathBuf;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::{
+  data::{PackOptions, PackScope, ScopeMeta, current_time},
+  strategy::{PackReadStrategy, RootStrategy, ScopeReadStrategy, SplitPackStrategy, walk_dir},
+};
+use crate::{FileSystem, ItemPairs, error::Result};
+
+#[derive(Debug)]
+pub struct PackInfo {
+  pub bucket: usize,
+  pub name: String,
+  pub hash: String,
+  pub size: usize,
+  pub generation: usize,
+  pub path: Utf8PathBuf,
+}
+
+#[derive(Debug)]
+pub struct ScopeInfo {
+  pub name: String,
+  pub path: Utf8PathBuf,
+  pub bucket_size: usize,
+  pub pack_size: usize,
+  pub generation: usize,
+  pub packs: Vec<PackInfo>,
+}
+
+#[derive(Debug)]
+pub struct VersionInfo {
+  pub version: String,
+  pub path: Utf8PathBuf,
+  pub expire_time: u64,
+  pub scopes: Vec<ScopeInfo>,
+}
+
+impl VersionInfo {
+  pub fn is_expired(&self) -> bool {
+    current_time() > self.expire_time
+  }
+}
+
+#[derive(Debug)]
+pub enum PackVerifyResult {
+  /// The pack file matches the hash in scope meta
+  Valid(ItemPairs),
+  /// The pack file recorded in scope meta does not exist
+  Missing,
+  /// The pack file has been modified
+  HashMismatch,
+}
+
+/// Pack Inspector
+///
+/// A read-only view of the version directories in the storage root,
+/// used by debugging tools to look inside the persistent cache.
+/// Only `gc` will modify the storage, it should not be called when a compiler
+/// is using the same storage.
+#[derive(Debug)]
+pub struct PackInspector {
+  root: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+}
+
+impl PackInspector {
+  pub fn new(root: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self { root, fs }
+  }
+
+  fn get_strategy(&self, version: &str) -> SplitPackStrategy {
+    let root = self.root.join(version);
+    SplitPackStrategy::new(
+      root.clone(),
+      root.join(".temp"),
+      self.fs.clone(),
+      None,
+      None,
+    )
+  }
+
+  /// Get the version directories in the storage root
+  pub async fn versions(&self) -> Result<Vec<String>> {
+    if !self.fs.exists(&self.root).await? {
+      return Ok(vec![]);
+    }
+    let mut versions = vec![];
+    for name in self.fs.read_dir(&self.root).await? {
+      // skip hidden dirs and lock files
+      if name.starts_with('.') || name.contains(".lock") {
+        continue;
+      }
+      if self.fs.metadata(&self.root.join(&name)).await?.is_directory {
+        versions.push(name);
+      }
+    }
+    versions.sort();
+    Ok(versions)
+  }
+
+  /// Read the root meta and scope metas of a version,
+  /// return None if the root meta of the version not exists.
+  pub async fn inspect(&self, version: &str) -> Result<Option<VersionInfo>> {
+    let strategy = self.get_strategy(version);
+    let Some(root_meta) = strategy.read_root_meta().await? else {
+      return Ok(None);
+    };
+
+    let mut scopes = vec![];
+    for name in root_meta.scopes.iter().filter(|s| !s.is_empty()).sorted() {
+      // scope names in pack scope are static strings,
+      // the inspector is only used by short-lived tools so leaking them is acceptable.
+      let name: &'static str = Box::leak(name.clone().into_boxed_str());
+      let mut scope = PackScope::new(
+        name,
+        strategy.get_path(name),
+        Arc::new(PackOptions {
+          bucket_size: 0,
+          pack_size: 0,
+        }),
+      );
+      strategy.ensure_meta(&mut scope).await?;
+      let meta = scope.meta.expect_value();
+      scopes.push(ScopeInfo {
+        name: name.to_string(),
+        path: scope.path.clone(),
+        bucket_size: meta.bucket_size,
+        pack_size: meta.pack_size,
+        generation: meta.generation,
+        packs: meta
+          .packs
+          .iter()
+          .enumerate()
+          .flat_map(|(bucket, pack_metas)| {
+            let bucket_path = scope.path.join(bucket.to_string());
+            pack_metas.iter().map(move |pack_meta| PackInfo {
+              bucket,
+              name: pack_meta.name.clone(),
+              hash: pack_meta.hash.clone(),
+              size: pack_meta.size,
+              generation: pack_meta.generation,
+              path: bucket_path.join(&pack_meta.name),
+            })
+          })
+          .collect(),
+      });
+    }
+
+    Ok(Some(VersionInfo {
+      version: version.to_string(),
+      path: self.root.join(version),
+      expire_time: root_meta.expire_time,
+      scopes,
+    }))
+  }
+
+  /// Check the pack file with the hash in scope meta and read the items of it
+  pub async fn verify_pack(&self, version: &str, pack: &PackInfo) -> Result<PackVerifyResult> {
+    let strategy = self.get_strategy(version);
+    let Some(keys) = strategy.read_pack_keys(&pack.path).await? else {
+      return Ok(PackVerifyResult::Missing);
+    };
+    let hash = strategy
+      .get_pack_hash(&pack.path, &keys, &Default::default())
+      .await?;
+    if hash != pack.hash {
+      return Ok(PackVerifyResult::HashMismatch);
+    }
+    let Some(contents) = strategy.read_pack_contents(&pack.path).await? else {
+      return Ok(PackVerifyResult::Missing);
+    };
+    Ok(PackVerifyResult::Valid(
+      keys.into_iter().zip(contents.contents).collect(),
+    ))
+  }
+
+  /// Remove the expired versions and the files which are not referenced by any meta,
+  /// return the removed paths.
+  ///
+  /// Versions with lock files are skipped, they will be recovered by the next build.
+  pub async fn gc(&self) -> Result<Vec<Utf8PathBuf>> {
+    let mut removed = vec![];
+    for version in self.versions().await? {
+      let dir = self.root.join(&version);
+      let entries = self.fs.read_dir(&dir).await?;
+      if entries.iter().any(|name| name.ends_with(".lock")) {
+        continue;
+      }
+
+      let info = match self.inspect(&version).await? {
+        Some(info) if !info.is_expired() => info,
+        // versions without root meta are never loaded and expired versions will be reset
+        _ => {
+          self.fs.remove_dir(&dir).await?;
+          removed.push(dir);
+          continue;
+        }
+      };
+
+      let scope_names = info
+        .scopes
+        .iter()
+        .map(|scope| scope.name.as_str())
+        .collect::<HashSet<_>>();
+      for name in entries.iter().sorted() {
+        if name.starts_with('.') || scope_names.contains(name.as_str()) {
+          continue;
+        }
+        let path = dir.join(name);
+        if self.fs.metadata(&path).await?.is_directory {
+          self.fs.remove_dir(&path).await?;
+          removed.push(path);
+        }
+      }
+
+      for scope in &info.scopes {
+        if !self.fs.exists(&scope.path).await? {
+          continue;
+        }
+        let mut referenced = scope
+          .packs
+          .iter()
+          .map(|pack| &pack.path)
+          .collect::<HashSet<_>>();
+        let meta_path = ScopeMeta::get_path(&scope.path);
+        referenced.insert(&meta_path);
+        for file in walk_dir(&scope.path, self.fs.clone())
+          .await?
+          .into_iter()
+          .sorted()
+        {
+          if !referenced.contains(&file) {
+            self.fs.remove_file(&file).await?;
+            removed.push(file);
+          }
+        }
+      }
+    }
+    Ok(removed)
+  }
+}
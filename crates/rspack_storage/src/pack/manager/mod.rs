@@ -317,7 +317,7 @@ async fn save_scopes(
   strategy.write_root_meta(root_meta).await?;
   strategy.merge_changed(changed).await?;
   strategy.after_all(&mut scopes).await?;
-  strategy.clean(root_meta, &scopes, root_options).await?;
+  strategy.clean(root_meta, &mut scopes, root_options).await?;
 
   Ok(scopes.into_iter().collect())
 }
@@ -371,6 +371,7 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
@@ -425,6 +426,7 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
@@ -498,6 +500,7 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       // different bucket size
@@ -545,6 +548,7 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 100,
@@ -1,4 +1,5 @@
 mod data;
+mod inspect;
 mod manager;
 mod strategy;
 
@@ -7,7 +8,10 @@ use std::{
   sync::{Arc, Mutex},
 };
 
+pub use data::PackCompression;
+pub(crate) use data::RootMeta;
 use data::{PackOptions, RootOptions};
+pub use inspect::{PackInfo, PackInspector, PackVerifyResult, ScopeInfo, VersionInfo};
 use manager::ScopeManager;
 use rspack_paths::AssertUtf8;
 use rustc_hash::FxHashMap as HashMap;
@@ -34,6 +38,10 @@ pub struct PackStorageOptions {
   pub clean: bool,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  /// The compression of new pack files
+  pub compression: PackCompression,
+  /// The max total size of the storage root in bytes, see [RootOptions::max_size]
+  pub max_size: Option<u64>,
 }
 
 impl PackStorage {
@@ -44,18 +52,22 @@ impl PackStorage {
           root: options.root.clone().assert_utf8(),
           expire: options.expire,
           clean: options.clean,
+          max_size: options.max_size,
         }),
         Arc::new(PackOptions {
           bucket_size: options.bucket_size,
           pack_size: options.pack_size,
         }),
-        Arc::new(SplitPackStrategy::new(
-          options.root.join(&options.version).assert_utf8(),
-          options.temp_root.join(&options.version).assert_utf8(),
-          options.fs,
-          options.fresh_generation,
-          options.release_generation,
-        )),
+        Arc::new(
+          SplitPackStrategy::new(
+            options.root.join(&options.version).assert_utf8(),
+            options.temp_root.join(&options.version).assert_utf8(),
+            options.fs,
+            options.fresh_generation,
+            options.release_generation,
+          )
+          .with_compression(options.compression),
+        ),
       ),
       updates: Default::default(),
     }
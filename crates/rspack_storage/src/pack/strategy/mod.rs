@@ -3,7 +3,7 @@ mod split;
 use async_trait::async_trait;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
-pub use split::SplitPackStrategy;
+pub use split::{SplitPackStrategy, walk_dir};
 
 use super::data::{
   Pack, PackContents, PackFileMeta, PackGenerations, PackKeys, PackOptions, PackScope, RootMeta,
@@ -41,7 +41,7 @@ pub trait RootStrategy {
   async fn clean(
     &self,
     root_meta: &RootMeta,
-    scopes: &HashMap<String, PackScope>,
+    scopes: &mut HashMap<String, PackScope>,
     root_options: &RootOptions,
   ) -> Result<()>;
   async fn reset(&self);
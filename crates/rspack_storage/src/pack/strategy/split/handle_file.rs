@@ -350,14 +350,7 @@ async fn try_remove_version(
   }
 
   // remove direcotires of expired versions
-  let mut reader = fs.read_file(&meta).await?;
-  let expire_time = reader.read_line().await?.parse::<u64>().map_err(|e| {
-    FSError::from_message(
-      &meta,
-      FSOperation::Read,
-      format!("parse option meta failed: {e}"),
-    )
-  })?;
+  let expire_time = read_expire_time(dir, fs.clone()).await?;
   let current = current_time();
 
   if current > expire_time {
@@ -368,6 +361,72 @@ async fn try_remove_version(
   }
 }
 
+async fn read_expire_time(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> FSResult<u64> {
+  let meta = RootMeta::get_path(dir);
+  let mut reader = fs.read_file(&meta).await?;
+  reader.read_line().await?.parse::<u64>().map_err(|e| {
+    FSError::from_message(
+      &meta,
+      FSOperation::Read,
+      format!("parse option meta failed: {e}"),
+    )
+  })
+}
+
+async fn get_dir_size(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> BatchFSResult<u64> {
+  let mut size = 0;
+  for file in walk_dir(dir, fs.clone()).await? {
+    size += fs.metadata(&file).await?.size;
+  }
+  Ok(size)
+}
+
+/// Remove the least recently saved versions until the total size of all versions fits in `max_size`
+///
+/// The expire time in root meta is the last saved time plus `expire`, so it is used to sort versions.
+/// The current version is never removed, the remaining total size is returned so that the packs
+/// of the current version can be evicted when it exceeds `max_size` alone.
+pub async fn remove_oversized_versions(
+  root: &Utf8Path,
+  root_options: &RootOptions,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<u64> {
+  let Some(max_size) = root_options.max_size else {
+    return Ok(0);
+  };
+
+  let mut total_size = 0;
+  let mut candidates = vec![];
+  for version in fs.read_dir(&root_options.root).await? {
+    // skip hidden dirs and lock files
+    if version.starts_with(".") || version.contains(".lock") {
+      continue;
+    }
+    let version_dir = root_options.root.join(&version);
+    if !(fs.metadata(&version_dir).await?.is_directory) {
+      continue;
+    }
+    let size = get_dir_size(&version_dir, fs.clone()).await?;
+    total_size += size;
+    // versions without root meta are removed by `remove_expired_versions`
+    if version_dir == root || !fs.exists(&RootMeta::get_path(&version_dir)).await? {
+      continue;
+    }
+    let expire_time = read_expire_time(&version_dir, fs.clone()).await?;
+    candidates.push((expire_time, version_dir, size));
+  }
+
+  candidates.sort_by_key(|(expire_time, _, _)| *expire_time);
+  for (_, version_dir, size) in candidates {
+    if total_size <= max_size {
+      break;
+    }
+    fs.remove_dir(&version_dir).await?;
+    total_size -= size;
+  }
+  Ok(total_size)
+}
+
 pub async fn remove_expired_versions(
   root: &Utf8Path,
   root_options: &RootOptions,
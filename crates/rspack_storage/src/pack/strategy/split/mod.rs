@@ -11,22 +11,24 @@ use std::{
   sync::Arc,
 };
 
+pub use handle_file::walk_dir;
 use handle_file::{
-  recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_unused_scope_files,
-  remove_unused_scopes,
+  recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_oversized_versions,
+  remove_unused_scope_files, remove_unused_scopes,
 };
 use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 use util::get_name;
 
-use super::{RootStrategy, ScopeStrategy};
+use super::{RootStrategy, ScopeStrategy, ScopeWriteStrategy, WriteScopeResult};
 use crate::{
-  FileSystem,
+  FileSystem, Reader,
   error::{Result, ValidateResult},
   fs::{FSError, FSOperation},
   pack::data::{
-    PackContents, PackKeys, PackScope, RootMeta, RootMetaFrom, RootOptions, current_time,
+    PackCompression, PackContents, PackKeys, PackScope, RootMeta, RootMetaFrom, RootOptions,
+    current_time,
   },
 };
 
@@ -37,6 +39,7 @@ pub struct SplitPackStrategy {
   pub temp_root: Arc<Utf8PathBuf>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  pub compression: PackCompression,
 }
 
 impl SplitPackStrategy {
@@ -53,9 +56,41 @@ impl SplitPackStrategy {
       temp_root: Arc::new(temp_root),
       fresh_generation,
       release_generation,
+      compression: PackCompression::None,
     }
   }
 
+  pub fn with_compression(mut self, compression: PackCompression) -> Self {
+    self.compression = compression;
+    self
+  }
+
+  /// Open a pack file and read its first line
+  ///
+  /// The compressed pack file will be decompressed into memory,
+  /// so the returned reader always starts from the second line of the uncompressed pack.
+  pub async fn open_pack(&self, path: &Utf8Path) -> Result<(String, Reader)> {
+    let mut reader = self.fs.read_file(path).await?;
+    let first_line = reader.read_line().await?;
+    let compression = PackCompression::from_header(&first_line);
+    if matches!(compression, PackCompression::None) {
+      return Ok((first_line, reader));
+    }
+
+    let contents = compression
+      .decompress(reader.read_to_end().await?)
+      .map_err(|e| {
+        FSError::from_message(
+          path,
+          FSOperation::Read,
+          format!("decompress pack failed: {e}"),
+        )
+      })?;
+    let mut reader = Reader::from_contents(path, contents);
+    let first_line = reader.read_line().await?;
+    Ok((first_line, reader))
+  }
+
   pub async fn get_pack_hash(
     &self,
     path: &Utf8Path,
@@ -73,6 +108,71 @@ impl SplitPackStrategy {
 
     Ok(format!("{:016x}", hasher.finish()))
   }
+
+  /// Remove the oldest generation packs of the current version until `exceeded` bytes are freed
+  ///
+  /// The items of the removed packs are dropped from the scopes,
+  /// and the scope metas are rewritten so that they will not refer to the removed packs.
+  async fn remove_oversized_packs(
+    &self,
+    scopes: &mut HashMap<String, PackScope>,
+    exceeded: u64,
+  ) -> Result<()> {
+    let mut candidates = vec![];
+    for (name, scope) in scopes.iter() {
+      if !scope.loaded() {
+        continue;
+      }
+      let meta = scope.meta.expect_value();
+      let packs = scope.packs.expect_value();
+      for (bucket_pack_metas, bucket_packs) in meta.packs.iter().zip(packs) {
+        for (pack_meta, pack) in bucket_pack_metas.iter().zip(bucket_packs) {
+          let size = self.fs.metadata(&pack.path).await?.size;
+          candidates.push((
+            meta.generation - pack_meta.generation,
+            name,
+            pack.path.clone(),
+            size,
+          ));
+        }
+      }
+    }
+
+    candidates.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.2.cmp(&b.2)));
+    let mut freed = 0;
+    let mut changed_scopes = HashSet::default();
+    let mut removed_files = HashSet::default();
+    for (_, name, path, size) in candidates {
+      if freed >= exceeded {
+        break;
+      }
+      freed += size;
+      changed_scopes.insert(name.clone());
+      removed_files.insert(path);
+    }
+
+    let mut changed = WriteScopeResult::default();
+    for name in changed_scopes {
+      let scope = scopes.get_mut(&name).expect("should have scope");
+      let mut scope_meta = scope.meta.take_value().expect("should have scope meta");
+      let mut scope_packs = scope.packs.take_value().expect("should have scope packs");
+      for (bucket_pack_metas, bucket_packs) in scope_meta.packs.iter_mut().zip(&mut scope_packs) {
+        let (metas, packs): (Vec<_>, Vec<_>) = std::mem::take(bucket_pack_metas)
+          .into_iter()
+          .zip(std::mem::take(bucket_packs))
+          .filter(|(_, pack)| !removed_files.contains(&pack.path))
+          .unzip();
+        *bucket_pack_metas = metas;
+        *bucket_packs = packs;
+      }
+      scope.packs.set_value(scope_packs);
+      scope.meta.set_value(scope_meta);
+      changed.extend(self.write_meta(scope).await?);
+    }
+    changed.removed_files = removed_files;
+
+    self.merge_changed(changed).await
+  }
 }
 
 #[async_trait::async_trait]
@@ -141,7 +241,7 @@ impl RootStrategy for SplitPackStrategy {
   async fn clean(
     &self,
     root_meta: &RootMeta,
-    scopes: &HashMap<String, PackScope>,
+    scopes: &mut HashMap<String, PackScope>,
     root_options: &RootOptions,
   ) -> Result<()> {
     if !root_options.clean {
@@ -153,6 +253,16 @@ impl RootStrategy for SplitPackStrategy {
       remove_unused_scopes(&self.root, root_meta, self.fs.clone()),
       remove_expired_versions(&self.root, root_options, self.fs.clone())
     );
+    // the size is calculated after expired versions are removed,
+    // report the failure so that the cache directory will not grow silently
+    let total_size = remove_oversized_versions(&self.root, root_options, self.fs.clone()).await?;
+    if let Some(max_size) = root_options.max_size
+      && total_size > max_size
+    {
+      self
+        .remove_oversized_packs(scopes, total_size - max_size)
+        .await?;
+    }
 
     Ok(())
   }
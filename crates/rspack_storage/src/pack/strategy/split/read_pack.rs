@@ -20,11 +20,9 @@ impl PackReadStrategy for SplitPackStrategy {
       return Ok(None);
     }
 
-    let mut reader = self.fs.read_file(path).await?;
+    let (first_line, mut reader) = self.open_pack(path).await?;
     // read key lengths
-    let key_lengths = reader
-      .read_line()
-      .await?
+    let key_lengths = first_line
       .split(" ")
       .map(|item| {
         item.parse::<usize>().map_err(|e| {
@@ -55,10 +53,8 @@ impl PackReadStrategy for SplitPackStrategy {
       return Ok(None);
     }
 
-    let mut reader = self.fs.read_file(path).await?;
-    let total_key_length = reader
-      .read_line()
-      .await?
+    let (first_line, mut reader) = self.open_pack(path).await?;
+    let total_key_length = first_line
       .split(" ")
       .map(|item| {
         item.parse::<usize>().map_err(|e| {
@@ -181,45 +181,53 @@ impl PackWriteStrategy for SplitPackStrategy {
       panic!("pack keys and contents length not match");
     }
 
-    let mut writer = self.fs.write_file(&path).await?;
+    let mut buf = vec![];
 
     // key meta line
-    writer
-      .write_line(
-        keys
-          .iter()
-          .map(|key| key.len().to_string())
-          .join(" ")
-          .as_str(),
-      )
-      .await?;
+    buf.extend(keys.iter().map(|key| key.len()).join(" ").as_bytes());
+    buf.push(b'\n');
 
     // content meta line
-    writer
-      .write_line(
-        contents
-          .iter()
-          .map(|content| content.len().to_string())
-          .join(" ")
-          .as_str(),
-      )
-      .await?;
+    buf.extend(
+      contents
+        .iter()
+        .map(|content| content.len())
+        .join(" ")
+        .as_bytes(),
+    );
+    buf.push(b'\n');
 
     // content generation line
-    writer
-      .write_line(generations.iter().map(|g| g.to_string()).join(" ").as_str())
-      .await?;
+    buf.extend(generations.iter().join(" ").as_bytes());
+    buf.push(b'\n');
 
     // keys blob
     for key in keys {
-      writer.write(key).await?;
+      buf.extend(key.as_slice());
     }
 
     // contents blob
     for content in contents {
-      writer.write(content).await?;
+      buf.extend(content.as_slice());
     }
 
+    // compressed pack starts with a header line
+    if let Some(header) = self.compression.header() {
+      let compressed = self.compression.compress(buf).map_err(|e| {
+        FSError::from_message(
+          &path,
+          FSOperation::Write,
+          format!("compress pack failed: {e}"),
+        )
+      })?;
+      buf = Vec::with_capacity(header.len() + 1 + compressed.len());
+      buf.extend(header.as_bytes());
+      buf.push(b'\n');
+      buf.extend(compressed);
+    }
+
+    let mut writer = self.fs.write_file(&path).await?;
+    writer.write_all(&buf).await?;
     writer.flush().await?;
 
     Ok(())
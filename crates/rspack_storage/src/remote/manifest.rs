@@ -0,0 +1,85 @@
+use itertools::Itertools;
+use xxhash_rust::xxh3::xxh3_128;
+
+use crate::{Result, error::Error};
+
+/// Get the content address of a file
+pub fn get_content_hash(content: &[u8]) -> String {
+  format!("{:032x}", xxh3_128(content))
+}
+
+/// Manifest of a version of storage in remote
+///
+/// The manifest maps each file of the local version directory to its content address:
+///
+/// ```text
+/// <version>
+/// <content_hash> <relative_path>
+/// <content_hash> <relative_path>
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+  pub version: String,
+  pub files: Vec<(String, String)>,
+}
+
+impl Manifest {
+  pub fn get_key(version: &str) -> String {
+    format!("manifests/{version}")
+  }
+
+  pub fn get_pack_key(hash: &str) -> String {
+    format!("packs/{hash}")
+  }
+
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut lines = vec![self.version.clone()];
+    lines.extend(
+      self
+        .files
+        .iter()
+        .map(|(hash, path)| format!("{hash} {path}")),
+    );
+    lines.join("\n").into_bytes()
+  }
+
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    let invalid =
+      |reason: &str| Error::from_reason(None, None, format!("invalid manifest: {reason}"));
+    let content = std::str::from_utf8(bytes).map_err(|_| invalid("not utf8"))?;
+    let mut lines = content.lines();
+    let version = lines.next().ok_or_else(|| invalid("missing version"))?;
+    let files = lines
+      .map(|line| {
+        line
+          .split_once(' ')
+          .map(|(hash, path)| (hash.to_string(), path.to_string()))
+          .ok_or_else(|| invalid(line))
+      })
+      .try_collect()?;
+    Ok(Self {
+      version: version.to_string(),
+      files,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Manifest, get_content_hash};
+
+  #[test]
+  fn should_manifest_serialize_work() {
+    let manifest = Manifest {
+      version: "xxx".into(),
+      files: vec![
+        (get_content_hash(b"a"), "storage_meta".into()),
+        (get_content_hash(b"b"), "scope/scope_meta".into()),
+      ],
+    };
+    let bytes = manifest.to_bytes();
+    assert_eq!(Manifest::from_bytes(&bytes).unwrap(), manifest);
+    assert!(Manifest::from_bytes(b"xxx\nbroken_line").is_err());
+    assert_ne!(get_content_hash(b"a"), get_content_hash(b"b"));
+  }
+}
@@ -0,0 +1,249 @@
+mod manifest;
+mod transport;
+
+use std::sync::Arc;
+
+use futures::future::join_all;
+use manifest::{Manifest, get_content_hash};
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+use tokio::sync::{Mutex, oneshot, oneshot::Receiver};
+pub use transport::{DirectoryTransport, RemoteTransport};
+
+use crate::{
+  FileSystem, ItemKey, ItemPairs, ItemValue, PackStorage, PackStorageOptions, Storage,
+  error::{Error, ErrorType, Result},
+  pack::RootMeta,
+};
+
+pub struct RemoteStorageOptions {
+  pub pack: PackStorageOptions,
+  pub transport: Arc<dyn RemoteTransport>,
+  /// Push the local packs to remote after saved, set it to false to use remote as read only.
+  pub push: bool,
+}
+
+/// Remote Storage
+///
+/// Remote storage uses a `PackStorage` in local directory, and shares the version directory
+/// of it with other machines through `RemoteTransport`.
+///
+/// * Packs are content-addressed, each file is uploaded as `packs/<content_hash>` only once.
+/// * The `manifests/<version>` lists the files of a version directory.
+///   Since the version is calculated from the compiler options and rspack version,
+///   a manifest written by one machine is only used by the compiler with the same version.
+/// * The pulled storage contains the build dependencies snapshot of the machine which wrote it,
+///   and the persistent cache will reset the storage if it does not match the local one.
+#[derive(Debug)]
+pub struct RemoteStorage {
+  local: PackStorage,
+  root: Arc<Utf8PathBuf>,
+  version: Arc<str>,
+  fs: Arc<dyn FileSystem>,
+  transport: Arc<dyn RemoteTransport>,
+  push: bool,
+  pulled: Mutex<bool>,
+}
+
+impl RemoteStorage {
+  pub fn new(options: RemoteStorageOptions) -> Self {
+    let RemoteStorageOptions {
+      pack,
+      transport,
+      push,
+    } = options;
+    Self {
+      root: Arc::new(pack.root.join(&pack.version).assert_utf8()),
+      version: Arc::from(pack.version.as_str()),
+      fs: pack.fs.clone(),
+      local: PackStorage::new(pack),
+      transport,
+      push,
+      pulled: Mutex::new(false),
+    }
+  }
+
+  /// Pull the version directory from remote if local storage not exists
+  async fn ensure_pulled(&self) -> Result<()> {
+    let mut pulled = self.pulled.lock().await;
+    if *pulled {
+      return Ok(());
+    }
+    *pulled = true;
+
+    if self.fs.exists(&RootMeta::get_path(&self.root)).await? {
+      return Ok(());
+    }
+    let res = pull(&self.root, &self.version, &self.fs, &self.transport).await;
+    if res.is_err() {
+      // remove the incomplete files
+      self.local.reset().await;
+    }
+    res
+  }
+}
+
+#[tracing::instrument("Cache::Storage::Remote::pull", skip_all)]
+async fn pull(
+  root: &Utf8Path,
+  version: &str,
+  fs: &Arc<dyn FileSystem>,
+  transport: &Arc<dyn RemoteTransport>,
+) -> Result<()> {
+  let Some(bytes) = transport.get(&Manifest::get_key(version)).await? else {
+    return Ok(());
+  };
+  let manifest = Manifest::from_bytes(&bytes)?;
+  if manifest.version != version {
+    return Err(Error::from_reason(
+      Some(ErrorType::Load),
+      None,
+      format!(
+        "remote manifest version `{}` not match `{version}`",
+        manifest.version
+      ),
+    ));
+  }
+
+  let root_meta_path = RootMeta::get_path(root);
+  let (root_meta, files): (Vec<_>, Vec<_>) = manifest
+    .files
+    .into_iter()
+    .map(|(hash, path)| (hash, root.join(path)))
+    .partition(|(_, path)| *path == root_meta_path);
+
+  // write root meta at last, local storage is treated as not exists until it was written
+  for tasks in [files, root_meta] {
+    join_all(tasks.into_iter().map(|(hash, path)| async move {
+      let content = transport
+        .get(&Manifest::get_pack_key(&hash))
+        .await?
+        .ok_or_else(|| {
+          Error::from_reason(
+            Some(ErrorType::Load),
+            None,
+            format!("remote pack `{hash}` of `{path}` not exists"),
+          )
+        })?;
+      if get_content_hash(&content) != hash {
+        return Err(Error::from_reason(
+          Some(ErrorType::Load),
+          None,
+          format!("remote pack `{hash}` of `{path}` is broken"),
+        ));
+      }
+      let mut writer = fs.write_file(&path).await?;
+      writer.write_all(&content).await?;
+      writer.flush().await?;
+      Ok(())
+    }))
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+  }
+
+  tracing::debug!("pull storage {version} from remote");
+  Ok(())
+}
+
+#[tracing::instrument("Cache::Storage::Remote::push", skip_all)]
+async fn push(
+  root: &Utf8Path,
+  version: &str,
+  fs: &Arc<dyn FileSystem>,
+  transport: &Arc<dyn RemoteTransport>,
+) -> Result<()> {
+  let mut files = vec![];
+  let mut dirs = vec![root.to_path_buf()];
+  while let Some(dir) = dirs.pop() {
+    for name in fs.read_dir(&dir).await? {
+      // lock files only exist when the local storage is saving
+      if name.ends_with(".lock") {
+        continue;
+      }
+      let path = dir.join(name);
+      if fs.metadata(&path).await?.is_directory {
+        dirs.push(path);
+      } else {
+        files.push(path);
+      }
+    }
+  }
+
+  let files = join_all(files.into_iter().map(|path| async move {
+    let mut reader = fs.read_file(&path).await?;
+    let content = reader.read_to_end().await?;
+    let hash = get_content_hash(&content);
+    let key = Manifest::get_pack_key(&hash);
+    if !transport.exists(&key).await? {
+      transport.put(&key, content).await?;
+    }
+    let relative = path
+      .strip_prefix(root)
+      .expect("should be a sub path of root")
+      .to_string();
+    Ok((hash, relative))
+  }))
+  .await
+  .into_iter()
+  .collect::<Result<Vec<_>>>()?;
+
+  // upload manifest after all packs are uploaded
+  let manifest = Manifest {
+    version: version.to_string(),
+    files,
+  };
+  transport
+    .put(&Manifest::get_key(version), manifest.to_bytes())
+    .await?;
+
+  tracing::debug!("push storage {version} to remote");
+  Ok(())
+}
+
+#[async_trait::async_trait]
+impl Storage for RemoteStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    self.ensure_pulled().await?;
+    self.local.load(scope).await
+  }
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    self.local.set(scope, key, value);
+  }
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    self.local.remove(scope, key);
+  }
+  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    let local_rx = self.local.trigger_save()?;
+    if !self.push {
+      return Ok(local_rx);
+    }
+
+    let root = self.root.clone();
+    let version = self.version.clone();
+    let fs = self.fs.clone();
+    let transport = self.transport.clone();
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+      let res = match local_rx.await {
+        Ok(Ok(())) => push(&root, &version, &fs, &transport).await,
+        Ok(Err(err)) => Err(err),
+        Err(_) => Err(Error::from_reason(
+          Some(ErrorType::Save),
+          None,
+          "local storage stopped saving before finished".into(),
+        )),
+      };
+      let _ = tx.send(res);
+    });
+    Ok(rx)
+  }
+  async fn reset(&self) {
+    // the pulled storage is invalid, use an empty storage and overwrite remote at next save
+    *self.pulled.lock().await = true;
+    self.local.reset().await;
+  }
+  async fn scopes(&self) -> Result<Vec<String>> {
+    self.ensure_pulled().await?;
+    self.local.scopes().await
+  }
+}
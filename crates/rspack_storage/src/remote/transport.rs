@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use rspack_paths::Utf8PathBuf;
+use rspack_util::current_time;
+
+use crate::{FileSystem, Result};
+
+/// Transport of the remote storage
+///
+/// The remote storage is a simple key-value store, the keys are relative paths like
+/// `packs/<content_hash>` and `manifests/<version>`.
+#[async_trait::async_trait]
+pub trait RemoteTransport: std::fmt::Debug + Sync + Send {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()>;
+  async fn exists(&self, key: &str) -> Result<bool>;
+}
+
+/// Remote transport based on a local directory
+///
+/// This is the reference implementation of `RemoteTransport`, the directory can be
+/// a shared volume mounted by multiple CI runners.
+#[derive(Debug)]
+pub struct DirectoryTransport {
+  root: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+}
+
+impl DirectoryTransport {
+  pub fn new(root: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self { root, fs }
+  }
+}
+
+#[async_trait::async_trait]
+impl RemoteTransport for DirectoryTransport {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    let path = self.root.join(key);
+    if !self.fs.exists(&path).await? {
+      return Ok(None);
+    }
+    let mut reader = self.fs.read_file(&path).await?;
+    Ok(Some(reader.read_to_end().await?))
+  }
+
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+    // write to a temporary file first and then move it,
+    // so that other machines will never read an incomplete file
+    let path = self.root.join(key);
+    let temp_path = self.root.join(format!(
+      "{key}.{}.{}.tmp",
+      std::process::id(),
+      current_time()
+    ));
+    let mut writer = self.fs.write_file(&temp_path).await?;
+    writer.write_all(&value).await?;
+    writer.flush().await?;
+    self.fs.move_file(&temp_path, &path).await?;
+    Ok(())
+  }
+
+  async fn exists(&self, key: &str) -> Result<bool> {
+    Ok(self.fs.exists(&self.root.join(key)).await?)
+  }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod test_storage_compression {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackCompression, PackInspector, PackStorage, PackStorageOptions,
+    Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_compression")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_compression/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    compression: PackCompression,
+  ) -> PackStorage {
+    PackStorage::new(PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 10,
+      pack_size: 2000,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      compression,
+      max_size: None,
+    })
+  }
+
+  async fn read_first_lines(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<Vec<String>> {
+    let inspector = PackInspector::new(root.clone(), fs.clone());
+    let info = inspector.inspect("xxx").await?.expect("should have info");
+    let mut lines = vec![];
+    for pack in &info.scopes[0].packs {
+      lines.push(fs.read_file(&pack.path).await?.read_line().await?);
+    }
+    Ok(lines)
+  }
+
+  async fn test_compression(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    compression: PackCompression,
+  ) -> Result<()> {
+    let storage = create_storage(root, temp_root, fs.clone(), compression);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").repeat(10).as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+
+    let lines = read_first_lines(root, fs.clone()).await?;
+    assert!(!lines.is_empty());
+    for line in lines {
+      assert_eq!(PackCompression::from_header(&line), compression);
+    }
+
+    // packs can be read without knowing the compression
+    let storage = create_storage(root, temp_root, fs.clone(), PackCompression::None);
+    let mut data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    data.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(*data[99].0, "key_099".as_bytes());
+    assert_eq!(*data[99].1, "val_099".repeat(10).as_bytes());
+
+    // update items with another compression
+    storage.set(
+      "test_scope",
+      "key_000".as_bytes().to_vec(),
+      "new_000".as_bytes().to_vec(),
+    );
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    let lines = read_first_lines(root, fs.clone()).await?;
+    assert!(
+      lines
+        .iter()
+        .any(|line| PackCompression::from_header(line) == PackCompression::None)
+    );
+
+    let storage = create_storage(root, temp_root, fs.clone(), compression);
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    assert!(
+      data
+        .iter()
+        .any(|(key, value)| **key == "key_000".as_bytes() && **value == "new_000".as_bytes())
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_pack_compression() -> Result<()> {
+    for compression in [PackCompression::Lz4, PackCompression::Zstd] {
+      let cases = [
+        (
+          get_native_path(&format!("test_compression_{compression:?}_native")),
+          Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+        ),
+        (
+          get_memory_path(&format!("test_compression_{compression:?}_memory")),
+          Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+        ),
+      ];
+
+      for ((root, temp_root), fs) in cases {
+        let root = root.assert_utf8();
+        let temp_root = temp_root.assert_utf8();
+        fs.remove_dir(&root).await.expect("should remove root");
+        fs.remove_dir(&temp_root)
+          .await
+          .expect("should remove temp root");
+
+        test_compression(&root, &temp_root, fs, compression).await?;
+      }
+    }
+    Ok(())
+  }
+}
@@ -37,6 +37,8 @@ mod test_storage_error {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     }
   }
 
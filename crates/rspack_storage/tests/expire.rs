@@ -37,6 +37,8 @@ mod test_storage_expire {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -74,6 +76,8 @@ mod test_storage_expire {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     });
     assert!(storage.load("test_scope").await.is_err_and(|e| {
       e.to_string()
@@ -101,6 +105,8 @@ mod test_storage_expire {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test_storage_inspect {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackInspector, PackStorage, PackStorageOptions, PackVerifyResult,
+    Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_inspect")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_inspect/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    version: &str,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
+    }
+  }
+
+  async fn test_inspect(inspector: &PackInspector) -> Result<()> {
+    assert_eq!(inspector.versions().await?, vec!["xxx".to_string()]);
+    let info = inspector.inspect("xxx").await?.expect("should have info");
+    assert!(!info.is_expired());
+    assert_eq!(info.scopes.len(), 1);
+    let scope = &info.scopes[0];
+    assert_eq!(scope.name, "test_scope");
+    assert_eq!(scope.bucket_size, 10);
+    assert!(!scope.packs.is_empty());
+    assert!(inspector.inspect("yyy").await?.is_none());
+    Ok(())
+  }
+
+  async fn test_verify(inspector: &PackInspector, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let info = inspector.inspect("xxx").await?.expect("should have info");
+    let packs = &info.scopes[0].packs;
+    let mut count = 0;
+    for pack in packs {
+      match inspector.verify_pack("xxx", pack).await? {
+        PackVerifyResult::Valid(items) => count += items.len(),
+        res => panic!("pack should be valid, but got {res:?}"),
+      }
+    }
+    assert_eq!(count, 100);
+
+    // modify a pack
+    let mut writer = fs.write_file(&packs[0].path).await?;
+    writer.write_all("0\n0\n0\n".as_bytes()).await?;
+    writer.flush().await?;
+    assert!(matches!(
+      inspector.verify_pack("xxx", &packs[0]).await?,
+      PackVerifyResult::HashMismatch
+    ));
+
+    // remove a pack
+    fs.remove_file(&packs[1].path).await?;
+    assert!(matches!(
+      inspector.verify_pack("xxx", &packs[1]).await?,
+      PackVerifyResult::Missing
+    ));
+    Ok(())
+  }
+
+  async fn test_gc(
+    root: &Utf8PathBuf,
+    inspector: &PackInspector,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    // unreachable version, scope and pack
+    fs.ensure_dir(&root.join("unknown_version")).await?;
+    fs.ensure_dir(&root.join("xxx/unknown_scope")).await?;
+    let mut writer = fs
+      .write_file(&root.join("xxx/test_scope/0/unknown_pack"))
+      .await?;
+    writer.write_all("unknown".as_bytes()).await?;
+    writer.flush().await?;
+
+    let mut removed = inspector.gc().await?;
+    removed.sort();
+    assert_eq!(
+      removed,
+      vec![
+        root.join("unknown_version"),
+        root.join("xxx/test_scope/0/unknown_pack"),
+        root.join("xxx/unknown_scope"),
+      ]
+    );
+    assert!(inspector.gc().await?.is_empty());
+    assert!(inspector.inspect("xxx").await?.is_some());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_pack_inspector() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_inspector_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_inspector_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      let storage = PackStorage::new(create_pack_options(&root, &temp_root, "xxx", fs.clone()));
+      assert!(storage.load("test_scope").await?.is_empty());
+      for i in 0..100 {
+        storage.set(
+          "test_scope",
+          format!("key_{i:0>3}").as_bytes().to_vec(),
+          format!("val_{i:0>3}").as_bytes().to_vec(),
+        );
+      }
+      let rx = storage.trigger_save()?;
+      rx.await.expect("should save")?;
+
+      let inspector = PackInspector::new(root.clone(), fs.clone());
+      test_inspect(&inspector).await?;
+      test_gc(&root, &inspector, fs.clone()).await?;
+      test_verify(&inspector, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
@@ -100,6 +100,8 @@ mod test_storage_lock {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -137,6 +139,8 @@ mod test_storage_lock {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     });
     assert_eq!(storage.load("test_scope").await?.len(), 100);
     Ok(())
@@ -159,6 +163,8 @@ mod test_storage_lock {
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      compression: Default::default(),
+      max_size: None,
     });
     assert!(storage.load("test_scope").await.is_err_and(|e| {
       e.to_string()
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod test_storage_max_size {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_max_size")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_max_size/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    version: &str,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    max_size: Option<u64>,
+  ) -> PackStorage {
+    PackStorage::new(PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 2,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      compression: Default::default(),
+      max_size,
+    })
+  }
+
+  async fn save_version(
+    version: &str,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    max_size: Option<u64>,
+  ) -> Result<()> {
+    let storage = create_storage(version, root, temp_root, fs, max_size);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // make sure the versions have different expire time
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    Ok(())
+  }
+
+  async fn get_version_size(dir: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<u64> {
+    let mut size = 0;
+    let mut stack = vec![dir.clone()];
+    while let Some(path) = stack.pop() {
+      if fs.metadata(&path).await?.is_directory {
+        stack.extend(
+          fs.read_dir(&path)
+            .await?
+            .into_iter()
+            .map(|name| path.join(name)),
+        );
+      } else {
+        size += fs.metadata(&path).await?.size;
+      }
+    }
+    Ok(size)
+  }
+
+  async fn test_remove_oversized(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    save_version("v1", root, temp_root, fs.clone(), None).await?;
+    save_version("v2", root, temp_root, fs.clone(), None).await?;
+    let size = get_version_size(&root.join("v1"), fs.clone()).await?;
+    assert!(size > 0);
+
+    // all versions have the same data, so only two versions can be kept
+    save_version("v3", root, temp_root, fs.clone(), Some(size * 2)).await?;
+    assert!(!fs.exists(&root.join("v1")).await?);
+    assert!(fs.exists(&root.join("v2")).await?);
+    assert!(fs.exists(&root.join("v3")).await?);
+
+    // the oldest packs of the current version are removed when it exceeds the max size alone
+    let max_size = size / 2;
+    save_version("v4", root, temp_root, fs.clone(), Some(max_size)).await?;
+    assert!(!fs.exists(&root.join("v2")).await?);
+    assert!(!fs.exists(&root.join("v3")).await?);
+    assert!(get_version_size(&root.join("v4"), fs.clone()).await? <= max_size);
+
+    let storage = create_storage("v4", root, temp_root, fs.clone(), Some(max_size));
+    let items = storage.load("test_scope").await?;
+    assert!(!items.is_empty());
+    assert!(items.len() < 100);
+    for i in 0..10 {
+      storage.set(
+        "test_scope",
+        format!("new_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    assert!(get_version_size(&root.join("v4"), fs.clone()).await? <= max_size);
+
+    // the items of the latest generation are kept
+    let storage = create_storage("v4", root, temp_root, fs.clone(), None);
+    let items = storage.load("test_scope").await?;
+    for i in 0..10 {
+      let key = format!("new_{i:0>3}");
+      assert!(items.iter().any(|(k, _)| k.as_slice() == key.as_bytes()));
+    }
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_max_size() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_max_size_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_max_size_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      test_remove_oversized(&root, &temp_root, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
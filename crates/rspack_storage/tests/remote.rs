@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod test_storage_remote {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, DirectoryTransport, FileSystem, PackStorageOptions, RemoteStorage,
+    RemoteStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> PathBuf {
+    std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_remote")
+      .join(p)
+  }
+
+  pub fn get_memory_path(p: &str) -> PathBuf {
+    PathBuf::from("/rspack_test/storage/test_storage_remote/").join(p)
+  }
+
+  fn create_remote_storage(
+    base: &Utf8PathBuf,
+    machine: &str,
+    version: &str,
+    push: bool,
+    fs: Arc<dyn FileSystem>,
+  ) -> RemoteStorage {
+    RemoteStorage::new(RemoteStorageOptions {
+      pack: PackStorageOptions {
+        version: version.to_string(),
+        root: base.join(machine).join("cache").into(),
+        temp_root: base.join(machine).join("temp").into(),
+        fs: fs.clone(),
+        bucket_size: 10,
+        pack_size: 200,
+        expire: 7 * 24 * 60 * 60 * 1000,
+        clean: true,
+        fresh_generation: Some(1),
+        release_generation: Some(2),
+        compression: Default::default(),
+        max_size: None,
+      },
+      transport: Arc::new(DirectoryTransport::new(base.join("remote"), fs)),
+      push,
+    })
+  }
+
+  async fn test_push(base: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_remote_storage(base, "machine_a", "xxx", true, fs.clone());
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    assert!(fs.exists(&base.join("remote/manifests/xxx")).await?);
+    Ok(())
+  }
+
+  async fn test_pull(base: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_remote_storage(base, "machine_b", "xxx", false, fs.clone());
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    assert!(
+      fs.exists(&base.join("machine_b/cache/xxx/storage_meta"))
+        .await?
+    );
+    Ok(())
+  }
+
+  async fn test_version_mismatch(base: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_remote_storage(base, "machine_c", "yyy", false, fs.clone());
+    assert!(storage.load("test_scope").await?.is_empty());
+    Ok(())
+  }
+
+  async fn test_reset(base: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_remote_storage(base, "machine_d", "xxx", false, fs.clone());
+    // build dependencies mismatch will reset the storage after pulled
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    storage.reset().await;
+    assert!(storage.load("test_scope").await?.is_empty());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_remote() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_remote_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_remote_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for (base, fs) in cases {
+      let base = base.assert_utf8();
+      fs.remove_dir(&base).await.expect("should remove base");
+
+      test_push(&base, fs.clone()).await?;
+      test_pull(&base, fs.clone()).await?;
+      test_version_mismatch(&base, fs.clone()).await?;
+      test_reset(&base, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
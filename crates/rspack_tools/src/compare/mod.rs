@@ -1,5 +1,5 @@
 mod build_dependencies;
-mod occasion;
+pub mod occasion;
 mod snapshot;
 
 use std::{collections::VecDeque, sync::Arc};
@@ -64,6 +64,7 @@ pub fn load_storages_from_path(path: &Utf8PathBuf) -> HashMap<String, Arc<dyn St
       StorageOptions::FileSystem {
         directory: path.clone().into(),
       },
+      &Default::default(),
       v.clone(),
       fs.clone(),
     );
@@ -152,6 +153,13 @@ async fn compare_storage(
       occasion::make::SCOPE => {
         occasion::make::compare(storage1.clone(), storage2.clone(), cur_debug_info).await?;
       }
+      occasion::code_generate::SCOPE => {
+        occasion::code_generate::compare(storage1.clone(), storage2.clone(), cur_debug_info)
+          .await?;
+      }
+      occasion::chunk_render::SCOPE => {
+        occasion::chunk_render::compare(storage1.clone(), storage2.clone(), cur_debug_info).await?;
+      }
       _ => {
         return Err(error!(
           "Comparison for unknown scope: {} \n{}",
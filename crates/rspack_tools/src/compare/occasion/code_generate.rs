@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+pub use rspack_core::cache::persistent::occasion::code_generate::SCOPE;
+use rspack_core::cache::persistent::storage::Storage;
+use rspack_error::Result;
+
+use crate::{debug_info::DebugInfo, utils::ensure_iter_equal};
+
+/// Compare code_generate scope data between two storages
+///
+/// The cache keys already contain the hash of the content, so only the keys are compared.
+pub async fn compare(
+  storage1: Arc<dyn Storage>,
+  storage2: Arc<dyn Storage>,
+  debug_info: DebugInfo,
+) -> Result<()> {
+  // Load code generation results from both storages
+  let data1 = storage1.load(SCOPE).await?;
+  let data2 = storage2.load(SCOPE).await?;
+
+  ensure_iter_equal(
+    "Code generation result key",
+    data1.iter().map(|(key, _)| key),
+    data2.iter().map(|(key, _)| key),
+    &debug_info,
+  )
+}
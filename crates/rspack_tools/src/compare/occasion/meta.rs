@@ -10,7 +10,7 @@ use crate::debug_info::DebugInfo;
 /// Meta struct that mirrors rspack_core's Meta structure
 #[cacheable]
 #[derive(Debug)]
-struct Meta {
+pub struct Meta {
   pub max_dependencies_id: u32,
 }
 
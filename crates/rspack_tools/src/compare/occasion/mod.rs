@@ -1,2 +1,4 @@
+pub mod chunk_render;
+pub mod code_generate;
 pub mod make;
 pub mod meta;
@@ -0,0 +1,143 @@
+use std::{fmt::Write, sync::Arc};
+
+use itertools::Itertools;
+use rspack_core::cache::persistent::{
+  build_dependencies,
+  codec::CacheCodec,
+  occasion::MakeOccasion,
+  snapshot::{self, Strategy, StrategyHelper, ValidateResult},
+  storage::{Storage, StorageOptions, create_storage},
+};
+use rspack_error::Result;
+use rspack_fs::NativeFileSystem;
+use rspack_paths::{ArcPath, Utf8PathBuf};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{compare::find_relative_cache_path, utils::create_inspector};
+
+/// Explain why the snapshots of modules are invalidated
+///
+/// The modules whose identifier contains `module` are searched in the make scope,
+/// and their dependencies are validated with the strategies saved in the snapshot scope
+/// against the current file system.
+pub async fn explain_module(path: Utf8PathBuf, module: &str) -> Result<String> {
+  let fs = Arc::new(NativeFileSystem::new(false));
+  let helper = StrategyHelper::new(fs.clone());
+  let codec = Arc::new(CacheCodec::new(None));
+  let mut report = String::new();
+
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+    for version in create_inspector(&cache_path).versions().await? {
+      writeln!(report, "{cache_path} {version}").expect("should write report");
+      let storage = create_storage(
+        StorageOptions::FileSystem {
+          directory: cache_path.clone().into(),
+        },
+        &Default::default(),
+        version,
+        fs.clone(),
+      );
+
+      // build dependencies changed will reset the whole storage
+      let build_deps = load_strategies(storage.as_ref(), &codec, build_dependencies::SCOPE).await?;
+      for (path, strategy) in build_deps
+        .iter()
+        .sorted_by_key(|(path, _)| path.as_os_str())
+      {
+        if let Some(reason) = explain_path(&helper, path, strategy).await {
+          writeln!(
+            report,
+            "  build dependency {} {reason}, the whole cache will be reset",
+            path.display()
+          )
+          .expect("should write report");
+        }
+      }
+
+      let snapshots = load_strategies(storage.as_ref(), &codec, snapshot::SCOPE).await?;
+      let artifact = MakeOccasion::new(storage.clone(), codec.clone())
+        .recovery()
+        .await?;
+      let modules = artifact
+        .module_graph
+        .modules()
+        .into_iter()
+        .filter(|(identifier, _)| identifier.contains(module))
+        .sorted_by_key(|(identifier, _)| *identifier)
+        .collect_vec();
+      if modules.is_empty() {
+        writeln!(report, "  no module matches `{module}`").expect("should write report");
+        continue;
+      }
+
+      for (identifier, module) in modules {
+        writeln!(report, "  module {identifier}").expect("should write report");
+        let build_info = module.build_info();
+        let mut unchanged = 0;
+        let mut invalidated = false;
+        for (kind, paths) in [
+          ("file", &build_info.file_dependencies),
+          ("context", &build_info.context_dependencies),
+          ("missing", &build_info.missing_dependencies),
+          ("build", &build_info.build_dependencies),
+        ] {
+          for path in paths.iter().sorted_by_key(|path| path.as_os_str()) {
+            let reason = match snapshots.get(path) {
+              Some(strategy) => match explain_path(&helper, path, strategy).await {
+                Some(reason) => reason,
+                None => {
+                  unchanged += 1;
+                  continue;
+                }
+              },
+              // immutable paths are not saved to snapshot
+              None => "has no snapshot".to_string(),
+            };
+            invalidated = true;
+            writeln!(report, "    {kind} dependency {} {reason}", path.display())
+              .expect("should write report");
+          }
+        }
+        if !invalidated {
+          writeln!(report, "    snapshot is valid").expect("should write report");
+        }
+        writeln!(report, "    {unchanged} dependencies unchanged").expect("should write report");
+      }
+    }
+  }
+
+  Ok(report)
+}
+
+async fn load_strategies(
+  storage: &dyn Storage,
+  codec: &CacheCodec,
+  scope: &'static str,
+) -> Result<HashMap<ArcPath, Strategy>> {
+  storage
+    .load(scope)
+    .await?
+    .into_iter()
+    .map(|(key, value)| Ok((codec.decode(&key)?, codec.decode(&value)?)))
+    .collect()
+}
+
+/// Return the reason if the path is invalidated
+async fn explain_path(
+  helper: &StrategyHelper,
+  path: &ArcPath,
+  strategy: &Strategy,
+) -> Option<String> {
+  match (helper.validate(path, strategy).await, strategy) {
+    (ValidateResult::NoChanged, _) => None,
+    (ValidateResult::Deleted, _) => Some("was deleted".to_string()),
+    (ValidateResult::Modified, Strategy::Missing) => Some("was created".to_string()),
+    (ValidateResult::Modified, Strategy::PackageVersion(version)) => Some(format!(
+      "was modified, package version changed from {version}"
+    )),
+    (ValidateResult::Modified, Strategy::PathHash { .. }) => {
+      Some("was modified, content hash changed".to_string())
+    }
+  }
+}
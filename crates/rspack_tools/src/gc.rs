@@ -0,0 +1,17 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use rspack_paths::Utf8PathBuf;
+
+use crate::{compare::find_relative_cache_path, utils::create_inspector};
+
+/// Remove expired versions and unreachable packs of all cache directories in a directory
+///
+/// Returns the removed paths. Do not run it while a compiler is using the cache.
+pub async fn gc_cache_dir(path: Utf8PathBuf) -> Result<Vec<Utf8PathBuf>> {
+  let mut removed = vec![];
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+    removed.extend(create_inspector(&cache_path).gc().await?);
+  }
+  Ok(removed)
+}
@@ -0,0 +1,77 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+use rspack_error::Result;
+use rspack_paths::Utf8PathBuf;
+
+use crate::{
+  compare::find_relative_cache_path,
+  utils::{create_inspector, format_expire_time, format_size},
+};
+
+/// Inspect all cache directories in a directory and return the report
+///
+/// The report lists the versions, scopes and packs recorded in the storage metas.
+pub async fn inspect_cache_dir(path: Utf8PathBuf, show_packs: bool) -> Result<String> {
+  let mut report = String::new();
+
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+    let inspector = create_inspector(&cache_path);
+    writeln!(report, "{cache_path}").expect("should write report");
+
+    for version in inspector.versions().await? {
+      let Some(info) = inspector.inspect(&version).await? else {
+        writeln!(report, "  {version}: storage meta not exists, unreachable")
+          .expect("should write report");
+        continue;
+      };
+
+      let total_size = info
+        .scopes
+        .iter()
+        .flat_map(|scope| &scope.packs)
+        .map(|pack| pack.size)
+        .sum::<usize>();
+      writeln!(
+        report,
+        "  {version}: {} scopes, {}, {}",
+        info.scopes.len(),
+        format_size(total_size),
+        format_expire_time(info.expire_time)
+      )
+      .expect("should write report");
+
+      for scope in &info.scopes {
+        writeln!(
+          report,
+          "    {}: {} packs, {}, {} buckets, pack size {}, generation {}",
+          scope.name,
+          scope.packs.len(),
+          format_size(scope.packs.iter().map(|pack| pack.size).sum()),
+          scope.bucket_size,
+          format_size(scope.pack_size),
+          scope.generation
+        )
+        .expect("should write report");
+
+        if show_packs {
+          for pack in &scope.packs {
+            writeln!(
+              report,
+              "      {}/{}: hash {}, {}, generation {}",
+              pack.bucket,
+              pack.name,
+              pack.hash,
+              format_size(pack.size),
+              pack.generation
+            )
+            .expect("should write report");
+          }
+        }
+      }
+    }
+  }
+
+  Ok(report)
+}
@@ -1,5 +1,13 @@
 mod compare;
 mod debug_info;
+mod explain;
+mod gc;
+mod inspect;
 mod utils;
+mod verify;
 
 pub use compare::compare_cache_dir;
+pub use explain::explain_module;
+pub use gc::gc_cache_dir;
+pub use inspect::inspect_cache_dir;
+pub use verify::verify_cache_dir;
@@ -1,7 +1,9 @@
 use clap::{Parser, Subcommand};
 use rspack_error::Diagnostic;
 use rspack_paths::Utf8PathBuf;
-use rspack_tools::compare_cache_dir;
+use rspack_tools::{
+  compare_cache_dir, explain_module, gc_cache_dir, inspect_cache_dir, verify_cache_dir,
+};
 
 /// Toolkit for debugging and testing rspack internals
 #[derive(Parser, Debug)]
@@ -25,13 +27,49 @@ enum Commands {
     #[arg(value_name = "CACHE2")]
     cache2: String,
   },
+
+  /// List the versions, scopes and packs of a cache directory
+  Inspect {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// List every pack of each scope
+    #[arg(long)]
+    packs: bool,
+  },
+
+  /// Check the hash of every pack and the deserialization of every scope
+  Verify {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+
+  /// Remove expired versions and unreachable packs, do not run it while building
+  Gc {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+
+  /// Show why the snapshot of a module is invalidated
+  Explain {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Module identifier or a part of it, such as the resource path
+    #[arg(value_name = "MODULE")]
+    module: String,
+  },
 }
 
 #[tokio::main]
 async fn main() {
   let cli = Cli::parse();
 
-  match cli.command {
+  let result = match cli.command {
     Commands::Compare { cache1, cache2 } => {
       println!("Comparing cache directories:");
       println!("  Path 1: {}", cache1);
@@ -41,17 +79,41 @@ async fn main() {
       let path1 = Utf8PathBuf::from(&cache1);
       let path2 = Utf8PathBuf::from(&cache2);
 
-      if let Err(err) = compare_cache_dir(path1, path2).await {
-        eprintln!(
-          "{}",
-          Diagnostic::from(err)
-            .render_report(true)
-            .expect("render error failed")
-        );
-        std::process::exit(1);
-      }
-
-      println!("✓ Cache directories are identical");
+      compare_cache_dir(path1, path2)
+        .await
+        .map(|_| println!("✓ Cache directories are identical"))
     }
+    Commands::Inspect { cache, packs } => inspect_cache_dir(Utf8PathBuf::from(&cache), packs)
+      .await
+      .map(|report| print!("{report}")),
+    Commands::Verify { cache } => {
+      println!("Verifying cache directory: {}", cache);
+      println!();
+
+      verify_cache_dir(Utf8PathBuf::from(&cache))
+        .await
+        .map(|_| println!("✓ Cache directory is valid"))
+    }
+    Commands::Gc { cache } => gc_cache_dir(Utf8PathBuf::from(&cache))
+      .await
+      .map(|removed| {
+        for path in &removed {
+          println!("  removed {path}");
+        }
+        println!("✓ Removed {} unused paths", removed.len());
+      }),
+    Commands::Explain { cache, module } => explain_module(Utf8PathBuf::from(&cache), &module)
+      .await
+      .map(|report| print!("{report}")),
+  };
+
+  if let Err(err) = result {
+    eprintln!(
+      "{}",
+      Diagnostic::from(err)
+        .render_report(true)
+        .expect("render error failed")
+    );
+    std::process::exit(1);
   }
 }
@@ -1,4 +1,12 @@
+use std::{
+  sync::Arc,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
 use rspack_error::{Result, error};
+use rspack_fs::NativeFileSystem;
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::{BridgeFileSystem, PackInspector};
 use rustc_hash::FxHashSet as HashSet;
 
 use super::debug_info::DebugInfo;
@@ -38,3 +46,40 @@ where
 
   Ok(())
 }
+
+/// Create a pack inspector for the cache directory which contains version directories
+pub fn create_inspector(path: &Utf8PathBuf) -> PackInspector {
+  PackInspector::new(
+    path.clone(),
+    Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+  )
+}
+
+/// Format bytes to a human readable size
+pub fn format_size(size: usize) -> String {
+  const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+  let mut value = size as f64;
+  let mut unit = 0;
+  while value >= 1024.0 && unit < UNITS.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+  if unit == 0 {
+    format!("{size} B")
+  } else {
+    format!("{value:.1} {}", UNITS[unit])
+  }
+}
+
+/// Format the expire time of a version to a human readable duration
+pub fn format_expire_time(expire_time: u64) -> String {
+  let now = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .expect("should get current time")
+    .as_millis() as u64;
+  if now > expire_time {
+    return "expired".to_string();
+  }
+  let hours = (expire_time - now) / (60 * 60 * 1000);
+  format!("expires in {}d {}h", hours / 24, hours % 24)
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use rspack_core::cache::persistent::{
+  build_dependencies,
+  codec::CacheCodec,
+  occasion::{
+    BuildChunkGraphOccasion, ChunkRenderOccasion, CodeGenerateOccasion, MakeOccasion,
+    build_chunk_graph, chunk_render, code_generate, make, meta,
+  },
+  snapshot::{self, Strategy},
+  storage::{Storage, StorageOptions, create_storage},
+};
+use rspack_error::{Result, error};
+use rspack_fs::NativeFileSystem;
+use rspack_paths::{ArcPath, Utf8PathBuf};
+use rspack_storage::PackVerifyResult;
+
+use crate::{
+  compare::{find_relative_cache_path, occasion::meta::Meta},
+  debug_info::DebugInfo,
+  utils::create_inspector,
+};
+
+/// Verify all cache directories in a directory
+///
+/// Every pack is checked with the hash recorded in scope meta, and the items of
+/// each scope are deserialized in the same way as the persistent cache recovery.
+pub async fn verify_cache_dir(path: Utf8PathBuf) -> Result<()> {
+  let fs = Arc::new(NativeFileSystem::new(false));
+  let mut failures = vec![];
+
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+    let inspector = create_inspector(&cache_path);
+
+    for version in inspector.versions().await? {
+      let debug_info = DebugInfo::default()
+        .with_field("path", cache_path.as_str())
+        .with_field("version", &version);
+      let Some(info) = inspector.inspect(&version).await? else {
+        continue;
+      };
+
+      let mut broken = false;
+      for scope in &info.scopes {
+        for pack in &scope.packs {
+          let reason = match inspector.verify_pack(&version, pack).await? {
+            PackVerifyResult::Valid(_) => continue,
+            PackVerifyResult::Missing => "is missing",
+            PackVerifyResult::HashMismatch => "does not match the hash in scope meta",
+          };
+          broken = true;
+          failures.push(format!(
+            "Pack {} {reason}\n{}",
+            pack.path,
+            debug_info.with_field("scope", &scope.name)
+          ));
+        }
+      }
+
+      // the storage refuses to load modified packs,
+      // so the deserialization is only checked for intact versions
+      if broken {
+        continue;
+      }
+      let storage = create_storage(
+        StorageOptions::FileSystem {
+          directory: cache_path.clone().into(),
+        },
+        &Default::default(),
+        version.clone(),
+        fs.clone(),
+      );
+      for scope in &info.scopes {
+        if let Err(err) = verify_scope(storage.clone(), scope.name.clone()).await {
+          failures.push(format!(
+            "{err}\n{}",
+            debug_info.with_field("scope", &scope.name)
+          ));
+        }
+      }
+    }
+  }
+
+  if failures.is_empty() {
+    Ok(())
+  } else {
+    Err(error!("Verification failed:\n{}", failures.join("\n")))
+  }
+}
+
+/// Deserialize all items of a scope
+async fn verify_scope(storage: Arc<dyn Storage>, scope: String) -> Result<()> {
+  let codec = Arc::new(CacheCodec::new(None));
+  // the recovery of occasions panics on broken data, run it in a separate task to catch the panic
+  tokio::spawn(async move {
+    match scope.as_str() {
+      snapshot::SCOPE => verify_snapshot(storage, codec, snapshot::SCOPE).await,
+      build_dependencies::SCOPE => verify_snapshot(storage, codec, build_dependencies::SCOPE).await,
+      meta::SCOPE => {
+        for (_, value) in storage.load(meta::SCOPE).await? {
+          codec.decode::<Meta>(&value)?;
+        }
+        Ok(())
+      }
+      make::SCOPE => MakeOccasion::new(storage, codec)
+        .recovery()
+        .await
+        .map(|_| ()),
+      build_chunk_graph::SCOPE => {
+        BuildChunkGraphOccasion::new(storage, codec)
+          .recovery(&mut Default::default())
+          .await
+      }
+      code_generate::SCOPE => {
+        CodeGenerateOccasion::new(storage, codec)
+          .recovery(&Default::default())
+          .await
+      }
+      chunk_render::SCOPE => {
+        ChunkRenderOccasion::new(storage, codec)
+          .recovery(&Default::default())
+          .await
+      }
+      _ => Err(error!("Verification for unknown scope: {scope}")),
+    }
+  })
+  .await
+  .map_err(|err| error!("Deserialize failed: {err}"))?
+}
+
+async fn verify_snapshot(
+  storage: Arc<dyn Storage>,
+  codec: Arc<CacheCodec>,
+  scope: &'static str,
+) -> Result<()> {
+  for (key, value) in storage.load(scope).await? {
+    codec.decode::<ArcPath>(&key)?;
+    codec.decode::<Strategy>(&value)?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_core::{
+    BuildChunkGraphArtifact, ModuleGraph,
+    cache::persistent::{
+      codec::CacheCodec,
+      occasion::{BuildChunkGraphOccasion, build_chunk_graph},
+      storage::{StorageOptions, create_storage},
+    },
+  };
+  use rspack_fs::NativeFileSystem;
+  use rspack_paths::Utf8PathBuf;
+
+  use super::verify_cache_dir;
+
+  #[tokio::test]
+  async fn should_verify_build_chunk_graph_scope() {
+    let path = Utf8PathBuf::from_path_buf(
+      std::env::temp_dir().join(format!("rspack-tools-verify-{}", std::process::id())),
+    )
+    .unwrap();
+    let storage = create_storage(
+      StorageOptions::FileSystem {
+        directory: path.join("rspack").into(),
+      },
+      &Default::default(),
+      "version".into(),
+      Arc::new(NativeFileSystem::new(false)),
+    );
+
+    BuildChunkGraphOccasion::new(storage.clone(), Arc::new(CacheCodec::new(None))).save(
+      &BuildChunkGraphArtifact::default(),
+      &ModuleGraph::default(),
+      &Default::default(),
+    );
+    storage.trigger_save().unwrap().await.unwrap().unwrap();
+    assert!(
+      storage
+        .scopes()
+        .await
+        .unwrap()
+        .iter()
+        .any(|scope| scope == build_chunk_graph::SCOPE)
+    );
+
+    let result = verify_cache_dir(path.clone()).await;
+    std::fs::remove_dir_all(&path).unwrap();
+    result.unwrap();
+  }
+}
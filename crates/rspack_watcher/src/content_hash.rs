@@ -0,0 +1,130 @@
+use std::{
+  hash::BuildHasher,
+  sync::Arc,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use rspack_paths::{ArcPath, ArcPathDashMap, ArcPathDashSet};
+use rspack_util::fx_hash::BuildFxHasher;
+
+use crate::{EventBatch, FsEventKind};
+
+/// Computes the content hash of a file, which is the same as the file hash of the persistent
+/// cache snapshot, so the hashes computed by the snapshot can be reused.
+pub fn content_hash(content: &[u8]) -> u64 {
+  BuildFxHasher::default().hash_one(content)
+}
+
+fn file_hash(path: &ArcPath) -> Option<u64> {
+  let metadata = std::fs::metadata(path).ok()?;
+  if !metadata.is_file() {
+    return None;
+  }
+  std::fs::read(path)
+    .ok()
+    .map(|content| content_hash(&content))
+}
+
+/// `ContentHashes` keeps the content hashes of the watched files at the last build, and drops the
+/// change events of files whose content is the same as the last build.
+#[derive(Debug, Default)]
+pub struct ContentHashes {
+  hashes: ArcPathDashMap<u64>,
+}
+
+impl ContentHashes {
+  /// Sets the hashes of the files computed outside, e.g. by the persistent cache snapshot.
+  ///
+  /// The hashes are given with the modified times of the files in milliseconds. The files modified
+  /// since `start_time` are skipped, since the hashes may be computed after the modification while
+  /// the last build read the content before it.
+  pub fn set(&self, hashes: impl IntoIterator<Item = (ArcPath, u64, u64)>, start_time: SystemTime) {
+    let start_time = start_time
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |duration| duration.as_millis() as u64);
+    for (path, mtime, hash) in hashes {
+      if mtime >= start_time {
+        continue;
+      }
+      self.hashes.insert(path, hash);
+    }
+  }
+
+  /// Computes the hashes of the added files in the background and forgets the removed files.
+  ///
+  /// The files modified after `start_time` are skipped, since the last build may read the content
+  /// before the modification.
+  pub fn update(
+    self: &Arc<Self>,
+    added: &ArcPathDashSet,
+    removed: &ArcPathDashSet,
+    start_time: SystemTime,
+  ) {
+    for path in removed.iter() {
+      self.hashes.remove(&*path);
+    }
+
+    let added = added
+      .iter()
+      .filter(|path| !self.hashes.contains_key(&**path))
+      .map(|path| path.clone())
+      .collect::<Vec<_>>();
+    if added.is_empty() {
+      return;
+    }
+    let hashes = Arc::clone(self);
+    tokio::task::spawn_blocking(move || {
+      for path in added {
+        let Ok(modified) = std::fs::metadata(&path).and_then(|metadata| metadata.modified()) else {
+          continue;
+        };
+        if modified > start_time {
+          continue;
+        }
+        if let Some(hash) = file_hash(&path) {
+          // the hash is updated by the events already if it exists
+          hashes.hashes.entry(path).or_insert(hash);
+        }
+      }
+    });
+  }
+
+  /// Drops the change events of the files whose content is not changed, along with the events of
+  /// the parent directories in the same batch.
+  pub fn filter(&self, events: EventBatch) -> EventBatch {
+    let mut unchanged = vec![];
+    let mut events = events
+      .into_iter()
+      .filter(|event| match event.kind {
+        FsEventKind::Remove => {
+          // the file is rebuilt as removed, so a file created with the same content is a change
+          self.hashes.remove(&event.path);
+          true
+        }
+        FsEventKind::Change | FsEventKind::Create => {
+          let Some(hash) = file_hash(&event.path) else {
+            return true;
+          };
+          if let Some(last_hash) = self.hashes.insert(event.path.clone(), hash)
+            && last_hash == hash
+          {
+            unchanged.push(event.path.clone());
+            return false;
+          }
+          true
+        }
+      })
+      .collect::<Vec<_>>();
+
+    if !unchanged.is_empty() {
+      // the directories are notified by the changes of the files in them
+      events.retain(|event| {
+        !(event.kind == FsEventKind::Change
+          && event.path.is_dir()
+          && unchanged.iter().any(|file| file.starts_with(&event.path)))
+      });
+    }
+
+    events
+  }
+}
@@ -1,6 +1,6 @@
 use std::sync::{
   Arc,
-  atomic::{AtomicBool, Ordering},
+  atomic::{AtomicBool, AtomicUsize, Ordering},
 };
 
 use rspack_util::fx_hash::FxHashSet as HashSet;
@@ -10,7 +10,7 @@ use tokio::sync::{
 };
 
 use super::{EventAggregateHandler, EventHandler, FsEventKind};
-use crate::EventBatch;
+use crate::{EventBatch, content_hash::ContentHashes};
 
 type ThreadSafetyReceiver<T> = ThreadSafety<UnboundedReceiver<T>>;
 type ThreadSafety<T> = Arc<Mutex<T>>;
@@ -33,8 +33,12 @@ impl FilesData {
 /// deleted files, and coordinates the event handling logic.
 pub struct Executor {
   aggregate_timeout: u32,
+  max_aggregate_timeout: Option<u32>,
+  massive_change_threshold: Option<u32>,
+  content_hashes: Option<Arc<ContentHashes>>,
   rx: ThreadSafetyReceiver<EventBatch>,
   files_data: ThreadSafety<FilesData>,
+  received_events: Arc<AtomicUsize>,
   exec_aggregate_tx: UnboundedSender<ExecAggregateEvent>,
   exec_aggregate_rx: ThreadSafetyReceiver<ExecAggregateEvent>,
   exec_tx: UnboundedSender<ExecEvent>,
@@ -66,7 +70,18 @@ enum ExecEvent {
 
 impl Executor {
   /// Create a new `WatcherExecutor` with the given receiver and optional aggregate timeout.
-  pub fn new(rx: UnboundedReceiver<EventBatch>, aggregate_timeout: Option<u32>) -> Self {
+  ///
+  /// The events of files whose content is not changed are dropped if `content_hashes` is set.
+  ///
+  /// The aggregate window is widened while events keep arriving, up to `max_aggregate_timeout`.
+  /// The files are handled as a massive change if there are more than `massive_change_threshold`.
+  pub fn new(
+    rx: UnboundedReceiver<EventBatch>,
+    aggregate_timeout: Option<u32>,
+    max_aggregate_timeout: Option<u32>,
+    massive_change_threshold: Option<u32>,
+    content_hashes: Option<Arc<ContentHashes>>,
+  ) -> Self {
     let (exec_aggregate_tx, exec_aggregate_rx) = mpsc::unbounded_channel::<ExecAggregateEvent>();
     let (exec_tx, exec_rx) = mpsc::unbounded_channel::<ExecEvent>();
 
@@ -76,6 +91,7 @@ impl Executor {
       paused: Arc::new(AtomicBool::new(false)),
       rx: Arc::new(Mutex::new(rx)),
       files_data: Default::default(),
+      received_events: Default::default(),
       exec_aggregate_tx,
       exec_aggregate_rx: Arc::new(Mutex::new(exec_aggregate_rx)),
       exec_rx: Arc::new(Mutex::new(exec_rx)),
@@ -83,6 +99,9 @@ impl Executor {
       execute_aggregate_handle: None,
       execute_handle: None,
       aggregate_timeout: aggregate_timeout.unwrap_or(DEFAULT_AGGREGATE_TIMEOUT),
+      max_aggregate_timeout,
+      massive_change_threshold,
+      content_hashes,
     }
   }
 
@@ -133,9 +152,27 @@ impl Executor {
       let exec_tx = self.exec_tx.clone();
       let paused = Arc::clone(&self.paused);
       let aggregate_running = Arc::clone(&self.aggregate_running);
+      let received_events = Arc::clone(&self.received_events);
+      let content_hashes = self.content_hashes.clone();
 
       let future = async move {
         while let Some(events) = rx.lock().await.recv().await {
+          let events = match &content_hashes {
+            Some(content_hashes) => {
+              let content_hashes = Arc::clone(content_hashes);
+              // reading files is blocking
+              match tokio::task::spawn_blocking(move || content_hashes.filter(events)).await {
+                Ok(events) => events,
+                Err(_) => continue,
+              }
+            }
+            None => events,
+          };
+          if events.is_empty() {
+            continue;
+          }
+          received_events.fetch_add(events.len(), Ordering::Relaxed);
+
           for event in &events {
             let path = event.path.to_string_lossy().to_string();
             match event.kind {
@@ -182,7 +219,14 @@ impl Executor {
       event_aggregate_handler,
       Arc::clone(&self.exec_aggregate_rx),
       Arc::clone(&self.files_data),
-      self.aggregate_timeout as u64,
+      AggregateOptions {
+        aggregate_timeout: self.aggregate_timeout as u64,
+        max_aggregate_timeout: self.max_aggregate_timeout.map(|timeout| timeout as u64),
+        massive_change_threshold: self
+          .massive_change_threshold
+          .map(|threshold| threshold as usize),
+      },
+      Arc::clone(&self.received_events),
       Arc::clone(&self.aggregate_running),
     ));
 
@@ -227,11 +271,46 @@ fn create_execute_task(
   tokio::spawn(future)
 }
 
+struct AggregateOptions {
+  aggregate_timeout: u64,
+  max_aggregate_timeout: Option<u64>,
+  massive_change_threshold: Option<usize>,
+}
+
+impl AggregateOptions {
+  /// Waits for the aggregate timeout, the window is doubled while events keep arriving
+  /// until the events stop or `max_aggregate_timeout` is reached.
+  async fn wait(&self, received_events: &AtomicUsize) {
+    let mut waited = 0;
+    let mut timeout = self.aggregate_timeout;
+    loop {
+      let received = received_events.load(Ordering::Relaxed);
+      tokio::time::sleep(tokio::time::Duration::from_millis(timeout)).await;
+      waited += timeout;
+
+      let Some(max_aggregate_timeout) = self.max_aggregate_timeout else {
+        return;
+      };
+      if waited >= max_aggregate_timeout || received_events.load(Ordering::Relaxed) == received {
+        return;
+      }
+      timeout = (timeout * 2).min(max_aggregate_timeout - waited);
+    }
+  }
+
+  fn is_massive_change(&self, files: &FilesData) -> bool {
+    self
+      .massive_change_threshold
+      .is_some_and(|threshold| files.changed.len() + files.deleted.len() > threshold)
+  }
+}
+
 fn create_execute_aggregate_task(
   event_handler: Box<dyn EventAggregateHandler + Send>,
   exec_aggregate_rx: ThreadSafetyReceiver<ExecAggregateEvent>,
   files: ThreadSafety<FilesData>,
-  aggregate_timeout: u64,
+  options: AggregateOptions,
+  received_events: Arc<AtomicUsize>,
   running: Arc<AtomicBool>,
 ) -> tokio::task::JoinHandle<()> {
   let future = async move {
@@ -249,7 +328,7 @@ fn create_execute_aggregate_task(
       if let ExecAggregateEvent::Execute = aggregate_rx {
         running.store(true, Ordering::Relaxed);
         // Wait for the aggregate timeout before executing the handler
-        tokio::time::sleep(tokio::time::Duration::from_millis(aggregate_timeout)).await;
+        options.wait(&received_events).await;
 
         // Get the files to process
         let files = {
@@ -262,7 +341,11 @@ fn create_execute_aggregate_task(
         };
 
         // Call the event handler with the changed and deleted files
-        event_handler.on_event_handle(files.changed, files.deleted);
+        if options.is_massive_change(&files) {
+          event_handler.on_massive_change(files.changed, files.deleted);
+        } else {
+          event_handler.on_event_handle(files.changed, files.deleted);
+        }
         running.store(false, Ordering::Relaxed);
       }
     }
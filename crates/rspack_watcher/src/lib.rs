@@ -1,4 +1,5 @@
 mod analyzer;
+mod content_hash;
 mod disk_watcher;
 mod executor;
 mod ignored;
@@ -9,6 +10,8 @@ mod trigger;
 use std::{sync::Arc, time::SystemTime};
 
 use analyzer::{Analyzer, RecommendedAnalyzer};
+use content_hash::ContentHashes;
+pub use content_hash::content_hash;
 use disk_watcher::DiskWatcher;
 use executor::Executor;
 pub use ignored::FsWatcherIgnored;
@@ -51,6 +54,15 @@ pub trait EventAggregateHandler {
   /// Handle a batch of file system events.
   fn on_event_handle(&self, _changed_files: HashSet<String>, _deleted_files: HashSet<String>);
 
+  /// Handle a batch of file system events with more files than
+  /// [`FsWatcherOptions::massive_change_threshold`], e.g. caused by switching branches.
+  ///
+  /// Implementors may rescan all their dependencies instead of processing the files one by one.
+  /// The default implementation forwards the files to `on_event_handle`.
+  fn on_massive_change(&self, changed_files: HashSet<String>, deleted_files: HashSet<String>) {
+    self.on_event_handle(changed_files, deleted_files);
+  }
+
   /// Handle an error that occurs during file system watching.
   fn on_error(&self, _error: rspack_error::Error) {
     // Default implementation does nothing.
@@ -82,6 +94,22 @@ pub struct FsWatcherOptions {
 
   /// The timeout in milliseconds to aggregate events.
   pub aggregate_timeout: Option<u32>,
+
+  /// The max timeout in milliseconds to aggregate events during a burst of events.
+  ///
+  /// The aggregate window is widened while events keep arriving, until the events stop or this
+  /// timeout is reached. The window is not widened if it's `None`.
+  pub max_aggregate_timeout: Option<u32>,
+
+  /// The number of files above which the aggregated events are handled by
+  /// [`EventAggregateHandler::on_massive_change`].
+  pub massive_change_threshold: Option<u32>,
+
+  /// Whether to drop the change events of files whose content is the same as the last build.
+  ///
+  /// The content of the watched files is hashed when they are added, the hashes computed already
+  /// can be provided by [`FsWatcher::set_content_hashes`].
+  pub content_hash: bool,
 }
 
 pub struct FsWatcher {
@@ -91,6 +119,7 @@ pub struct FsWatcher {
   scanner: Scanner,
   analyzer: RecommendedAnalyzer,
   trigger: Option<Arc<Trigger>>,
+  content_hashes: Option<Arc<ContentHashes>>,
 }
 
 impl FsWatcher {
@@ -105,7 +134,16 @@ impl FsWatcher {
       options.poll_interval,
       trigger.clone(),
     );
-    let executor = Executor::new(rx, options.aggregate_timeout);
+    let content_hashes = options
+      .content_hash
+      .then(|| Arc::new(ContentHashes::default()));
+    let executor = Executor::new(
+      rx,
+      options.aggregate_timeout,
+      options.max_aggregate_timeout,
+      options.massive_change_threshold,
+      content_hashes.clone(),
+    );
     let scanner = Scanner::new(tx, Arc::clone(&path_manager));
 
     Self {
@@ -115,6 +153,7 @@ impl FsWatcher {
       scanner,
       analyzer: RecommendedAnalyzer::default(),
       trigger: Some(trigger),
+      content_hashes,
     }
   }
 
@@ -164,6 +203,22 @@ impl FsWatcher {
     }
   }
 
+  /// Sets the content hashes of files computed by [`content_hash`], e.g. the file hashes of the
+  /// persistent cache snapshot, so they don't need to be computed again.
+  ///
+  /// The hashes are given with the modified times of the files in milliseconds, the files modified
+  /// after `start_time` of the build are skipped. It does nothing if
+  /// [`FsWatcherOptions::content_hash`] is not enabled.
+  pub fn set_content_hashes(
+    &self,
+    hashes: impl IntoIterator<Item = (ArcPath, u64, u64)>,
+    start_time: SystemTime,
+  ) {
+    if let Some(content_hashes) = &self.content_hashes {
+      content_hashes.set(hashes, start_time);
+    }
+  }
+
   /// Pauses the file system watcher, stopping the execution of the event loop.
   pub fn pause(&self) -> Result<()> {
     self.executor.pause();
@@ -181,6 +236,12 @@ impl FsWatcher {
     self.path_manager.update(files, directories, missing)?;
     self.scanner.scan(start_time);
 
+    if let Some(content_hashes) = &self.content_hashes {
+      let accessor = self.path_manager.access();
+      let (_, added, removed) = accessor.files();
+      content_hashes.update(added, removed, start_time);
+    }
+
     let watch_patterns = self.analyzer.analyze(self.path_manager.access());
     self.disk_watcher.watch(watch_patterns.into_iter())?;
 
@@ -32,6 +32,7 @@ pub struct TestHelper {
 pub struct AggregatedEvent {
   pub changed_files: FxHashSet<String>,
   pub deleted_files: FxHashSet<String>,
+  pub massive: bool,
 }
 
 impl AggregatedEvent {
@@ -273,6 +274,19 @@ impl TestHelper {
         let _ = self.0.send(Event::Aggregated(AggregatedEvent {
           changed_files,
           deleted_files,
+          massive: false,
+        }));
+      }
+
+      fn on_massive_change(
+        &self,
+        changed_files: FxHashSet<String>,
+        deleted_files: FxHashSet<String>,
+      ) {
+        let _ = self.0.send(Event::Aggregated(AggregatedEvent {
+          changed_files,
+          deleted_files,
+          massive: true,
         }));
       }
     }
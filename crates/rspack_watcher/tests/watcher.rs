@@ -95,3 +95,72 @@ fn should_watch_a_single_file() {
     },
   );
 }
+
+#[test]
+fn should_ignore_changes_with_same_content() {
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(100),
+    content_hash: true,
+    ..Default::default()
+  });
+  std::fs::write(helper.join("a"), "a").unwrap();
+
+  let rx = watch!(helper, "a");
+
+  helper.tick(|| {
+    std::fs::write(helper.join("a"), "a").unwrap();
+  });
+  assert!(
+    rx.recv_timeout(std::time::Duration::from_secs(1)).is_err(),
+    "Expected no event for the file with the same content"
+  );
+
+  helper.tick(|| {
+    std::fs::write(helper.join("a"), "b").unwrap();
+  });
+
+  helper.collect_events(
+    rx,
+    |file, _| {
+      file.assert_path(helper.join("a"));
+    },
+    |changes, abort| {
+      changes.assert_changed(helper.join("a"));
+      *abort = true;
+    },
+  );
+}
+
+#[test]
+fn should_aggregate_burst_as_massive_change() {
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(100),
+    max_aggregate_timeout: Some(2000),
+    massive_change_threshold: Some(3),
+    ..Default::default()
+  });
+  for file in ["a", "b", "c", "d", "e"] {
+    helper.file(file);
+  }
+
+  let rx = watch!(helper, "a", "b", "c", "d", "e");
+
+  helper.tick(|| {
+    for file in ["a", "b", "c", "d", "e"] {
+      helper.file(file);
+      std::thread::sleep(std::time::Duration::from_millis(60));
+    }
+  });
+
+  helper.collect_events(
+    rx,
+    |_, _| {},
+    |changes, abort| {
+      assert!(changes.massive, "Expected the burst to be a massive change");
+      for file in ["a", "b", "c", "d", "e"] {
+        changes.assert_changed(helper.join(file));
+      }
+      *abort = true;
+    },
+  );
+}
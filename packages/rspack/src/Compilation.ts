@@ -177,6 +177,8 @@ export interface KnownNormalizedStatsOptions {
   usedExports: boolean;
   providedExports: boolean;
   optimizationBailout: boolean;
+  sideEffectsBailout: boolean;
+  cacheInvalidation: boolean;
   depth: boolean;
   assets: boolean;
   chunks: boolean;
@@ -1,5 +1,6 @@
 import binding from '@rspack/binding';
 import type Watchpack from 'watchpack';
+import type { WatchOptions } from './config';
 import type {
   FileSystemInfoEntry,
   InputFileSystem,
@@ -59,7 +60,7 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
       removed?: Iterable<string>;
     },
     startTime: number,
-    options: Watchpack.WatchOptions,
+    options: WatchOptions,
     callback: (
       error: Error | null,
       fileTimeInfoEntries: Map<string, FileSystemInfoEntry | 'ignore'>,
@@ -113,11 +114,16 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
         const removedFiles = result.removedFiles;
         if (this.#inputFileSystem?.purge) {
           const fs = this.#inputFileSystem;
-          for (const item of changedFiles) {
-            fs.purge?.(item);
-          }
-          for (const item of removedFiles) {
-            fs.purge?.(item);
+          if (result.massiveChange) {
+            // most of the files are changed, e.g. by switching branches
+            fs.purge?.();
+          } else {
+            for (const item of changedFiles) {
+              fs.purge?.(item);
+            }
+            for (const item of removedFiles) {
+              fs.purge?.(item);
+            }
           }
         }
         // TODO: add fileTimeInfoEntries and contextTimeInfoEntries
@@ -167,7 +173,7 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
     };
   }
 
-  getNativeWatcher(options: Watchpack.WatchOptions): binding.NativeWatcher {
+  getNativeWatcher(options: WatchOptions): binding.NativeWatcher {
     if (this.#inner) {
       return this.#inner;
     }
@@ -175,8 +181,11 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
     const nativeWatcherOptions: binding.NativeWatcherOptions = {
       followSymlinks: options.followSymlinks,
       aggregateTimeout: options.aggregateTimeout,
+      maxAggregateTimeout: options.maxAggregateTimeout,
+      massiveChangeThreshold: options.massiveChangeThreshold,
       pollInterval: typeof options.poll === 'boolean' ? 0 : options.poll,
       ignored: toJsWatcherIgnored(options.ignored),
+      contentHash: options.contentHash,
     };
     const nativeWatcher = new binding.NativeWatcher(nativeWatcherOptions);
     this.#inner = nativeWatcher;
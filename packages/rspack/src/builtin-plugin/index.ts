@@ -68,6 +68,7 @@ export * from './ProvidePlugin';
 export * from './RealContentHashPlugin';
 export * from './RemoveDuplicateModulesPlugin';
 export * from './RemoveEmptyChunksPlugin';
+export * from './RemoveUnusedClassMembersPlugin';
 export * from './RsdoctorPlugin';
 export * from './RslibPlugin';
 export * from './RstestPlugin';
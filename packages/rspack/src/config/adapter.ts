@@ -107,6 +107,8 @@ export const getRawOptions = (
     profile: options.profile!,
     amd: options.amd ? JSON.stringify(options.amd || {}) : undefined,
     bail: options.bail!,
+    recordsInputPath: options.recordsInputPath || undefined,
+    recordsOutputPath: options.recordsOutputPath || undefined,
     __references: {},
   };
 };
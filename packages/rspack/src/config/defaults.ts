@@ -88,6 +88,8 @@ export const applyRspackOptionsDefaults = (
   D(options, 'lazyCompilation', false);
   // IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
   D(options, 'bail', false);
+  D(options, 'recordsInputPath', false);
+  D(options, 'recordsOutputPath', false);
 
   // IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
   // but Rspack currently does not support this option
@@ -248,12 +250,17 @@ const applyExperimentsDefaults = (
   D(experiments, 'incremental', {});
   if (typeof experiments.incremental === 'object') {
     D(experiments.incremental, 'silent', true);
+    D(experiments.incremental, 'verify', false);
     D(experiments.incremental, 'make', true);
     D(experiments.incremental, 'inferAsyncModules', true);
     D(experiments.incremental, 'providedExports', true);
     D(experiments.incremental, 'dependenciesDiagnostics', true);
     D(experiments.incremental, 'sideEffects', true);
     D(experiments.incremental, 'buildChunkGraph', false);
+    D(experiments.incremental, 'moduleConcatenation', false);
+    D(experiments.incremental, 'splitChunks', false);
+    D(experiments.incremental, 'inlineExports', false);
+    D(experiments.incremental, 'mangleExports', false);
     D(experiments.incremental, 'moduleIds', true);
     D(experiments.incremental, 'chunkIds', true);
     D(experiments.incremental, 'modulesHashes', true);
@@ -1055,6 +1062,8 @@ const applyOptimizationDefaults = (
   F(optimization, 'sideEffects', () => (production ? true : 'flag'));
   D(optimization, 'mangleExports', production);
   D(optimization, 'inlineExports', deprecatedInline && production);
+  // IGNORE(optimization.removeUnusedClassMembers): Rspack specific configuration for removing unused class members
+  D(optimization, 'removeUnusedClassMembers', false);
   D(optimization, 'providedExports', true);
   D(optimization, 'usedExports', production);
   D(optimization, 'innerGraph', production);
@@ -1065,6 +1074,8 @@ const applyOptimizationDefaults = (
   D(optimization, 'avoidEntryIife', false);
   D(optimization, 'minimize', production);
   D(optimization, 'concatenateModules', production);
+  // IGNORE(optimization.concatenateCommonjsModules): Rspack specific configuration for concatenating CommonJS modules
+  D(optimization, 'concatenateCommonjsModules', false);
   // IGNORE(optimization.minimizer): Rspack use `SwcJsMinimizerRspackPlugin` and `LightningCssMinimizerRspackPlugin` by default
   A(optimization, 'minimizer', () => [
     new SwcJsMinimizerRspackPlugin(),
@@ -37,6 +37,7 @@ import type {
   EntryDescription,
   EntryStatic,
   Environment,
+  ExperimentCacheOptions,
   Externals,
   ExternalsPresets,
   ExternalsType,
@@ -73,6 +74,8 @@ import type {
   Plugins,
   Profile,
   PublicPath,
+  RecordsInputPath,
+  RecordsOutputPath,
   Resolve,
   RspackFutureOptions,
   RspackOptions,
@@ -404,13 +407,10 @@ export const getNormalizedRspackOptions = (
                 ...p,
               ]) || [/[\\/]node_modules[\\/][^.]/],
             },
-            storage: {
-              type: 'filesystem',
-              directory: path.resolve(
-                config.context || process.cwd(),
-                cache.storage?.directory || 'node_modules/.cache/rspack',
-              ),
-            },
+            storage: normalizeCacheStorage(
+              cache.storage,
+              config.context || process.cwd(),
+            ),
           };
         }),
         lazyCompilation: optionalNestedConfig(
@@ -431,6 +431,14 @@ export const getNormalizedRspackOptions = (
     profile: config.profile,
     amd: config.amd,
     bail: config.bail,
+    recordsInputPath:
+      config.recordsInputPath !== undefined
+        ? config.recordsInputPath
+        : config.recordsPath,
+    recordsOutputPath:
+      config.recordsOutputPath !== undefined
+        ? config.recordsOutputPath
+        : config.recordsPath,
     lazyCompilation: optionalNestedConfig(config.lazyCompilation, (options) =>
       options === true ? {} : options,
     ),
@@ -508,6 +516,39 @@ const getNormalizedOptimizationRuntimeChunk = (
   }
 };
 
+type ExperimentCacheStorage = Extract<
+  ExperimentCacheOptions,
+  { type: 'persistent' }
+>['storage'];
+
+const normalizeCacheStorage = (
+  storage: ExperimentCacheStorage,
+  context: string,
+): Extract<ExperimentCacheNormalized, { type: 'persistent' }>['storage'] => {
+  const directory = path.resolve(
+    context,
+    storage?.directory || 'node_modules/.cache/rspack',
+  );
+  const compression = storage?.compression;
+  const maxSize = storage?.maxSize;
+  if (storage?.type === 'remote') {
+    return {
+      type: 'remote',
+      directory,
+      compression,
+      maxSize,
+      remoteDirectory: path.resolve(context, storage.remoteDirectory),
+      push: storage.push ?? false,
+    };
+  }
+  return {
+    type: 'filesystem',
+    directory,
+    compression,
+    maxSize,
+  };
+};
+
 const getNormalizedIncrementalOptions = (
   incremental: IncrementalPresets | Incremental,
 ): false | Incremental => {
@@ -515,12 +556,17 @@ const getNormalizedIncrementalOptions = (
   if (incremental === 'safe')
     return {
       silent: true,
+      verify: false,
       make: true,
       inferAsyncModules: false,
       providedExports: false,
       dependenciesDiagnostics: false,
       sideEffects: false,
       buildChunkGraph: false,
+      moduleConcatenation: false,
+      splitChunks: false,
+      inlineExports: false,
+      mangleExports: false,
       moduleIds: false,
       chunkIds: false,
       modulesHashes: false,
@@ -678,9 +724,19 @@ export type ExperimentCacheNormalized =
         managedPaths: (string | RegExp)[];
       };
       storage: {
-        type: 'filesystem';
         directory: string;
-      };
+        compression?: 'none' | 'lz4' | 'zstd';
+        maxSize?: number;
+      } & (
+        | {
+            type: 'filesystem';
+          }
+        | {
+            type: 'remote';
+            remoteDirectory: string;
+            push: boolean;
+          }
+      );
     };
 
 export interface ExperimentsNormalized {
@@ -766,4 +822,6 @@ export interface RspackOptionsNormalized {
   profile?: Profile;
   amd?: Amd;
   bail?: Bail;
+  recordsInputPath?: RecordsInputPath;
+  recordsOutputPath?: RecordsOutputPath;
 }
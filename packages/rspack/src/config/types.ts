@@ -2021,6 +2021,17 @@ export type StatsOptions = {
    * @default false
    */
   optimizationBailout?: boolean;
+  /**
+   * Enables or disables the display of the statement that keeps a module only for its side effects.
+   * @default false
+   */
+  sideEffectsBailout?: boolean;
+  /**
+   * Enables or disables the display of the reasons why the persistent cache was reset
+   * or invalidated.
+   * @default false
+   */
+  cacheInvalidation?: boolean;
   /**
    * Enables or disables grouping of modules by type.
    */
@@ -2521,6 +2532,13 @@ export type Optimization = {
    */
   concatenateModules?: boolean;
 
+  /**
+   * Allows the CommonJS modules with static exports to be concatenated into the ES modules importing them,
+   * only takes effect when `concatenateModules` is enabled.
+   * @default false
+   */
+  concatenateCommonjsModules?: boolean;
+
   /**
    * Tells Rspack whether to perform a more detailed analysis of variable assignments.
    *
@@ -2553,6 +2571,12 @@ export type Optimization = {
    */
   inlineExports?: boolean;
 
+  /**
+   * Removes the unused methods and static properties of the classes that are not exposed dynamically.
+   * @default false
+   */
+  removeUnusedClassMembers?: boolean;
+
   /**
    * Tells Rspack to set process.env.NODE_ENV to a given string value.
    * @default false
@@ -2601,9 +2625,34 @@ export type ExperimentCacheOptions =
         managedPaths?: (string | RegExp)[];
       };
       storage?: {
-        type: 'filesystem';
         directory?: string;
-      };
+        /**
+         * The algorithm to compress the pack files.
+         * @default 'none'
+         */
+        compression?: 'none' | 'lz4' | 'zstd';
+        /**
+         * The max total size of all cache versions in bytes, the least recently saved versions
+         * are removed when it is exceeded, and then the oldest packs of the current version.
+         */
+        maxSize?: number;
+      } & (
+        | {
+            type: 'filesystem';
+          }
+        | {
+            type: 'remote';
+            /**
+             * The directory to share the cache with other machines, e.g. a mounted network drive.
+             */
+            remoteDirectory: string;
+            /**
+             * Whether to push the cache to `remoteDirectory` after it is saved.
+             * @default false
+             */
+            push?: boolean;
+          }
+      );
     };
 
 /**
@@ -2675,6 +2724,13 @@ export type Incremental = {
    * Warning if there are cases that not friendly for incremental
    */
   silent?: boolean;
+  /**
+   * Run a full rebuild after each incremental rebuild and report a warning if their outputs diverge.
+   * This slows down rebuilds significantly and is only intended for troubleshooting.
+   * The plugin hooks run again in the full rebuild, so plugins with side effects or outputs that
+   * change in every compilation may be called twice or reported as divergences.
+   */
+  verify?: boolean;
   /**
    * Enable incremental make.
    */
@@ -2705,6 +2761,26 @@ export type Incremental = {
    */
   buildChunkGraph?: boolean;
 
+  /**
+   * Enable incremental module concatenation.
+   */
+  moduleConcatenation?: boolean;
+
+  /**
+   * Enable incremental split chunks.
+   */
+  splitChunks?: boolean;
+
+  /**
+   * Enable incremental inline exports.
+   */
+  inlineExports?: boolean;
+
+  /**
+   * Enable incremental mangle exports.
+   */
+  mangleExports?: boolean;
+
   /**
    * Enable incremental module ids.
    */
@@ -2922,6 +2998,26 @@ export type WatchOptions = {
    * Stop watching when stdin stream has ended.
    */
   stdin?: boolean;
+
+  /**
+   * Ignore the changes of files whose content is the same as the last build.
+   * Only works with `experiments.nativeWatcher`.
+   * @default false
+   */
+  contentHash?: boolean;
+
+  /**
+   * The max delay to aggregate changes while they keep coming, e.g. when switching branches.
+   * Only works with `experiments.nativeWatcher`.
+   */
+  maxAggregateTimeout?: number;
+
+  /**
+   * The number of changed files above which the whole input file system cache is purged
+   * instead of the changed files one by one.
+   * Only works with `experiments.nativeWatcher`.
+   */
+  massiveChangeThreshold?: number;
 };
 //#endregion
 
@@ -2980,6 +3076,27 @@ export type Amd = false | Record<string, any>;
 export type Bail = boolean;
 //#endregion
 
+//#region Records
+/**
+ * The path of a JSON file to read and write records, which keep the module ids, chunk ids and
+ * the hashes used by hot module replacement across separate builds.
+ * It's used as the default of `recordsInputPath` and `recordsOutputPath`.
+ */
+export type RecordsPath = false | string;
+
+/**
+ * The path of a JSON file to read the records of the last build from.
+ * @default false
+ */
+export type RecordsInputPath = false | string;
+
+/**
+ * The path of a JSON file to write the records of the current build to.
+ * @default false
+ */
+export type RecordsOutputPath = false | string;
+//#endregion
+
 //#region Performance
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
@@ -3144,6 +3261,18 @@ export type RspackOptions = {
    * Whether to fail on the first error.
    */
   bail?: Bail;
+  /**
+   * The path to read and write records.
+   */
+  recordsPath?: RecordsPath;
+  /**
+   * The path to read records from.
+   */
+  recordsInputPath?: RecordsInputPath;
+  /**
+   * The path to write records to.
+   */
+  recordsOutputPath?: RecordsOutputPath;
   /**
    * Performance optimization options.
    */
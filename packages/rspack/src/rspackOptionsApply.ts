@@ -59,6 +59,7 @@ import {
   OccurrenceChunkIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
+  RemoveUnusedClassMembersPlugin,
   RuntimeChunkPlugin,
   RuntimePlugin,
   SideEffectsFlagPlugin,
@@ -282,6 +283,9 @@ export class RspackOptionsApply {
     if (options.optimization.inlineExports) {
       new InlineExportsPlugin().apply(compiler);
     }
+    if (options.optimization.removeUnusedClassMembers) {
+      new RemoveUnusedClassMembersPlugin().apply(compiler);
+    }
     if (options.optimization.mangleExports) {
       new MangleExportsPlugin(
         options.optimization.mangleExports !== 'size',
@@ -865,6 +865,17 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
       const statsCompilation = context.getStatsCompilation(compilation);
       object.hash = statsCompilation.hash;
     },
+    cacheInvalidation: (
+      object,
+      compilation,
+      context: KnownStatsFactoryContext,
+    ) => {
+      const statsCompilation = context.getStatsCompilation(compilation);
+      // only the compilations with persistent cache have the report
+      if (statsCompilation.cacheInvalidation) {
+        object.cacheInvalidation = statsCompilation.cacheInvalidation;
+      }
+    },
     version: (object) => {
       object.version = WEBPACK_VERSION;
       object.rspackVersion = RSPACK_VERSION;
@@ -1332,6 +1343,10 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
       object.optimizationBailout =
         module.commonAttributes.optimizationBailout || null;
     },
+    sideEffectsBailout: (object, module) => {
+      object.sideEffectsBailout =
+        module.commonAttributes.sideEffectsBailout || null;
+    },
     depth: (object, module) => {
       object.depth = module.commonAttributes.depth;
     },
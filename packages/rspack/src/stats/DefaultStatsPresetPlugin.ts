@@ -41,6 +41,8 @@ const NAMED_PRESETS: Record<string, StatsOptions> = {
     usedExports: true,
     providedExports: true,
     optimizationBailout: true,
+    sideEffectsBailout: true,
+    cacheInvalidation: true,
     errorDetails: true,
     errorStack: true,
     publicPath: true,
@@ -72,6 +74,8 @@ const NAMED_PRESETS: Record<string, StatsOptions> = {
     usedExports: true,
     providedExports: true,
     optimizationBailout: true,
+    sideEffectsBailout: true,
+    cacheInvalidation: true,
     errorDetails: true,
     publicPath: true,
     logging: true,
@@ -227,6 +231,8 @@ const DEFAULTS: StatsDefault = {
   usedExports: OFF_FOR_TO_STRING,
   providedExports: OFF_FOR_TO_STRING,
   optimizationBailout: OFF_FOR_TO_STRING,
+  sideEffectsBailout: OFF_FOR_TO_STRING,
+  cacheInvalidation: OFF_FOR_TO_STRING,
   children: OFF_FOR_TO_STRING,
   source: NORMAL_OFF,
   moduleTrace: NORMAL_ON,
@@ -14,7 +14,11 @@ import { compareIds } from '../util/comparators';
 import { formatSize } from '../util/SizeFormatHelpers';
 import { SHARED_ITEM_NAMES } from './DefaultStatsFactoryPlugin';
 import type { StatsPrinter, StatsPrinterContext } from './StatsPrinter';
-import type { StatsChunkGroup, StatsCompilation } from './statsFactoryUtils';
+import type {
+  StatsCacheInvalidation,
+  StatsChunkGroup,
+  StatsCompilation,
+} from './statsFactoryUtils';
 
 const DATA_URI_CONTENT_LENGTH = 16;
 
@@ -174,6 +178,41 @@ const SIMPLE_PRINTERS: Record<
       : undefined,
   'compilation.publicPath': (publicPath, { bold }) =>
     `PublicPath: ${bold(publicPath || '(none)')}`,
+  'compilation.cacheInvalidation': (
+    cacheInvalidation: StatsCacheInvalidation | undefined,
+    { yellow },
+  ) => {
+    if (!cacheInvalidation) return undefined;
+    const lines: string[] = [];
+    const { reset, paths, modules } = cacheInvalidation;
+    if (reset?.type === 'versionNotFound') {
+      lines.push(
+        yellow(
+          `Persistent cache reset: no cache found for version ${reset.version}`,
+        ),
+      );
+    } else if (reset?.type === 'buildDependencies') {
+      lines.push(
+        yellow('Persistent cache reset: build dependencies have changed'),
+      );
+      for (const item of reset.paths) lines.push(`  ${item.message}`);
+    }
+    if (paths.length > 0) {
+      lines.push(
+        `Persistent cache: ${paths.length} ${plural(
+          paths.length,
+          'path',
+          'paths',
+        )} changed, ${modules.length} ${plural(
+          modules.length,
+          'module',
+          'modules',
+        )} invalidated`,
+      );
+      for (const item of paths) lines.push(`  ${item.message}`);
+    }
+    return lines.length > 0 ? lines.join('\n') : undefined;
+  },
   'compilation.entrypoints': (entrypoints, context, printer) =>
     Array.isArray(entrypoints)
       ? undefined
@@ -429,6 +468,12 @@ const SIMPLE_PRINTERS: Record<
   },
   'module.optimizationBailout[]': (optimizationBailout, { yellow }) =>
     yellow(optimizationBailout),
+  'module.sideEffectsBailout': (sideEffectsBailout, { yellow }) =>
+    sideEffectsBailout
+      ? yellow(
+          `kept for side effects: ${sideEffectsBailout.type} at ${sideEffectsBailout.loc}: ${sideEffectsBailout.statement}`,
+        )
+      : undefined,
   'module.issuerPath': (_issuerPath, { module }) =>
     module.profile ? undefined : '',
   'module.profile': (_profile) => undefined,
@@ -729,6 +774,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
     'builtAt',
     'env',
     'publicPath',
+    'cacheInvalidation',
     'assets',
     'filteredAssets',
     'entrypoints',
@@ -818,6 +864,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
     'providedExports',
     'usedExports',
     'optimizationBailout',
+    'sideEffectsBailout',
     'reasons',
     'filteredReasons',
     'issuerPath',
@@ -1101,6 +1148,7 @@ const SIMPLE_ELEMENT_JOINERS: Record<
           case 'providedExports':
           case 'usedExports':
           case 'optimizationBailout':
+          case 'sideEffectsBailout':
           case 'reasons':
           case 'issuerPath':
           case 'profile':
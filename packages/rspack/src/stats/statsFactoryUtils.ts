@@ -130,6 +130,7 @@ export type KnownStatsModule = {
   usedExports?: boolean | string[] | null;
   providedExports?: string[] | null;
   optimizationBailout?: string[] | null;
+  sideEffectsBailout?: StatsModuleSideEffectsBailout | null;
   depth?: number;
   modules?: StatsModule[];
   filteredModules?: number;
@@ -155,6 +156,12 @@ export type KnownStatsModuleIssuer = {
 
 export type StatsModuleIssuer = KnownStatsModuleIssuer & Record<string, any>;
 
+export type StatsModuleSideEffectsBailout = {
+  type: string;
+  loc: string;
+  statement: string;
+};
+
 export enum StatsErrorCode {
   /**
    * Warning generated when either builtin `SwcJsMinimizer` or `LightningcssMinimizer` fails to minify the code.
@@ -272,6 +279,7 @@ export type KnownStatsCompilation = {
   hash?: string;
   time?: number;
   builtAt?: number;
+  cacheInvalidation?: StatsCacheInvalidation;
   publicPath?: string;
   outputPath?: string;
   assets?: StatsAsset[];
@@ -296,6 +304,22 @@ export type KnownStatsCompilation = {
 
 export type StatsCompilation = KnownStatsCompilation & Record<string, any>;
 
+export type StatsInvalidatedPath = {
+  path: string;
+  type: 'modified' | 'deleted';
+  message: string;
+};
+
+export type StatsCacheInvalidation = {
+  reset?: {
+    type: 'versionNotFound' | 'buildDependencies';
+    version?: string;
+    paths: StatsInvalidatedPath[];
+  };
+  paths: StatsInvalidatedPath[];
+  modules: string[];
+};
+
 export type StatsLogging = KnownStatsLogging & Record<string, any>;
 
 export type KnownStatsLogging = {
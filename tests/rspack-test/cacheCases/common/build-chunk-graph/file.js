@@ -0,0 +1,5 @@
+export default () => import("./a");
+---
+export default () => import("./a");
+---
+export default () => import("./b");
@@ -0,0 +1,16 @@
+import load from "./file";
+
+it("should restore chunk graph from persistent cache", async () => {
+	const { default: value } = await load();
+	if (COMPILER_INDEX == 0) {
+		expect(value).toBe("a");
+		await NEXT_START();
+	}
+	if (COMPILER_INDEX == 1) {
+		expect(value).toBe("a");
+		await NEXT_START();
+	}
+	if (COMPILER_INDEX == 2) {
+		expect(value).toBe("b");
+	}
+});
@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	experiments: {
+		cache: {
+			type: "persistent"
+		}
+	}
+};
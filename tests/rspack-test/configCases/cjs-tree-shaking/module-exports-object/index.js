@@ -0,0 +1,29 @@
+const generated = __non_webpack_require__("fs").readFileSync(__filename, "utf-8");
+
+// the strings are concatenated to avoid matching the test code itself
+const text = (...parts) => parts.join("");
+
+it("should keep the used properties of module.exports", () => {
+	// calling `require("./lib").used()` directly references the whole exports object
+	const used = require("./lib").used;
+	const method = require("./lib").method;
+	expect(used()).toBe("used");
+	expect(method()).toBe("method");
+	expect(require("./lib").constant).toBe("constant");
+	expect(require("./lib")["quoted-key"]).toBe("quoted");
+});
+
+it("should remove the unused properties of module.exports", () => {
+	expect(generated.includes(text("unused", "-function"))).toBe(false);
+	expect(generated.includes(text("unused", "-constant"))).toBe(false);
+	expect(generated.includes(text("unused", "-method"))).toBe(false);
+});
+
+it("should keep the unused properties with side effects", () => {
+	expect(global.sideEffectCount).toBe(1);
+	expect(generated.includes(text("unusedEffect", ": sideEffect()"))).toBe(true);
+});
+
+it("should keep all properties when the object refers to itself", () => {
+	expect(require("./self").get()).toBe("value");
+});
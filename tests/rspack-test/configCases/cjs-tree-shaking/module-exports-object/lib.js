@@ -0,0 +1,25 @@
+function used() {
+	return "used";
+}
+
+function sideEffect() {
+	global.sideEffectCount = (global.sideEffectCount || 0) + 1;
+	return 1;
+}
+
+module.exports = {
+	used,
+	unused: function () {
+		return "unused-function";
+	},
+	constant: "constant",
+	unusedConstant: "unused-constant",
+	unusedEffect: sideEffect(),
+	method() {
+		return "method";
+	},
+	unusedMethod() {
+		return "unused-method";
+	},
+	"quoted-key": "quoted"
+};
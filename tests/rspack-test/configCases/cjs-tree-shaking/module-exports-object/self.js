@@ -0,0 +1,6 @@
+module.exports = {
+	get() {
+		return this.value;
+	},
+	value: "value"
+};
@@ -0,0 +1,5 @@
+"use strict";
+
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.default = "flagged-default";
+exports.named = "flagged-named";
@@ -0,0 +1,45 @@
+import { add, counter, increment, "kebab-name" as kebab } from "./lib";
+import flaggedDefault, { named } from "./flagged";
+import dynamic from "./dynamic";
+import * as namespace from "./namespace";
+import { isStrict } from "./sloppy";
+
+it("should concatenate the CommonJS modules imported by the names of their exports", () => {
+	expect(add(1, 2)).toBe(3);
+	expect(kebab).toBe("kebab");
+	expect(flaggedDefault).toBe("flagged-default");
+	expect(named).toBe("flagged-named");
+});
+
+it("should keep the live bindings of the concatenated CommonJS exports", () => {
+	expect(counter).toBe(0);
+	increment();
+	expect(counter).toBe(1);
+});
+
+it("should not concatenate the CommonJS modules referenced as a whole", () => {
+	expect(dynamic()).toBe("dynamic");
+	expect(namespace).toEqual(expect.objectContaining({ value: "namespace" }));
+});
+
+it("should not concatenate the CommonJS modules in non-strict mode", () => {
+	expect(isStrict).toBe(false);
+});
+
+it("should report why the CommonJS modules are not concatenated", () => {
+	const chunk = __STATS__.chunks[0];
+	expect(chunk.modules.map(module => module.name).sort()).toEqual([
+		"./dynamic.js",
+		"./index.js + 2 modules",
+		"./namespace.js",
+		"./sloppy.js"
+	]);
+	const dynamicModule = __STATS__.modules.find(module => module.name === "./dynamic.js");
+	expect(dynamicModule.optimizationBailout).toContain(
+		"ModuleConcatenation bailout: Module uses an assignment to `module.exports`"
+	);
+	const sloppyModule = __STATS__.modules.find(module => module.name === "./sloppy.js");
+	expect(sloppyModule.optimizationBailout).toContain(
+		"ModuleConcatenation bailout: Module is not in strict mode"
+	);
+});
@@ -0,0 +1,8 @@
+"use strict";
+
+exports.add = (a, b) => a + b;
+exports.counter = 0;
+exports.increment = function () {
+	exports.counter++;
+};
+exports["kebab-name"] = "kebab";
@@ -0,0 +1,3 @@
+exports.isStrict = (function () {
+	return this === undefined;
+})();
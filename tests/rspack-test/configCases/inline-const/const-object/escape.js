@@ -0,0 +1,12 @@
+import { Aliased, Assigned, Passed } from "./objects.escaped.js";
+import * as objectsEscaped from "./objects.escaped.js";
+
+function mutate(object) {
+	object.A = 2;
+}
+
+Object.assign(Assigned, { A: 2 });
+mutate(Passed);
+const aliased = Aliased;
+aliased.A = 2;
+objectsEscaped.Namespaced.A = 2;
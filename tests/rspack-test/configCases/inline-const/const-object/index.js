@@ -0,0 +1,74 @@
+import * as objects from "./objects.js";
+import * as objectsMixed from "./objects.mixed.js";
+import * as objectsNoInline from "./objects.no-inline.js";
+import * as objectsMutated from "./objects.mutated.js";
+import * as objectsEscaped from "./objects.escaped.js";
+import "./mutate.js";
+import "./escape.js";
+
+const generated = /** @type {string} */ (__non_webpack_require__("fs").readFileSync(__filename, "utf-8"));
+
+it("should inline properties of const objects", () => {
+	// START:A
+	expect(objects.Direction.Up).toBe(0);
+	expect(objects.Direction.Down).toBe(1);
+	expect(objects.Direction.Left).toBe("left");
+	expect(objects.Direction.SMALL).toBe("s");
+	expect(objects.Frozen.A).toBe(1);
+	expect(objects.Frozen.B).toBe(true);
+	expect(objectsMixed.Mixed.A).toBe(null);
+	// END:A
+	const block = generated.match(/\/\/ START:A([\s\S]*)\/\/ END:A/)[1];
+	expect(block.includes(`((/* inlined export .Direction.Up */0)).toBe(0)`)).toBe(true);
+	expect(block.includes(`((/* inlined export .Direction.Down */1)).toBe(1)`)).toBe(true);
+	expect(block.includes(`((/* inlined export .Direction.Left */"left")).toBe("left")`)).toBe(true);
+	expect(block.includes(`((/* inlined export .Direction.SMALL */"s")).toBe("s")`)).toBe(true);
+	expect(block.includes(`((/* inlined export .Frozen.A */1)).toBe(1)`)).toBe(true);
+	expect(block.includes(`((/* inlined export .Frozen.B */true)).toBe(true)`)).toBe(true);
+	expect(block.includes(`((/* inlined export .Mixed.A */null)).toBe(null)`)).toBe(true);
+});
+
+it("should not inline properties that are not inlinable", () => {
+	// START:B
+	expect(objectsMixed.Mixed.B).toEqual({});
+	expect(objectsNoInline.Mutated.A).toBe(2);
+	expect(objectsNoInline.Spread.A).toBe(1);
+	// END:B
+	const block = generated.match(/\/\/ START:B([\s\S]*)\/\/ END:B/)[1];
+	expect(block.includes("inlined export")).toBe(false);
+});
+
+it("should not inline properties that are mutated in other modules", () => {
+	// START:C
+	expect(objectsMutated.Status.Busy).toBe(5);
+	expect(objectsMutated.Status.Done).toBe(3);
+	expect(objectsMutated.Status.Idle).toBe(undefined);
+	// END:C
+	const block = generated.match(/\/\/ START:C([\s\S]*)\/\/ END:C/)[1];
+	expect(block.includes("inlined export")).toBe(false);
+	// START:D
+	expect(objectsMutated.Status.Kept).toBe("kept");
+	// END:D
+	const keptBlock = generated.match(/\/\/ START:D([\s\S]*)\/\/ END:D/)[1];
+	expect(keptBlock.includes(`((/* inlined export .Status.Kept */"kept")).toBe("kept")`)).toBe(true);
+});
+
+it("should not inline properties of objects that are referenced as a whole in other modules", () => {
+	// START:E
+	expect(objectsEscaped.Assigned.A).toBe(2);
+	expect(objectsEscaped.Passed.A).toBe(2);
+	expect(objectsEscaped.Aliased.A).toBe(2);
+	expect(objectsEscaped.Namespaced.A).toBe(2);
+	// END:E
+	const block = generated.match(/\/\/ START:E([\s\S]*)\/\/ END:E/)[1];
+	expect(block.includes("inlined export")).toBe(false);
+});
+
+it("should remove the module if all property accesses are inlined", () => {
+	const includesModule = m =>
+		CONCATENATED
+			? generated.includes(`;// CONCATENATED MODULE: ${m}`)
+			: generated.includes(`"${m}"(`);
+	expect(includesModule("./objects.js")).toBe(false);
+	expect(includesModule("./objects.mixed.js")).toBe(true);
+});
@@ -0,0 +1,5 @@
+import { Status } from "./objects.mutated.js";
+
+Status.Busy = 5;
+Status.Done++;
+delete Status.Idle;
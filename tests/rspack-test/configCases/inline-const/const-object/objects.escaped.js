@@ -0,0 +1,12 @@
+export const Assigned = {
+	A: 1
+};
+export const Passed = {
+	A: 1
+};
+export const Aliased = {
+	A: 1
+};
+export const Namespaced = {
+	A: 1
+};
@@ -0,0 +1,13 @@
+const SMALL = "s";
+
+export const Direction = {
+	Up: 0,
+	Down: 1,
+	"Left": "left",
+	SMALL
+};
+
+export const Frozen = Object.freeze({
+	A: 1,
+	B: true
+});
@@ -0,0 +1,4 @@
+export const Mixed = {
+	A: null,
+	B: {}
+};
@@ -0,0 +1,6 @@
+export const Status = {
+	Idle: 0,
+	Busy: 1,
+	Done: 2,
+	Kept: "kept"
+};
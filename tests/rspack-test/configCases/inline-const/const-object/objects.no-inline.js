@@ -0,0 +1,9 @@
+export const Mutated = {
+	A: 1
+};
+
+Mutated.A = 2;
+
+export const Spread = {
+	...{ A: 1 }
+};
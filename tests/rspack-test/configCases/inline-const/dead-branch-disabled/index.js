@@ -0,0 +1,12 @@
+import { DEBUG } from "./flags";
+
+const generated = /** @type {string} */ (__non_webpack_require__("fs").readFileSync(__filename, "utf-8"));
+
+// the strings are concatenated to avoid matching the test code itself
+const text = (...parts) => parts.join("");
+
+it("should keep the modules in the branches guarded by imported constants without inlineExports", () => {
+	const debug = DEBUG ? require("./debug") : null;
+	expect(debug).toBe(null);
+	expect(generated.includes(text("debug", "-tools"))).toBe(true);
+});
@@ -0,0 +1,3 @@
+export function debug() {
+	return "debug" + "-tools";
+}
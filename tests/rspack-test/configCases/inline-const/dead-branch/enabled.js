@@ -0,0 +1,3 @@
+export function enabled() {
+	return "enabled" + "-feature";
+}
@@ -0,0 +1,3 @@
+export const DEBUG = false;
+export const ENABLED = true;
+export const LEVEL = "info";
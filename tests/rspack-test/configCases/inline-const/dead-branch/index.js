@@ -0,0 +1,47 @@
+import { DEBUG, ENABLED, LEVEL } from "./flags";
+import * as flags from "./flags";
+import { debug } from "./debug";
+import { verbose } from "./verbose";
+import { enabled } from "./enabled";
+import { base } from "./lib";
+
+const generated = /** @type {string} */ (__non_webpack_require__("fs").readFileSync(__filename, "utf-8"));
+
+// the strings are concatenated to avoid matching the test code itself
+const text = (...parts) => parts.join("");
+
+it("should keep the modules in the live branches", () => {
+	const result = [];
+	if (ENABLED && !DEBUG) {
+		result.push(enabled());
+	}
+	expect(result).toEqual(["enabled-feature"]);
+	expect(generated.includes(text("enabled", "-feature"))).toBe(true);
+	expect(base).toBe("base");
+});
+
+it("should drop the modules in the branches guarded by imported constants", () => {
+	if (DEBUG) {
+		debug();
+	}
+	if (LEVEL === "verbose") {
+		verbose();
+	}
+	const lazy = flags.DEBUG ? import("./lazy") : null;
+	const disabled = !ENABLED ? require("./disabled") : null;
+	expect(lazy).toBe(null);
+	expect(disabled).toBe(null);
+	expect(generated.includes(text("debug", "-tools"))).toBe(false);
+	expect(generated.includes(text("verbose", "-logger"))).toBe(false);
+	expect(generated.includes(text("lazy", "-debug"))).toBe(false);
+	expect(generated.includes(text("disabled", "-feature"))).toBe(false);
+});
+
+it("should not build the modules only required in the branches guarded by imported constants", () => {
+	const unbuildable = DEBUG ? require("./unbuildable") : null;
+	expect(unbuildable).toBe(null);
+});
+
+it("should drop the modules in the branches guarded by unused exports", () => {
+	expect(generated.includes(text("unused", "-plugin"))).toBe(false);
+});
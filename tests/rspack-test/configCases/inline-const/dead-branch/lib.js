@@ -0,0 +1,9 @@
+import { plugin } from "./plugin";
+
+export const plugins = [];
+
+if (__webpack_exports_info__.plugins.used) {
+	plugins.push(plugin);
+}
+
+export const base = "base";
@@ -0,0 +1,3 @@
+export function plugin() {
+	return "unused" + "-plugin";
+}
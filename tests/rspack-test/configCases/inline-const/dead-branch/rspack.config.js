@@ -0,0 +1,22 @@
+/**
+ * @return {import("@rspack/core").Configuration}
+ */
+function config(index, { concatenateModules } = {}) {
+	return {
+		entry: "./index.js",
+		output: {
+			filename: `bundle.${index}.js`
+		},
+		optimization: {
+			concatenateModules,
+			moduleIds: "named",
+			inlineExports: true
+		}
+	};
+}
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	config(0, { concatenateModules: true }),
+	config(1, { concatenateModules: false })
+];
@@ -0,0 +1,2 @@
+// the module fails to build, it must not be built since it is only required in a dead branch
+export const = ;
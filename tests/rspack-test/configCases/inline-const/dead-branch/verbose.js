@@ -0,0 +1,3 @@
+export function verbose() {
+	return "verbose" + "-logger";
+}
@@ -2,6 +2,7 @@ import { b as a, cc } from "./named-barrel";
 import { b as c, d } from "./mixed-barrel";
 import { b } from "./star-barrel";
 import * as nested from "./nested-barrel";
+import { IconA } from "./nested-star-barrel";
 
 it("should correct build", () => {
   expect(a).toBe('a');
@@ -10,4 +11,5 @@ it("should correct build", () => {
   expect(c).toBe('c');
   expect(d).toBe('d');
   expect(nested.a).toBe('b');
+  expect(IconA).toBe('icon-a');
 })
@@ -0,0 +1 @@
+export const all = 'all';
@@ -0,0 +1 @@
+export default 'icon-b';
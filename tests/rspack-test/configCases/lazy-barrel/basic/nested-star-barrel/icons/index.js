@@ -0,0 +1,3 @@
+export { default as IconA } from "./a";
+export { default as IconB } from "./b";
+export * from "./more";
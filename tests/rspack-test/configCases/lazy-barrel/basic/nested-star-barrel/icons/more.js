@@ -0,0 +1 @@
+export const more = 'more';
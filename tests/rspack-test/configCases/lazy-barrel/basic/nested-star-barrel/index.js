@@ -0,0 +1,3 @@
+export * from "./icons";
+export * from "./utils";
+export * as all from "./all";
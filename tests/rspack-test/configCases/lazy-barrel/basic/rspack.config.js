@@ -18,7 +18,10 @@ const lazyModules = new Set(
 		"mixed-barrel/a.js",
 		"mixed-barrel/b.js",
 		"star-barrel/c.js",
-		"nested-barrel/c.js"
+		"nested-barrel/c.js",
+		"nested-star-barrel/icons/b.js",
+		"nested-star-barrel/icons/more.js",
+		"nested-star-barrel/all.js"
 	].map(filename => path.resolve(__dirname, filename))
 );
 
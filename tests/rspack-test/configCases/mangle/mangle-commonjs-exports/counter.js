@@ -0,0 +1,3 @@
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.counter = 0;
+exports.increment = () => ++exports.counter;
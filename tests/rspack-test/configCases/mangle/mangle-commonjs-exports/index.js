@@ -0,0 +1,36 @@
+import { first, second, third, "quoted-key" as quoted } from "./literal";
+import { counter, increment } from "./counter";
+import data from "./data.json";
+
+const getKeys = request => Object.keys(require.cache[request].exports).sort();
+
+it("should mangle the properties of the object literal assigned to module.exports", () => {
+	expect(first).toBe("first");
+	expect(second).toBe("second");
+	expect(quoted).toBe("quoted");
+	// the name of the anonymous function is inferred from its key
+	expect(third.name).toBe("third");
+	const keys = getKeys(require.resolve("./literal"));
+	expect(keys).toHaveLength(4);
+	expect(keys).toContain("third");
+	expect(keys).not.toContain("first");
+	expect(keys).not.toContain("second");
+	expect(keys).not.toContain("quoted-key");
+});
+
+it("should mangle the exports referenced by the module itself", () => {
+	expect(counter).toBe(0);
+	expect(increment()).toBe(1);
+	expect(counter).toBe(1);
+	const keys = getKeys(require.resolve("./counter"));
+	expect(keys).toHaveLength(2);
+	expect(keys).not.toContain("counter");
+	expect(keys).not.toContain("increment");
+});
+
+it("should mangle the properties of json", () => {
+	expect(data.longPropertyName).toBe("value");
+	const keys = getKeys(require.resolve("./data.json"));
+	expect(keys).toHaveLength(1);
+	expect(keys).not.toContain("longPropertyName");
+});
@@ -0,0 +1,8 @@
+const second = "second";
+module.exports = {
+	first: "first",
+	second,
+	third: function () {},
+	"quoted-key": "quoted",
+	unused: "unused"
+};
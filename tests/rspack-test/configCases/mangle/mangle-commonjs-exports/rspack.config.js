@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		mangleExports: true,
+		usedExports: true,
+		providedExports: true,
+		concatenateModules: false
+	}
+};
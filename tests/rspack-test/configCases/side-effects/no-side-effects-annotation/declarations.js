@@ -0,0 +1,6 @@
+import { createComponent, log } from "./utils";
+
+export const Button = createComponent("button");
+export const Used = createComponent("used");
+export const Logged = log("logged");
+export default createComponent("default");
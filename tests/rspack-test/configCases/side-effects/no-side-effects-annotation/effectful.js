@@ -0,0 +1,4 @@
+import { createComponent, log } from "./utils";
+
+log("log");
+createComponent(log("argument"));
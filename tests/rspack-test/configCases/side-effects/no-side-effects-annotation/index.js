@@ -0,0 +1,26 @@
+import "./register";
+import "./local";
+import "./effectful";
+import "./shadowed";
+import { Used } from "./declarations";
+import { calls } from "./calls";
+
+it("should drop modules that only call functions annotated with #__NO_SIDE_EFFECTS__", () => {
+	// `createComponent` in effectful.js is kept as its argument has side effects,
+	// and it receives the return value of `log`
+	expect(calls).toEqual([
+		"log",
+		"argument",
+		undefined,
+		"unused",
+		"shadowed",
+		"used",
+		"logged"
+	]);
+});
+
+it("should drop unused declarations initialized by calls to functions annotated with #__NO_SIDE_EFFECTS__", () => {
+	expect(Used).toEqual({ name: "used" });
+	expect(calls).not.toContain("button");
+	expect(calls).not.toContain("default");
+});
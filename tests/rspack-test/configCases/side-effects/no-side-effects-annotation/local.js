@@ -0,0 +1,8 @@
+import { calls } from "./calls";
+
+/*#__NO_SIDE_EFFECTS__*/
+function createLocal(name) {
+	calls.push(name);
+}
+
+createLocal("local");
@@ -0,0 +1 @@
+export { createComponent } from "./utils";
@@ -0,0 +1,5 @@
+import { createComponent } from "./reexport";
+import { createStore } from "./utils";
+
+createComponent("component");
+createStore("store");
@@ -0,0 +1,7 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		sideEffects: true,
+		usedExports: true
+	}
+};
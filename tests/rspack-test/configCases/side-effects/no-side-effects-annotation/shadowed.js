@@ -0,0 +1,11 @@
+import { calls } from "./calls";
+
+/*#__NO_SIDE_EFFECTS__*/
+function create(name) {
+	calls.push(name);
+}
+
+create("unused");
+
+// the annotation does not apply to the binding which shadows the annotated function
+for (let create = name => calls.push(name); !create("shadowed"); ) {}
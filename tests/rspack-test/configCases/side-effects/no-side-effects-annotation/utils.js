@@ -0,0 +1,16 @@
+import { calls } from "./calls";
+
+/*#__NO_SIDE_EFFECTS__*/
+export function createComponent(name) {
+	calls.push(name);
+	return { name };
+}
+
+export const createStore = /*#__NO_SIDE_EFFECTS__*/ name => {
+	calls.push(name);
+	return { name };
+};
+
+export function log(name) {
+	calls.push(name);
+}
@@ -0,0 +1,13 @@
+import { Plugin } from "./plugin.js";
+import "./no-parse.js";
+
+const generated = /** @type {string} */ (__non_webpack_require__("fs").readFileSync(__filename, "utf-8"));
+
+// the names are concatenated to avoid being treated as property accesses
+const name = (...parts) => parts.join("");
+
+it("should keep all class members if a module is not parsed", () => {
+	const plugin = new Plugin();
+	expect(plugin.usedMethod()).toBe("used");
+	expect(generated.includes(name("unused", "Method"))).toBe(true);
+});
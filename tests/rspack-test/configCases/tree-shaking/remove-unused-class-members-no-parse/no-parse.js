@@ -0,0 +1,2 @@
+// the members accessed by this module are unknown since it is not parsed
+globalThis.callPluginMethod = (plugin, key) => plugin[key]();
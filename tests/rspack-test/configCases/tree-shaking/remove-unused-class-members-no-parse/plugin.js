@@ -0,0 +1,9 @@
+export class Plugin {
+	usedMethod() {
+		return "used";
+	}
+
+	unusedMethod() {
+		return "unused";
+	}
+}
@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	module: {
+		noParse: /no-parse\.js$/
+	},
+	optimization: {
+		removeUnusedClassMembers: true
+	}
+};
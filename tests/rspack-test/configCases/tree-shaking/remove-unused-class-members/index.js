@@ -0,0 +1,39 @@
+import { Service, Derived, Escaped, Computed, Created, Passed } from "./service.js";
+
+const generated = /** @type {string} */ (__non_webpack_require__("fs").readFileSync(__filename, "utf-8"));
+
+// the names are concatenated to avoid being treated as property accesses
+const name = (...parts) => parts.join("");
+
+it("should keep the used class members", () => {
+	const service = new Service();
+	expect(service.usedMethod()).toBe("used");
+	expect(service.usedGetter).toBe(1);
+	expect(Service.VERSION).toBe(1);
+	expect(new Derived().baseUsedMethod()).toBe("base");
+});
+
+it("should remove the unused class members", () => {
+	expect(generated.includes(name("unused", "Method"))).toBe(false);
+	expect(generated.includes(name("unused", "Getter"))).toBe(false);
+	expect(generated.includes(name("unused", "Static"))).toBe(false);
+	expect(generated.includes(name("baseUnused", "Method"))).toBe(false);
+	expect(generated.includes(name("derivedUnused", "Method"))).toBe(false);
+});
+
+it("should keep all members of the classes that are accessed dynamically", () => {
+	const classes = [Escaped];
+	expect(classes.length).toBe(1);
+	const computed = new Computed();
+	const key = name("computed", "Method");
+	expect(computed[key]()).toBe("computed");
+	expect(generated.includes(name("escaped", "Method"))).toBe(true);
+});
+
+it("should keep all members of the classes whose instances escape", () => {
+	const created = Created.create();
+	expect(created[name("created", "Method")]()).toBe("created");
+	const call = (instance, key) => instance[key]();
+	const passed = new Passed();
+	expect(call(passed, name("passed", "Method"))).toBe("passed");
+});
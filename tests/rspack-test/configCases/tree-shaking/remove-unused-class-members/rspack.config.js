@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [false, true].map((concatenateModules, index) => ({
+	entry: "./index.js",
+	output: {
+		filename: `bundle.${index}.js`
+	},
+	optimization: {
+		concatenateModules,
+		removeUnusedClassMembers: true
+	}
+}));
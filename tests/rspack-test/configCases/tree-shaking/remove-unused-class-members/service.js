@@ -0,0 +1,64 @@
+export class Service {
+	static VERSION = 1;
+	static unusedStatic = 2;
+
+	usedMethod() {
+		return "used";
+	}
+
+	unusedMethod() {
+		return "unused";
+	}
+
+	get usedGetter() {
+		return 1;
+	}
+
+	get unusedGetter() {
+		return 2;
+	}
+}
+
+export class Base {
+	baseUsedMethod() {
+		return "base";
+	}
+
+	baseUnusedMethod() {
+		return "base unused";
+	}
+}
+
+export class Derived extends Base {
+	derivedUnusedMethod() {
+		return "derived unused";
+	}
+}
+
+export class Escaped {
+	escapedMethod() {
+		return "escaped";
+	}
+}
+
+export class Computed {
+	computedMethod() {
+		return "computed";
+	}
+}
+
+export class Created {
+	static create() {
+		return new Created();
+	}
+
+	createdMethod() {
+		return "created";
+	}
+}
+
+export class Passed {
+	passedMethod() {
+		return "passed";
+	}
+}
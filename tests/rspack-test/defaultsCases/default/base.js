@@ -35,7 +35,10 @@ module.exports = {
 			      dependenciesDiagnostics: true,
 			      emitAssets: true,
 			      inferAsyncModules: true,
+			      inlineExports: false,
 			      make: true,
+			      mangleExports: false,
+			      moduleConcatenation: false,
 			      moduleIds: true,
 			      modulesCodegen: true,
 			      modulesHashes: true,
@@ -43,6 +46,8 @@ module.exports = {
 			      providedExports: true,
 			      sideEffects: true,
 			      silent: true,
+			      splitChunks: false,
+			      verify: false,
 			    },
 			    inlineConst: true,
 			    inlineEnum: false,
@@ -245,6 +250,7 @@ module.exports = {
 			  optimization: Object {
 			    avoidEntryIife: false,
 			    chunkIds: natural,
+			    concatenateCommonjsModules: false,
 			    concatenateModules: false,
 			    emitOnErrors: true,
 			    inlineExports: false,
@@ -270,6 +276,7 @@ module.exports = {
 			    realContentHash: false,
 			    removeAvailableModules: true,
 			    removeEmptyChunks: true,
+			    removeUnusedClassMembers: false,
 			    runtimeChunk: false,
 			    sideEffects: flag,
 			    splitChunks: Object {
@@ -376,6 +383,8 @@ module.exports = {
 			  performance: false,
 			  plugins: Array [],
 			  profile: false,
+			  recordsInputPath: false,
+			  recordsOutputPath: false,
 			  resolve: Object {
 			    aliasFields: Array [],
 			    byDependency: Object {
@@ -12,12 +12,13 @@ module.exports = {
 			+   "mode": undefined,
 			@@ ... @@
 			-     "chunkIds": "natural",
+			+     "chunkIds": "deterministic",
+			@@ ... @@
 			-     "concatenateModules": false,
 			-     "emitOnErrors": true,
 			-     "inlineExports": false,
 			-     "innerGraph": false,
 			-     "mangleExports": false,
-			+     "chunkIds": "deterministic",
 			+     "concatenateModules": true,
 			+     "emitOnErrors": false,
 			+     "inlineExports": true,
@@ -0,0 +1,2 @@
+export const VERSION = '1.0.0'
+export const DEBUG = false
@@ -0,0 +1,13 @@
+import * as constants from './consts'
+
+export { VERSION } from './consts'
+export { constants }
+
+it('should inline constants from other chunks', async () => {
+	const { VERSION, constants } = await import(/* webpackIgnore: true */ './main.mjs')
+	expect(VERSION).toBe('1.0.0')
+	expect(constants.DEBUG).toBe(false)
+
+	const { version } = await import('./other')
+	expect(version).toBe('v1.0.0')
+})
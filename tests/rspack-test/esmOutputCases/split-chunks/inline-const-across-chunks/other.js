@@ -0,0 +1,3 @@
+import { VERSION } from './consts'
+
+export const version = `v${VERSION}`
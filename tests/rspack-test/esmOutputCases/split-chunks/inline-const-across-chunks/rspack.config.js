@@ -0,0 +1,13 @@
+module.exports = {
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				consts: {
+					test: /consts\.js/,
+					name: 'consts',
+					enforce: true,
+				}
+			}
+		}
+	}
+}
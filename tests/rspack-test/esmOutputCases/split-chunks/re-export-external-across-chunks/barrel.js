@@ -0,0 +1,3 @@
+export { join, basename } from 'path'
+
+globalThis.barrelLoaded = true
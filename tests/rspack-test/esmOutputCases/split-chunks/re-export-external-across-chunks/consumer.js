@@ -0,0 +1,3 @@
+import { join, basename } from './barrel'
+
+export const run = () => basename(join('a', 'b'))
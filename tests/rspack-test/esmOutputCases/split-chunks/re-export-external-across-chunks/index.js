@@ -0,0 +1,10 @@
+export { join } from './barrel'
+
+it('should import and re-export the symbols of external modules from the externals directly', async () => {
+	const { join } = await import(/* webpackIgnore: true */ './main.mjs')
+	expect(join('a', 'b')).toBe('a/b')
+	expect(globalThis.barrelLoaded).toBe(true)
+
+	const { run } = await import('./consumer')
+	expect(run()).toBe('b')
+})
@@ -0,0 +1,13 @@
+module.exports = {
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				barrel: {
+					test: /barrel\.js/,
+					name: 'barrel',
+					enforce: true,
+				}
+			}
+		}
+	}
+}
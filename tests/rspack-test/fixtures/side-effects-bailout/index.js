@@ -0,0 +1,4 @@
+import { unused } from "./lib";
+import { value } from "./pure";
+
+console.log(value());
@@ -0,0 +1,36 @@
+/** @type {import('@rspack/test-tools').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the reason why the persistent cache was reset",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/a",
+			mode: "production",
+			cache: true,
+			experiments: {
+				cache: {
+					type: "persistent",
+					storage: {
+						type: "filesystem",
+						directory: context.getDist("cache")
+					}
+				}
+			}
+		};
+	},
+	async check(stats) {
+		const { cacheInvalidation } = stats?.toJson({
+			all: false,
+			cacheInvalidation: true
+		});
+		expect(cacheInvalidation.reset.type).toBe("versionNotFound");
+		expect(typeof cacheInvalidation.reset.version).toBe("string");
+		expect(cacheInvalidation.paths).toEqual([]);
+		expect(
+			stats?.toString({ all: false, cacheInvalidation: true })
+		).toContain(
+			`Persistent cache reset: no cache found for version ${cacheInvalidation.reset.version}`
+		);
+		expect(stats?.toJson({ all: false }).cacheInvalidation).toBeUndefined();
+	}
+};
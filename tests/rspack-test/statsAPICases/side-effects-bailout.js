@@ -0,0 +1,32 @@
+/** @type {import('@rspack/test-tools').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have the statement that keeps the module for side effects",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/side-effects-bailout/index",
+			mode: "production",
+			optimization: {
+				concatenateModules: false
+			}
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.toJson({
+			all: false,
+			modules: true,
+			sideEffectsBailout: true
+		});
+		const lib = modules.find(m => m.name.endsWith("lib.js"));
+		expect(lib.sideEffectsBailout).toEqual({
+			type: "Statement",
+			loc: "3:1-40",
+			statement: 'globalThis.__sideEffectsBailout = "lib";'
+		});
+		const pure = modules.find(m => m.name.endsWith("pure.js"));
+		expect(pure.sideEffectsBailout).toBe(null);
+		expect(stats?.toString({ all: false, modules: true, sideEffectsBailout: true })).toContain(
+			'kept for side effects: Statement at 3:1-40: globalThis.__sideEffectsBailout = "lib";'
+		);
+	}
+};
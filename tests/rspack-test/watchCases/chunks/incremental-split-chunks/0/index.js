@@ -0,0 +1,7 @@
+import { a } from "./lib/a";
+import { value } from "./value";
+
+it("should split chunks correctly after changes", () => {
+	expect(a()).toBe("a");
+	expect(value).toBe(WATCH_STEP);
+});
@@ -0,0 +1,9 @@
+import { a } from "./lib/a";
+import { value } from "./value";
+
+it("should split chunks correctly after changes", () => {
+	expect(a()).toBe("a");
+	expect(value).toBe(WATCH_STEP);
+	const lib = __STATS__.chunks.find(chunk => chunk.names.includes("lib"));
+	expect(__STATS__.modules.filter(m => m.chunks.includes(lib.id)).length).toBe(1);
+});
@@ -0,0 +1,24 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			cacheGroups: {
+				lib: {
+					name: "lib",
+					test: /lib/
+				}
+			}
+		}
+	},
+	experiments: {
+		incremental: {
+			buildChunkGraph: true,
+			splitChunks: true
+		}
+	}
+};
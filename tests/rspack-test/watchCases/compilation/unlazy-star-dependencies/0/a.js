@@ -0,0 +1 @@
+export const a = 1;
\ No newline at end of file
@@ -0,0 +1 @@
+export const b = 2;
\ No newline at end of file
@@ -0,0 +1,5 @@
+import { a } from "./lib";
+
+it("should work", () => {
+  expect(a).toBe(1);
+});
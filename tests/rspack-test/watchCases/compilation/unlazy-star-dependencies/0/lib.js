@@ -0,0 +1 @@
+export * from "./nested"
\ No newline at end of file
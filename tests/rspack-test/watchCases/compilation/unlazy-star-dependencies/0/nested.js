@@ -0,0 +1,2 @@
+export { a } from "./a"
+export { b } from "./b"
\ No newline at end of file
@@ -0,0 +1,6 @@
+import { a, b } from "./lib";
+
+it("should work", () => {
+  expect(a).toBe(1);
+  expect(b).toBe(2);
+});
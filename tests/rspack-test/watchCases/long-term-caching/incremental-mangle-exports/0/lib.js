@@ -0,0 +1,9 @@
+export const ENABLED = true;
+
+export function getLongExportName() {
+	return "long";
+}
+
+export function getAnotherLongExportName() {
+	return "another";
+}
@@ -0,0 +1,5 @@
+import { ENABLED, getAnotherLongExportName, getLongExportName } from "./lib";
+
+export default function () {
+	return ENABLED ? `${getLongExportName()}-${getAnotherLongExportName()}` : "";
+}
@@ -0,0 +1,8 @@
+import { value } from "./value";
+
+it("should keep the mangled names of unchanged modules", async () => {
+	expect(value).toBe(WATCH_STEP);
+	expect(__STATS__.assetsByChunkName.async[0]).toBe(STATE.hash);
+	const { default: get } = await import(/* webpackChunkName: "async" */ "./other-chunk");
+	expect(get()).toBe("long-another");
+});
@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	output: {
+		chunkFilename: "[contenthash].js"
+	},
+	optimization: {
+		mangleExports: "deterministic",
+		inlineExports: true
+	},
+	experiments: {
+		incremental: {
+			inlineExports: true,
+			mangleExports: true
+		}
+	}
+};
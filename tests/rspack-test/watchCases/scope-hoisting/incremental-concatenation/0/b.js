@@ -0,0 +1 @@
+export { b } from "./b-inner";
@@ -0,0 +1,5 @@
+import { c } from "./c-inner";
+
+export function getC() {
+	return c;
+}
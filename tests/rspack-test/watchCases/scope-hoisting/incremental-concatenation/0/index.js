@@ -0,0 +1,9 @@
+import { a } from "./a";
+import { b } from "./b";
+import { getC } from "./c";
+
+it("should concatenate modules correctly after changes", function () {
+	expect(a).toBe(`a${WATCH_STEP}`);
+	expect(b).toBe("b");
+	expect(getC()).toBe(WATCH_STEP === "2" ? "c-commonjs" : "c");
+});
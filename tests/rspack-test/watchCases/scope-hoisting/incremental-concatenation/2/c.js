@@ -0,0 +1,8 @@
+import { c } from "./c-inner";
+
+// c-inner is referenced with commonjs and can not be concatenated
+const inner = require("./c-inner");
+
+export function getC() {
+	return `${c}-${inner.__esModule ? "commonjs" : "esm"}`;
+}
@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		concatenateModules: true
+	},
+	experiments: {
+		incremental: {
+			buildChunkGraph: true,
+			moduleConcatenation: true
+		}
+	}
+};